@@ -1,10 +1,7 @@
 //! https://adventofcode.com/2015/day/2
 //! https://adventofcode.com/2015/day/2/input
 
-use std::{
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
+use utils::Solution;
 
 type Parsed = Vec<(usize, usize, usize)>;
 
@@ -48,36 +45,6 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) -> Duration {
-    let test_input = "2x3x4".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2015/day_02_input.txt").unwrap()
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let parsed = parse(&puzzle_input);
-    let elapsed = start.elapsed();
-    println!("Parsed in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part1::solve(parsed.clone());
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part2::solve(parsed);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("Second part in {:?}", elapsed);
-    total += elapsed;
-
-    println!("Total {:?}", total);
-    total
-}
+#[derive(Solution)]
+#[example(part1 = 58, part2 = 34, input = "2x3x4")]
+pub struct Day02;
@@ -1,10 +1,7 @@
 //! https://adventofcode.com/2015/day/3
 //! https://adventofcode.com/2015/day/3/input
 
-use std::{
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
+use utils::Solution;
 
 pub mod part1 {
     use std::collections::HashSet;
@@ -60,30 +57,6 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) -> Duration {
-    let test_input = "".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2015/day_03_input.txt").unwrap()
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let result = part1::solve(&puzzle_input);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part2::solve(&puzzle_input);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("Second part in {:?}", elapsed);
-    total += elapsed;
-
-    println!("Total {:?}", total);
-    total
-}
+#[derive(Solution)]
+#[solution(unparsed)]
+pub struct Day03;
@@ -1,10 +1,7 @@
 //! https://adventofcode.com/2015/day/4
 //! https://adventofcode.com/2015/day/4/input
 
-use std::{
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
+use utils::Solution;
 
 pub mod part1 {
     use md5::compute;
@@ -39,30 +36,7 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) -> Duration {
-    let test_input = "abcdef".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2015/day_04_input.txt").unwrap()
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let result = part1::solve(&puzzle_input);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part2::solve(&puzzle_input);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("Second part in {:?}", elapsed);
-    total += elapsed;
-
-    println!("Total {:?}", total);
-    total
-}
+#[derive(Solution)]
+#[solution(unparsed)]
+#[example(part1 = 609043, input = "abcdef")]
+pub struct Day04;
@@ -1,12 +1,8 @@
 //! https://adventofcode.com/2015/day/6
 //! https://adventofcode.com/2015/day/6/input
 
-use std::{
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
-
 use regex::Regex;
+use utils::Solution;
 
 #[derive(Copy, Clone, Debug)]
 pub enum Action {
@@ -131,36 +127,5 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) -> Duration {
-    let test_input = "".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2015/day_06_input.txt").unwrap()
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let parsed = parse(&puzzle_input);
-    let elapsed = start.elapsed();
-    println!("Parsed in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part1::solve(parsed.clone());
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part2::solve(parsed);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("Second part in {:?}", elapsed);
-    total += elapsed;
-
-    println!("Total {:?}", total);
-    total
-}
+#[derive(Solution)]
+pub struct Day06;
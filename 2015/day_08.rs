@@ -1,10 +1,7 @@
 //! https://adventofcode.com/2015/day/8
 //! https://adventofcode.com/2015/day/8/input
 
-use std::{
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
+use utils::Solution;
 
 fn find_difference(string: &str) -> usize {
     let (memory, _, _) = string[1..string.len() - 1].chars().fold(
@@ -58,34 +55,14 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) -> Duration {
-    let test_input = r#"""
+#[derive(Solution)]
+#[solution(unparsed)]
+#[example(
+    part1 = 12,
+    part2 = 19,
+    input = r#"""
 "abc"
 "aaa\"aaa"
 "\x27""#
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2015/day_08_input.txt").unwrap()
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let result = part1::solve(&puzzle_input);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part2::solve(&puzzle_input);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("Second part in {:?}", elapsed);
-    total += elapsed;
-
-    println!("Total {:?}", total);
-    total
-}
+)]
+pub struct Day08;
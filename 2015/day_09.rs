@@ -1,13 +1,10 @@
 //! https://adventofcode.com/2015/day/9
 //! https://adventofcode.com/2015/day/9/input
 
-use std::{
-    collections::HashMap,
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
+use std::collections::HashMap;
 
 use itertools::Itertools;
+use utils::Solution;
 
 type Parsed = Vec<Vec<usize>>;
 
@@ -66,39 +63,12 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) -> Duration {
-    let test_input = "London to Dublin = 464
+#[derive(Solution)]
+#[example(
+    part1 = 605,
+    part2 = 982,
+    input = "London to Dublin = 464
 London to Belfast = 518
 Dublin to Belfast = 141"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2015/day_09_input.txt").unwrap()
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let parsed = parse(&puzzle_input);
-    let elapsed = start.elapsed();
-    println!("Parsed in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part1::solve(parsed.clone());
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part2::solve(parsed);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("Second part in {:?}", elapsed);
-    total += elapsed;
-
-    println!("Total {:?}", total);
-    total
-}
+)]
+pub struct Day09;
@@ -1,10 +1,7 @@
 //! https://adventofcode.com/2015/day/11
 //! https://adventofcode.com/2015/day/11/input
 
-use std::{
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
+use utils::Solution;
 
 const NEXT: [usize; 26] = [
     1, 2, 3, 4, 5, 6, 7, 9, 99, 10, 12, 99, 13, 15, 99, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 0,
@@ -92,36 +89,6 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) -> Duration {
-    let test_input = "abcdefgh".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2015/day_11_input.txt").unwrap()
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let parsed = parse(&puzzle_input);
-    let elapsed = start.elapsed();
-    println!("Parsed in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part1::solve(parsed.clone());
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part2::solve(parsed);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("Second part in {:?}", elapsed);
-    total += elapsed;
-
-    println!("Total {:?}", total);
-    total
-}
+#[derive(Solution)]
+#[example(part1 = "abcdffaa", input = "abcdefgh")]
+pub struct Day11;
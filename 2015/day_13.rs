@@ -1,13 +1,10 @@
 //! https://adventofcode.com/2015/day/13
 //! https://adventofcode.com/2015/day/13/input
 
-use std::{
-    collections::HashMap,
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
+use std::collections::HashMap;
 
 use itertools::Itertools;
+use utils::Solution;
 
 type Parsed = Vec<Vec<isize>>;
 
@@ -73,8 +70,10 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) -> Duration {
-    let test_input = "Alice would gain 54 happiness units by sitting next to Bob.
+#[derive(Solution)]
+#[example(
+    part1 = 330,
+    input = "Alice would gain 54 happiness units by sitting next to Bob.
 Alice would lose 79 happiness units by sitting next to Carol.
 Alice would lose 2 happiness units by sitting next to David.
 Bob would gain 83 happiness units by sitting next to Alice.
@@ -86,35 +85,5 @@ Carol would gain 55 happiness units by sitting next to David.
 David would gain 46 happiness units by sitting next to Alice.
 David would lose 7 happiness units by sitting next to Bob.
 David would gain 41 happiness units by sitting next to Carol."
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2015/day_13_input.txt").unwrap()
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let parsed = parse(&puzzle_input);
-    let elapsed = start.elapsed();
-    println!("Parsed in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part1::solve(parsed.clone());
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part2::solve(parsed);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("Second part in {:?}", elapsed);
-    total += elapsed;
-
-    println!("Total {:?}", total);
-    total
-}
+)]
+pub struct Day13;
@@ -2,12 +2,12 @@
 //! https://adventofcode.com/2015/day/15/input
 
 use std::{
-    fs::read_to_string,
     iter::Sum,
     ops::{AddAssign, Mul},
-    time::{Duration, Instant},
 };
 
+use utils::Solution;
+
 #[derive(Copy, Clone, Debug, Default)]
 pub struct Ingredient {
     capacity: isize,
@@ -146,38 +146,11 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) -> Duration {
-    let test_input = "Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8
+#[derive(Solution)]
+#[example(
+    part1 = 62842880,
+    part2 = 57600000,
+    input = "Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8
 Cinnamon: capacity 2, durability 3, flavor -2, texture -1, calories 3"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2015/day_15_input.txt").unwrap()
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let parsed = parse(&puzzle_input);
-    let elapsed = start.elapsed();
-    println!("Parsed in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part1::solve(parsed.clone());
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part2::solve(parsed);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("Second part in {:?}", elapsed);
-    total += elapsed;
-
-    println!("Total {:?}", total);
-    total
-}
+)]
+pub struct Day15;
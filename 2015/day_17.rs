@@ -1,10 +1,7 @@
 //! https://adventofcode.com/2015/day/17
 //! https://adventofcode.com/2015/day/17/input
 
-use std::{
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
+use utils::Solution;
 
 type Parsed = Vec<usize>;
 
@@ -16,7 +13,7 @@ fn parse(input: &str) -> Parsed {
 }
 
 pub mod part1 {
-    use super::parse;
+    use super::Parsed;
 
     fn explore(
         containers: &[usize],
@@ -45,8 +42,7 @@ pub mod part1 {
         result
     }
 
-    pub fn solve(input: &str, target: usize) -> usize {
-        let containers = parse(input);
+    pub fn solve(containers: Parsed, target: usize) -> usize {
         explore(
             &containers,
             0,
@@ -58,7 +54,7 @@ pub mod part1 {
 }
 
 pub mod part2 {
-    use super::parse;
+    use super::Parsed;
 
     fn explore(
         containers: &[usize],
@@ -98,8 +94,7 @@ pub mod part2 {
         states[container] = false;
     }
 
-    pub fn solve(input: &str, target: usize) -> usize {
-        let containers = parse(input);
+    pub fn solve(containers: Parsed, target: usize) -> usize {
         let mut solutions = vec![0; containers.len()];
         explore(
             &containers,
@@ -114,35 +109,16 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) -> Duration {
-    let test_input = "20
+#[derive(Solution)]
+#[solution(params = 150, parse = |input, _| parse(input))]
+#[example(
+    params = 25,
+    part1 = 4,
+    part2 = 3,
+    input = "20
 15
 10
 5
 5"
-    .to_owned();
-    let (puzzle_input, target) = if test {
-        (test_input, 25)
-    } else {
-        (read_to_string("../inputs/2015/day_17_input.txt").unwrap(), 150)
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let result = part1::solve(&puzzle_input, target);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part2::solve(&puzzle_input, target);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("Second part in {:?}", elapsed);
-    total += elapsed;
-
-    println!("Total {:?}", total);
-    total
-}
+)]
+pub struct Day17;
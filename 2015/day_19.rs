@@ -1,13 +1,11 @@
 //! https://adventofcode.com/2015/day/19
 //! https://adventofcode.com/2015/day/19/input
 
-use std::{
-    collections::{HashMap, HashSet},
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
+use std::collections::{HashMap, HashSet};
 
-type Parsed<'a> = (HashMap<&'a str, HashSet<&'a str>>, String);
+use utils::Solution;
+
+type Parsed = (HashMap<String, HashSet<String>>, String);
 
 fn parse(input: &str) -> Parsed {
     let mut lines = input.lines();
@@ -20,9 +18,9 @@ fn parse(input: &str) -> Parsed {
         let from = parts.next().unwrap();
         let to = parts.next().unwrap();
         replacements
-            .entry(from)
+            .entry(from.to_owned())
             .or_insert_with(HashSet::new)
-            .insert(to);
+            .insert(to.to_owned());
     }
     let molecule = lines.next().unwrap();
     (replacements, molecule.to_owned())
@@ -67,20 +65,23 @@ pub mod part1 {
 pub mod part2 {
     use std::collections::HashMap;
 
-    use rand::{prelude::SliceRandom, thread_rng};
+    use utils::random::{self, SliceRandom};
 
     use super::Parsed;
 
     pub fn solve((replacements, molecule): Parsed) -> usize {
         let mut reversed = HashMap::with_capacity(replacements.values().map(|v| v.len()).sum());
-        for (k, v) in replacements.into_iter() {
+        for (k, v) in replacements.iter() {
             for v in v {
-                reversed.entry(v).or_insert(k);
+                reversed.entry(v.as_str()).or_insert(k.as_str());
             }
         }
+        // sorted first, since the order of the keys changes from one run to the other
         let mut shuffled_rules: Vec<_> = reversed.keys().copied().collect();
+        shuffled_rules.sort_unstable();
+        let mut rng = random::rng();
         loop {
-            shuffled_rules.shuffle(&mut thread_rng());
+            shuffled_rules.shuffle(&mut rng);
             let mut molecule = molecule.clone();
             let mut steps = 0;
             while molecule != "e" {
@@ -99,43 +100,16 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) -> Duration {
-    let test_input = "e => H
+#[derive(Solution)]
+#[example(
+    part1 = 7,
+    part2 = 6,
+    input = "e => H
 e => O
 H => HO
 H => OH
 O => HH
 
 HOHOHO"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2015/day_19_input.txt").unwrap()
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let parsed = parse(&puzzle_input);
-    let elapsed = start.elapsed();
-    println!("Parsed in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part1::solve(parsed.clone());
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part2::solve(parsed);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("Second part in {:?}", elapsed);
-    total += elapsed;
-
-    println!("Total {:?}", total);
-    total
-}
+)]
+pub struct Day19;
@@ -1,18 +1,20 @@
 //! https://adventofcode.com/2015/day/20
 //! https://adventofcode.com/2015/day/20/input
 
-use std::{
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
+use utils::Solution;
 
 const MAX: usize = 1_000_000;
 
+type Parsed = usize;
+
+fn parse(input: &str) -> Parsed {
+    input.parse().unwrap()
+}
+
 pub mod part1 {
-    use super::MAX;
+    use super::{Parsed, MAX};
 
-    pub fn solve(input: &str) -> usize {
-        let target = input.parse().unwrap();
+    pub fn solve(target: Parsed) -> usize {
         let mut houses = vec![0; MAX];
         for elf in 1..=MAX {
             let addition = elf * 10;
@@ -32,10 +34,9 @@ pub mod part1 {
 }
 
 pub mod part2 {
-    use super::MAX;
+    use super::{Parsed, MAX};
 
-    pub fn solve(input: &str) -> usize {
-        let target = input.parse().unwrap();
+    pub fn solve(target: Parsed) -> usize {
         let mut houses = vec![0; MAX];
         for elf in 1..=MAX {
             let addition = elf * 11;
@@ -56,30 +57,6 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) -> Duration {
-    let test_input = "29000000".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2015/day_20_input.txt").unwrap()
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let result = part1::solve(&puzzle_input);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part2::solve(&puzzle_input);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("Second part in {:?}", elapsed);
-    total += elapsed;
-
-    println!("Total {:?}", total);
-    total
-}
+#[derive(Solution)]
+#[example(input = "29000000")]
+pub struct Day20;
@@ -1,10 +1,7 @@
 //! https://adventofcode.com/2015/day/21
 //! https://adventofcode.com/2015/day/21/input
 
-use std::{
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
+use utils::Solution;
 
 const WEAPONS: [(usize, isize, isize); 5] =
     [(8, 4, 0), (10, 5, 0), (25, 6, 0), (40, 7, 0), (74, 8, 0)];
@@ -19,7 +16,9 @@ const RINGS: [(usize, isize, isize); 6] = [
     (80, 0, 3),
 ];
 
-fn parse(input: &str) -> (isize, isize, isize) {
+type Parsed = (isize, isize, isize);
+
+fn parse(input: &str) -> Parsed {
     let mut lines = input.lines();
     let boss_hp = lines
         .next()
@@ -51,10 +50,9 @@ fn parse(input: &str) -> (isize, isize, isize) {
 pub mod part1 {
     use itertools::Itertools;
 
-    use super::{parse, ARMOR, RINGS, WEAPONS};
+    use super::{Parsed, ARMOR, RINGS, WEAPONS};
 
-    pub fn solve(input: &str) -> usize {
-        let boss = parse(input);
+    pub fn solve(boss: Parsed) -> usize {
         (0..WEAPONS.len())
             .cartesian_product((0..1).map(|_| 0..=ARMOR.len()).multi_cartesian_product())
             .cartesian_product((0..2).map(|_| 0..=RINGS.len()).multi_cartesian_product())
@@ -94,10 +92,9 @@ pub mod part1 {
 pub mod part2 {
     use itertools::Itertools;
 
-    use super::{parse, ARMOR, RINGS, WEAPONS};
+    use super::{Parsed, ARMOR, RINGS, WEAPONS};
 
-    pub fn solve(input: &str) -> usize {
-        let boss = parse(input);
+    pub fn solve(boss: Parsed) -> usize {
         (0..WEAPONS.len())
             .cartesian_product((0..1).map(|_| 0..=ARMOR.len()).multi_cartesian_product())
             .cartesian_product((0..2).map(|_| 0..=RINGS.len()).multi_cartesian_product())
@@ -134,30 +131,5 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) -> Duration {
-    let test_input = "".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2015/day_21_input.txt").unwrap()
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let result = part1::solve(&puzzle_input);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part2::solve(&puzzle_input);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("Second part in {:?}", elapsed);
-    total += elapsed;
-
-    println!("Total {:?}", total);
-    total
-}
+#[derive(Solution)]
+pub struct Day21;
@@ -1,10 +1,7 @@
 //! https://adventofcode.com/2015/day/22
 //! https://adventofcode.com/2015/day/22/input
 
-use std::{
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
+use utils::Solution;
 
 #[derive(Copy, Clone, Debug)]
 struct Spell {
@@ -165,10 +162,9 @@ fn fight(
 }
 
 pub mod part1 {
-    use super::{fight, parse, Character};
+    use super::{fight, Character, Parsed};
 
-    pub fn solve(input: &str, hp: isize, mana: usize) -> usize {
-        let boss = parse(input);
+    pub fn solve(boss: Parsed, hp: isize, mana: usize) -> usize {
         fight(
             Character {
                 hp,
@@ -190,10 +186,9 @@ pub mod part1 {
 }
 
 pub mod part2 {
-    use super::{fight, parse, Character};
+    use super::{fight, Character, Parsed};
 
-    pub fn solve(input: &str, hp: isize, mana: usize) -> usize {
-        let boss = parse(input);
+    pub fn solve(boss: Parsed, hp: isize, mana: usize) -> usize {
         fight(
             Character {
                 hp,
@@ -214,32 +209,17 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) -> Duration {
-    let test_input = "Hit Points: 14
+#[derive(Solution)]
+#[solution(
+    params = (50, 500),
+    parse = |input, _| parse(input),
+    part1 = |boss, (hp, mana)| part1::solve(boss, hp, mana),
+    part2 = |boss, (hp, mana)| part2::solve(boss, hp, mana)
+)]
+#[example(
+    params = (10, 250),
+    part1 = 641,
+    input = "Hit Points: 14
 Damage: 8"
-        .to_owned();
-    let (puzzle_input, hp, mana) = if test {
-        (test_input, 10, 250)
-    } else {
-        (read_to_string("../inputs/2015/day_22_input.txt").unwrap(), 50, 500)
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let result = part1::solve(&puzzle_input, hp, mana);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part2::solve(&puzzle_input, hp, mana);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("Second part in {:?}", elapsed);
-    total += elapsed;
-
-    println!("Total {:?}", total);
-    total
-}
+)]
+pub struct Day22;
@@ -1,59 +1,64 @@
 //! https://adventofcode.com/2015/day/23
 //! https://adventofcode.com/2015/day/23/input
 
-use std::{
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
+use std::str::FromStr;
 
-enum Instruction {
-    Hlf(usize),
-    Tpl(usize),
-    Inc(usize),
-    Jmp(isize),
-    Jie(usize, isize),
-    Jio(usize, isize),
-}
+use utils::{errors::ParseError, parsing::try_parse_lines, FromStr, Solution};
+
+/// Register `a` or `b`.
+#[derive(Copy, Clone)]
+pub struct Register(usize);
+
+impl FromStr for Register {
+    type Err = ParseError;
 
-impl From<&str> for Instruction {
-    fn from(string: &str) -> Self {
-        let parts: Vec<_> = string.split(' ').collect();
-        match parts[0] {
-            "hlf" => Instruction::Hlf((parts[1].chars().next().unwrap() as u8 - b'a') as usize),
-            "tpl" => Instruction::Tpl((parts[1].chars().next().unwrap() as u8 - b'a') as usize),
-            "inc" => Instruction::Inc((parts[1].chars().next().unwrap() as u8 - b'a') as usize),
-            "jmp" => Instruction::Jmp(parts[1].parse().unwrap()),
-            "jie" => Instruction::Jie(
-                (parts[1].chars().next().unwrap() as u8 - b'a') as usize,
-                parts[2].parse().unwrap(),
-            ),
-            "jio" => Instruction::Jio(
-                (parts[1].chars().next().unwrap() as u8 - b'a') as usize,
-                parts[2].parse().unwrap(),
-            ),
-            _ => panic!("Invalid instruction: {}", string),
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.as_bytes() {
+            &[name @ b'a'..=b'b'] => Ok(Register((name - b'a') as usize)),
+            _ => Err(ParseError::Invalid(format!(
+                "Expected a register, found `{}`",
+                value
+            ))),
         }
     }
 }
 
-fn parse(input: &str) -> Vec<Instruction> {
-    input.lines().map(Instruction::from).collect()
+#[derive(Copy, Clone, FromStr)]
+pub enum Instruction {
+    #[keyword("hlf")]
+    Hlf(Register),
+    #[keyword("tpl")]
+    Tpl(Register),
+    #[keyword("inc")]
+    Inc(Register),
+    #[keyword("jmp")]
+    Jmp(isize),
+    #[pattern("jie {0}, {1}")]
+    Jie(Register, isize),
+    #[pattern("jio {0}, {1}")]
+    Jio(Register, isize),
 }
 
-fn compute(a: usize, instructions: Vec<Instruction>) -> usize {
+type Parsed = Vec<Instruction>;
+
+fn parse(input: &str) -> Result<Parsed, ParseError> {
+    try_parse_lines(input)
+}
+
+fn compute(a: usize, instructions: Parsed) -> usize {
     let mut registers = [a, 0];
     let mut pc: isize = 0;
     while (pc as usize) < instructions.len() {
         pc += match instructions[pc as usize] {
-            Instruction::Hlf(reg) => {
+            Instruction::Hlf(Register(reg)) => {
                 registers[reg] /= 2;
                 1
             }
-            Instruction::Tpl(reg) => {
+            Instruction::Tpl(Register(reg)) => {
                 registers[reg] *= 3;
                 1
             }
-            Instruction::Inc(reg) => {
+            Instruction::Inc(Register(reg)) => {
                 registers[reg] += 1;
                 1
             }
@@ -61,7 +66,7 @@ fn compute(a: usize, instructions: Vec<Instruction>) -> usize {
                 pc += offset;
                 0
             }
-            Instruction::Jie(reg, offset) => {
+            Instruction::Jie(Register(reg), offset) => {
                 if registers[reg] % 2 == 0 {
                     pc += offset;
                     0
@@ -69,7 +74,7 @@ fn compute(a: usize, instructions: Vec<Instruction>) -> usize {
                     1
                 }
             }
-            Instruction::Jio(reg, offset) => {
+            Instruction::Jio(Register(reg), offset) => {
                 if registers[reg] == 1 {
                     pc += offset;
                     0
@@ -83,51 +88,25 @@ fn compute(a: usize, instructions: Vec<Instruction>) -> usize {
 }
 
 pub mod part1 {
-    use super::{compute, parse};
+    use super::{compute, Parsed};
 
-    pub fn solve(input: &str, a: usize) -> usize {
-        let instructions = parse(input);
+    pub fn solve(instructions: Parsed, a: usize) -> usize {
         compute(a, instructions)
     }
 }
 
 pub mod part2 {
-    use super::{compute, parse};
+    use super::{compute, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let instructions = parse(input);
+    pub fn solve(instructions: Parsed) -> usize {
         compute(1, instructions)
     }
 }
 
-pub fn main(test: bool) -> Duration {
-    let test_input = "inc a
+#[derive(Solution)]
+#[solution(part1 = |instructions| part1::solve(instructions, 0))]
+#[example(input = "inc a
 jio a, +2
 tpl a
-inc a"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2015/day_23_input.txt").unwrap()
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let result = part1::solve(&puzzle_input, 0);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part2::solve(&puzzle_input);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("Second part in {:?}", elapsed);
-    total += elapsed;
-
-    println!("Total {:?}", total);
-    total
-}
+inc a")]
+pub struct Day23;
@@ -1,12 +1,8 @@
 //! https://adventofcode.com/2015/day/24
 //! https://adventofcode.com/2015/day/24/input
 
-use std::{
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
-
 use itertools::Itertools;
+use utils::Solution;
 
 type Parsed = Vec<usize>;
 
@@ -68,8 +64,8 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) -> Duration {
-    let test_input = "1
+#[derive(Solution)]
+#[example(input = "1
 2
 3
 4
@@ -78,36 +74,5 @@ pub fn main(test: bool) -> Duration {
 8
 9
 10
-11"
-    .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2015/day_24_input.txt").unwrap()
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let parsed = parse(&puzzle_input);
-    let elapsed = start.elapsed();
-    println!("Parsed in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part1::solve(parsed.clone());
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part2::solve(parsed);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("Second part in {:?}", elapsed);
-    total += elapsed;
-
-    println!("Total {:?}", total);
-    total
-}
+11")]
+pub struct Day24;
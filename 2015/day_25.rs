@@ -3,10 +3,7 @@
 //! https://adventofcode.com/2015/day/25
 //! https://adventofcode.com/2015/day/25/input/input
 
-use std::{
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
+use utils::Solution;
 
 type Parsed = (usize, usize);
 
@@ -33,28 +30,10 @@ pub mod part1 {
     }
 }
 
-pub fn main(test: bool) -> Duration {
-    let test_input = "To continue, please consult the code grid in the manual.  Enter the code at row 5, column 3.".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2015/day_25_input.txt").unwrap()
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let parsed = parse(&puzzle_input);
-    let elapsed = start.elapsed();
-    println!("Parsed in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part1::solve(parsed);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    total
-}
+#[derive(Solution)]
+#[solution(single_part)]
+#[example(
+    part1 = 28094349,
+    input = "To continue, please consult the code grid in the manual.  Enter the code at row 5, column 3."
+)]
+pub struct Day25;
@@ -1,14 +1,16 @@
 //! https://adventofcode.com/2016/day/1
 //! https://adventofcode.com/2016/day/1/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
+#[derive(Copy, Clone)]
 enum Rotation {
     R,
     L,
 }
 
-struct Instruction {
+#[derive(Copy, Clone)]
+pub struct Instruction {
     rotation: Rotation,
     steps: isize,
 }
@@ -32,15 +34,16 @@ impl From<&str> for Instruction {
     }
 }
 
-fn parse(input: &str) -> Vec<Instruction> {
+type Parsed = Vec<Instruction>;
+
+fn parse(input: &str) -> Parsed {
     input.split(", ").map(Instruction::from).collect()
 }
 
 pub mod part1 {
-    use super::{parse, Instruction, Rotation};
+    use super::{Instruction, Parsed, Rotation};
 
-    pub fn solve(input: &str) -> usize {
-        let instructions = parse(input);
+    pub fn solve(instructions: Parsed) -> usize {
         let (mut dx, mut dy) = (-1, 0);
         let (mut x, mut y) = (0, 0);
         for Instruction { rotation, steps } in instructions {
@@ -58,10 +61,9 @@ pub mod part1 {
 pub mod part2 {
     use std::collections::HashSet;
 
-    use super::{parse, Instruction, Rotation};
+    use super::{Instruction, Parsed, Rotation};
 
-    pub fn solve(input: &str) -> usize {
-        let instructions = parse(input);
+    pub fn solve(instructions: Parsed) -> usize {
         let (mut dx, mut dy): (isize, _) = (-1, 0);
         let (mut x, mut y) = (0, 0);
         let mut visited = HashSet::new();
@@ -83,17 +85,6 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "R2, R2, R2".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2016/day_01_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[example(part1 = 2, input = "R2, R2, R2")]
+pub struct Day01;
@@ -1,9 +1,10 @@
 //! https://adventofcode.com/2016/day/2
 //! https://adventofcode.com/2016/day/2/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
-enum Movement {
+#[derive(Copy, Clone)]
+pub enum Movement {
     U,
     D,
     L,
@@ -22,7 +23,9 @@ impl From<char> for Movement {
     }
 }
 
-fn parse(input: &str) -> Vec<Vec<Movement>> {
+type Parsed = Vec<Vec<Movement>>;
+
+fn parse(input: &str) -> Parsed {
     input
         .lines()
         .map(|line| line.chars().map(Movement::from).collect())
@@ -30,10 +33,9 @@ fn parse(input: &str) -> Vec<Vec<Movement>> {
 }
 
 pub mod part1 {
-    use super::{parse, Movement};
+    use super::{Movement, Parsed};
 
-    pub fn solve(input: &str) -> String {
-        let movements = parse(input);
+    pub fn solve(movements: Parsed) -> String {
         let keyboard = [['1', '2', '3'], ['4', '5', '6'], ['7', '8', '9']];
         let (mut x, mut y) = (0, 0);
         let mut result = String::with_capacity(movements.len());
@@ -69,10 +71,9 @@ pub mod part1 {
 }
 
 pub mod part2 {
-    use super::{parse, Movement};
+    use super::{Movement, Parsed};
 
-    pub fn solve(input: &str) -> String {
-        let movements = parse(input);
+    pub fn solve(movements: Parsed) -> String {
         let keyboard = [
             [' ', ' ', '1', ' ', ' '],
             [' ', '2', '3', '4', ' '],
@@ -113,21 +114,13 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "ULL
+#[derive(Solution)]
+#[example(
+    part1 = 1985,
+    part2 = "5DB3",
+    input = "ULL
 RRDDD
 LURDL
 UUUUD"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2016/day_02_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day02;
@@ -1,9 +1,26 @@
 //! https://adventofcode.com/2016/day/3
 //! https://adventofcode.com/2016/day/3/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
 type Triangle = (usize, usize, usize);
+
+type Parsed = Vec<Triangle>;
+
+fn parse(input: &str) -> Parsed {
+    input
+        .lines()
+        .map(|line| {
+            let mut parts = line.split_whitespace();
+            (
+                parts.next().unwrap().parse().unwrap(),
+                parts.next().unwrap().parse().unwrap(),
+                parts.next().unwrap().parse().unwrap(),
+            )
+        })
+        .collect()
+}
+
 fn generic_solve(triangles: Vec<Triangle>) -> usize {
     triangles
         .iter()
@@ -16,51 +33,29 @@ fn generic_solve(triangles: Vec<Triangle>) -> usize {
 }
 
 pub mod part1 {
-    use super::{generic_solve, Triangle};
+    use super::{generic_solve, Parsed};
 
-    fn parse(input: &str) -> Vec<Triangle> {
-        input
-            .lines()
-            .map(|line| {
-                let mut parts = line.split_whitespace();
-                (
-                    parts.next().unwrap().parse().unwrap(),
-                    parts.next().unwrap().parse().unwrap(),
-                    parts.next().unwrap().parse().unwrap(),
-                )
-            })
-            .collect()
-    }
-
-    pub fn solve(input: &str) -> usize {
-        generic_solve(parse(input))
+    pub fn solve(triangles: Parsed) -> usize {
+        generic_solve(triangles)
     }
 }
 
 pub mod part2 {
-    use super::{generic_solve, Triangle};
+    use super::{generic_solve, Parsed};
 
-    fn parse(input: &str) -> Vec<Triangle> {
-        let numbers: Vec<Vec<usize>> = input
-            .lines()
-            .map(|line| line.split_whitespace().flat_map(str::parse).collect())
-            .collect();
-        let mut result = vec![];
-        for i in (0..numbers.len()).step_by(3) {
-            for j in 0..3 {
-                result.push((numbers[i][j], numbers[i + 1][j], numbers[i + 2][j]))
-            }
+    pub fn solve(rows: Parsed) -> usize {
+        let mut triangles = vec![];
+        for rows in rows.chunks(3) {
+            triangles.push((rows[0].0, rows[1].0, rows[2].0));
+            triangles.push((rows[0].1, rows[1].1, rows[2].1));
+            triangles.push((rows[0].2, rows[1].2, rows[2].2));
         }
-        result
-    }
-
-    pub fn solve(input: &str) -> usize {
-        generic_solve(parse(input))
+        generic_solve(triangles)
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "775  785  361
+#[derive(Solution)]
+#[example(input = "775  785  361
   622  375  125
   297  839  375
   245   38  891
@@ -123,17 +118,5 @@ pub fn main(test: bool) {
    64  430  347
   784  534  237
   740  485  470
-  570  894  790"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2016/day_03_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+  570  894  790")]
+pub struct Day03;
@@ -1,9 +1,10 @@
 //! https://adventofcode.com/2016/day/4
 //! https://adventofcode.com/2016/day/4/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
-struct Room {
+#[derive(Clone)]
+pub struct Room {
     letters: Vec<char>,
     id: usize,
     checksum: String,
@@ -27,7 +28,9 @@ impl From<&str> for Room {
     }
 }
 
-fn parse(input: &str) -> Vec<Room> {
+type Parsed = Vec<Room>;
+
+fn parse(input: &str) -> Parsed {
     input.lines().map(Room::from).collect()
 }
 
@@ -46,10 +49,10 @@ fn is_valid(room: &Room) -> bool {
 }
 
 pub mod part1 {
-    use super::{is_valid, parse};
+    use super::{is_valid, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        parse(input)
+    pub fn solve(parsed: Parsed) -> usize {
+        parsed
             .iter()
             .filter_map(|room| is_valid(room).then_some(room.id))
             .sum()
@@ -57,10 +60,9 @@ pub mod part1 {
 }
 
 pub mod part2 {
-    use super::{is_valid, parse};
+    use super::{is_valid, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let rooms = parse(input);
+    pub fn solve(rooms: Parsed) -> usize {
         let rooms: Vec<_> = rooms.iter().filter(|room| is_valid(room)).collect();
         for room in rooms {
             let name: String = room
@@ -76,21 +78,12 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "aaaaa-bbb-z-y-x-123[abxyz]
+#[derive(Solution)]
+#[example(
+    part1 = 1514,
+    input = "aaaaa-bbb-z-y-x-123[abxyz]
 a-b-c-d-e-f-g-h-987[abcde]
 not-a-real-room-404[oarel]
 totally-real-room-200[decoy]"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2016/day_04_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day04;
@@ -1,7 +1,7 @@
 //! https://adventofcode.com/2016/day/5
 //! https://adventofcode.com/2016/day/5/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
 const CHARS: [char; 16] = [
     '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'a', 'b', 'c', 'd', 'e', 'f',
@@ -62,17 +62,7 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "abc".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2016/day_05_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[solution(unparsed)]
+#[example(input = "abc")]
+pub struct Day05;
@@ -1,9 +1,11 @@
 //! https://adventofcode.com/2016/day/6
 //! https://adventofcode.com/2016/day/6/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
-fn parse(input: &str) -> Vec<Vec<char>> {
+type Parsed = Vec<Vec<char>>;
+
+fn parse(input: &str) -> Parsed {
     input.lines().map(|line| line.chars().collect()).collect()
 }
 
@@ -28,25 +30,25 @@ fn find_message(messages: Vec<Vec<char>>, coefficient: isize) -> String {
 }
 
 pub mod part1 {
-    use super::{find_message, parse};
+    use super::{find_message, Parsed};
 
-    pub fn solve(input: &str) -> String {
-        let messages = parse(input);
+    pub fn solve(messages: Parsed) -> String {
         find_message(messages, 1)
     }
 }
 
 pub mod part2 {
-    use super::{find_message, parse};
+    use super::{find_message, Parsed};
 
-    pub fn solve(input: &str) -> String {
-        let messages = parse(input);
+    pub fn solve(messages: Parsed) -> String {
         find_message(messages, -1)
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "eedadn
+#[derive(Solution)]
+#[example(
+    part1 = "easter",
+    input = "eedadn
 drvtee
 eandsr
 raavrd
@@ -62,16 +64,5 @@ vntsnd
 vrdear
 dvrsen
 enarar"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2016/day_06_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day06;
@@ -1,7 +1,7 @@
 //! https://adventofcode.com/2016/day/7
 //! https://adventofcode.com/2016/day/7/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
 fn parse(input: &str) -> Vec<&str> {
     input.lines().collect()
@@ -78,21 +78,13 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "aba[bab]xyz
+#[derive(Solution)]
+#[solution(unparsed)]
+#[example(
+    part2 = 3,
+    input = "aba[bab]xyz
 xyx[xyx]xyx
 aaa[kek]eke
 zazbz[bzb]cdb"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2016/day_07_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day07;
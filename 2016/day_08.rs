@@ -1,7 +1,7 @@
 //! https://adventofcode.com/2016/day/8
 //! https://adventofcode.com/2016/day/8/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
 #[derive(Copy, Clone, Debug)]
 enum Operation {
@@ -25,7 +25,7 @@ impl From<&str> for Operation {
 }
 
 #[derive(Copy, Clone, Debug)]
-struct Instruction {
+pub struct Instruction {
     operation: Operation,
     a: usize,
     b: usize,
@@ -59,61 +59,79 @@ impl From<&str> for Instruction {
     }
 }
 
-fn parse(input: &str) -> Vec<Instruction> {
+type Parsed = Vec<Instruction>;
+
+fn parse(input: &str) -> Parsed {
     input.lines().map(Instruction::from).collect()
 }
 
-pub mod part1 {
-    use super::{parse, Instruction, Operation};
-
-    pub fn solve(input: &str, width: usize, height: usize) -> usize {
-        let instructions = parse(input);
-        let mut screen = vec![vec![0; width]; height];
-        for Instruction { operation, a, b } in instructions {
-            match operation {
-                Operation::Rect => {
-                    for row in &mut screen[0..b] {
-                        for pixel in &mut row[0..a] {
-                            *pixel = 1;
-                        }
+fn draw(instructions: Parsed, width: usize, height: usize) -> Vec<Vec<usize>> {
+    let mut screen = vec![vec![0; width]; height];
+    for Instruction { operation, a, b } in instructions {
+        match operation {
+            Operation::Rect => {
+                for row in &mut screen[0..b] {
+                    for pixel in &mut row[0..a] {
+                        *pixel = 1;
                     }
                 }
-                Operation::Row => {
-                    screen[a].rotate_right(b);
+            }
+            Operation::Row => {
+                screen[a].rotate_right(b);
+            }
+            Operation::Column => {
+                let mut new_col = vec![0; screen.len()];
+                for (i, row) in screen.iter().enumerate() {
+                    new_col[(i + b) % screen.len()] = row[a]
                 }
-                Operation::Column => {
-                    let mut new_col = vec![0; screen.len()];
-                    for (i, row) in screen.iter().enumerate() {
-                        new_col[(i + b) % screen.len()] = row[a]
-                    }
-                    for (i, &pixel) in new_col.iter().enumerate() {
-                        screen[i][a] = pixel;
-                    }
+                for (i, &pixel) in new_col.iter().enumerate() {
+                    screen[i][a] = pixel;
                 }
             }
         }
-        for row in &screen {
-            for &pixel in row {
-                print!("{}", if pixel == 1 { '#' } else { '.' });
-            }
-            println!();
-        }
-        screen.iter().flatten().sum()
     }
+    screen
 }
 
-pub fn main(test: bool) {
-    let test_input = "rect 3x2
+pub mod part1 {
+    use super::{draw, Parsed};
+
+    pub fn solve(instructions: Parsed, width: usize, height: usize) -> usize {
+        draw(instructions, width, height).iter().flatten().sum()
+    }
+}
+
+pub mod part2 {
+    use super::{draw, Parsed};
+
+    /// The letters have to be read from the screen.
+    pub fn solve(instructions: Parsed, width: usize, height: usize) -> String {
+        draw(instructions, width, height)
+            .iter()
+            .map(|row| {
+                let row: String = row
+                    .iter()
+                    .map(|&pixel| if pixel == 1 { '#' } else { '.' })
+                    .collect();
+                format!("\n{}", row)
+            })
+            .collect()
+    }
+}
+
+#[derive(Solution)]
+#[solution(
+    params = (50, 6),
+    parse = |input, _| parse(input),
+    part1 = |instructions, (width, height)| part1::solve(instructions, width, height),
+    part2 = |instructions, (width, height)| part2::solve(instructions, width, height)
+)]
+#[example(
+    params = (7, 3),
+    part1 = 6,
+    input = "rect 3x2
 rotate column x=1 by 1
 rotate row y=0 by 4
 rotate column x=1 by 1"
-        .to_owned();
-    let (puzzle_input, w, h) = if test {
-        (test_input, 7, 3)
-    } else {
-        (read_to_string("../inputs/2016/day_08_input.txt").unwrap(), 50, 6)
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input, w, h));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day08;
@@ -1,9 +1,11 @@
 //! https://adventofcode.com/2016/day/9
 //! https://adventofcode.com/2016/day/9/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
-fn parse(input: &str) -> Vec<char> {
+type Parsed = Vec<char>;
+
+fn parse(input: &str) -> Parsed {
     input
         .split_whitespace()
         .flat_map(|line| line.chars())
@@ -11,10 +13,9 @@ fn parse(input: &str) -> Vec<char> {
 }
 
 pub mod part1 {
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let file = parse(input);
+    pub fn solve(file: Parsed) -> usize {
         let mut i = 0;
         let mut decompressed_len = 0;
         while i < file.len() {
@@ -46,7 +47,7 @@ pub mod part1 {
 }
 
 pub mod part2 {
-    use super::parse;
+    use super::Parsed;
 
     fn decompress(file: &Vec<char>, start: usize, end: usize) -> usize {
         let mut decompressed_len = 0;
@@ -78,23 +79,11 @@ pub mod part2 {
         decompressed_len
     }
 
-    pub fn solve(input: &str) -> usize {
-        let file = parse(input);
+    pub fn solve(file: Parsed) -> usize {
         decompress(&file, 0, file.len())
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "X(8x2)(3x3)ABCY".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2016/day_09_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[example(part1 = 18, part2 = 20, input = "X(8x2)(3x3)ABCY")]
+pub struct Day09;
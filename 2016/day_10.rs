@@ -1,7 +1,7 @@
 //! https://adventofcode.com/2016/day/10
 //! https://adventofcode.com/2016/day/10/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
 #[derive(Copy, Clone, Debug)]
 enum Destination {
@@ -16,12 +16,14 @@ impl Default for Destination {
 }
 
 #[derive(Copy, Clone, Debug, Default)]
-struct Bot {
+pub struct Bot {
     low: Destination,
     high: Destination,
 }
 
-fn parse(input: &str) -> (Vec<Option<usize>>, Vec<Bot>, usize) {
+type Parsed = (Vec<Option<usize>>, Vec<Bot>, usize);
+
+fn parse(input: &str) -> Parsed {
     let values: Vec<_> = input
         .lines()
         .filter(|line| line.starts_with('v'))
@@ -85,10 +87,9 @@ fn parse(input: &str) -> (Vec<Option<usize>>, Vec<Bot>, usize) {
 pub mod part1 {
     use std::collections::VecDeque;
 
-    use super::{parse, Destination};
+    use super::{Destination, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let (values, bots, _) = parse(input);
+    pub fn solve((values, bots, _): Parsed) -> usize {
         let mut states = vec![vec![]; bots.len()];
         let mut queue = VecDeque::new();
         for (value, bot) in values
@@ -127,10 +128,9 @@ pub mod part1 {
 pub mod part2 {
     use std::collections::VecDeque;
 
-    use super::{parse, Destination};
+    use super::{Destination, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let (values, bots, outputs) = parse(input);
+    pub fn solve((values, bots, outputs): Parsed) -> usize {
         let mut states = vec![vec![]; bots.len()];
         let mut outputs = vec![0; outputs + 1];
         let mut queue = VecDeque::new();
@@ -173,23 +173,11 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "value 5 goes to bot 2
+#[derive(Solution)]
+#[example(input = "value 5 goes to bot 2
 bot 2 gives low to bot 1 and high to bot 0
 value 3 goes to bot 1
 bot 1 gives low to output 1 and high to bot 0
 bot 0 gives low to output 2 and high to output 0
-value 2 goes to bot 2"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2016/day_10_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+value 2 goes to bot 2")]
+pub struct Day10;
@@ -1,13 +1,10 @@
 //! https://adventofcode.com/2016/day/11
 //! https://adventofcode.com/2016/day/11/input
 
-use std::{
-    collections::{hash_map::Entry, HashMap},
-    fs::read_to_string,
-    time::Instant,
-};
+use std::collections::{hash_map::Entry, HashMap};
 
 use regex::Regex;
+use utils::Solution;
 
 const UP: u64 = 0;
 const DOWN: u64 = 1;
@@ -17,7 +14,7 @@ const FIRST_FLOOR: u64 = 0;
 const LAST_FLOOR: u64 = 3;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
-struct State {
+pub struct State {
     items: u64,
     elevator: usize,
 }
@@ -26,7 +23,9 @@ fn gm(generator: u64, microchip: u64) -> u64 {
     1 << (generator << 4) << (microchip << 2)
 }
 
-fn parse(input: &str) -> (State, State) {
+type Parsed = (State, State);
+
+fn parse(input: &str) -> Parsed {
     #[derive(Debug, Clone, Copy)]
     struct Pair {
         generator: u64,
@@ -185,39 +184,29 @@ fn solve_generic(start: State, end: State) -> usize {
 }
 
 pub mod part1 {
-    use super::{parse, solve_generic};
+    use super::{solve_generic, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let (start, end) = parse(input);
+    pub fn solve((start, end): Parsed) -> usize {
         solve_generic(start, end)
     }
 }
 
 pub mod part2 {
-    use super::{gm, parse, solve_generic, FIRST_FLOOR, LAST_FLOOR};
+    use super::{gm, solve_generic, Parsed, FIRST_FLOOR, LAST_FLOOR};
 
-    pub fn solve(input: &str) -> usize {
-        let (mut start, mut end) = parse(input);
+    pub fn solve((mut start, mut end): Parsed) -> usize {
         start.items += 2 * gm(FIRST_FLOOR, FIRST_FLOOR);
         end.items += 2 * gm(LAST_FLOOR, LAST_FLOOR);
         solve_generic(start, end)
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "The first floor contains a hydrogen-compatible microchip and a lithium-compatible microchip.
+#[derive(Solution)]
+#[example(
+    part1 = 11,
+    input = "The first floor contains a hydrogen-compatible microchip and a lithium-compatible microchip.
 The second floor contains a hydrogen generator.
 The third floor contains a lithium generator.
-The fourth floor contains nothing relevant.".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2016/day_11_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+The fourth floor contains nothing relevant."
+)]
+pub struct Day11;
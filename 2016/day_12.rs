@@ -1,68 +1,79 @@
 //! https://adventofcode.com/2016/day/12
 //! https://adventofcode.com/2016/day/12/input
 
-use std::{fs::read_to_string, time::Instant};
+use std::str::FromStr;
+
+use utils::{errors::ParseError, parsing::try_parse_lines, FromStr, Solution};
+
+/// One of the registers `a` to `d`.
+#[derive(Copy, Clone, Debug)]
+pub struct Register(usize);
+
+impl FromStr for Register {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.as_bytes() {
+            &[name @ b'a'..=b'd'] => Ok(Register((name - b'a') as usize)),
+            _ => Err(ParseError::Invalid(format!(
+                "Expected a register, found `{}`",
+                value
+            ))),
+        }
+    }
+}
 
 #[derive(Copy, Clone, Debug)]
-enum Val {
+pub enum Val {
     Lit(isize),
-    Reg(usize),
+    Reg(Register),
 }
 
 impl Val {
     fn evaluate(&self, registers: &[isize; 4]) -> isize {
         match *self {
             Val::Lit(lit) => lit,
-            Val::Reg(reg) => registers[reg],
+            Val::Reg(Register(reg)) => registers[reg],
         }
     }
 }
 
-impl From<&str> for Val {
-    fn from(string: &str) -> Self {
-        if string.chars().next().unwrap().is_ascii_digit() {
-            Val::Lit(string.parse().unwrap())
-        } else {
-            Val::Reg(string.chars().next().unwrap() as usize - 'a' as usize)
+impl FromStr for Val {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.parse() {
+            Ok(lit) => Ok(Val::Lit(lit)),
+            Err(_) => value.parse().map(Val::Reg),
         }
     }
 }
 
-#[derive(Copy, Clone, Debug)]
-enum Instruction {
-    Copy(Val, usize),
-    Inc(usize),
-    Dec(usize),
+#[derive(Copy, Clone, Debug, FromStr)]
+pub enum Instruction {
+    #[keyword("cpy")]
+    Copy(Val, Register),
+    #[keyword("inc")]
+    Inc(Register),
+    #[keyword("dec")]
+    Dec(Register),
+    #[keyword("jnz")]
     Jnz(Val, isize),
 }
 
-impl From<&str> for Instruction {
-    fn from(string: &str) -> Self {
-        let parts: Vec<_> = string.split_whitespace().collect();
-        match parts[0].chars().next().unwrap() {
-            'c' => Instruction::Copy(
-                Val::from(parts[1]),
-                parts[2].chars().next().unwrap() as usize - 'a' as usize,
-            ),
-            'i' => Instruction::Inc(parts[1].chars().next().unwrap() as usize - 'a' as usize),
-            'd' => Instruction::Dec(parts[1].chars().next().unwrap() as usize - 'a' as usize),
-            'j' => Instruction::Jnz(Val::from(parts[1]), parts[2].parse().unwrap()),
-            _ => panic!("Invalid instruction: {}", string),
-        }
-    }
-}
+type Parsed = Vec<Instruction>;
 
-fn parse(input: &str) -> Vec<Instruction> {
-    input.lines().map(Instruction::from).collect()
+fn parse(input: &str) -> Result<Parsed, ParseError> {
+    try_parse_lines(input)
 }
 
-fn run(instructions: Vec<Instruction>, registers: &mut [isize; 4]) {
+fn run(instructions: Parsed, registers: &mut [isize; 4]) {
     let mut pc = 0;
     while (pc as usize) < instructions.len() {
         match instructions[pc as usize] {
-            Instruction::Copy(val, reg) => registers[reg] = val.evaluate(registers),
-            Instruction::Inc(reg) => registers[reg] += 1,
-            Instruction::Dec(reg) => registers[reg] -= 1,
+            Instruction::Copy(val, Register(reg)) => registers[reg] = val.evaluate(registers),
+            Instruction::Inc(Register(reg)) => registers[reg] += 1,
+            Instruction::Dec(Register(reg)) => registers[reg] -= 1,
             Instruction::Jnz(val, offset) => {
                 if val.evaluate(registers) != 0 {
                     pc = pc + offset - 1
@@ -74,10 +85,9 @@ fn run(instructions: Vec<Instruction>, registers: &mut [isize; 4]) {
 }
 
 pub mod part1 {
-    use super::{parse, run};
+    use super::{run, Parsed};
 
-    pub fn solve(input: &str) -> isize {
-        let instructions = parse(input);
+    pub fn solve(instructions: Parsed) -> isize {
         let mut registers = [0; 4];
         run(instructions, &mut registers);
         registers[0]
@@ -85,33 +95,23 @@ pub mod part1 {
 }
 
 pub mod part2 {
-    use super::{parse, run};
+    use super::{run, Parsed};
 
-    pub fn solve(input: &str) -> isize {
-        let instructions = parse(input);
+    pub fn solve(instructions: Parsed) -> isize {
         let mut registers = [0, 0, 1, 0];
         run(instructions, &mut registers);
         registers[0]
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "cpy 41 a
+#[derive(Solution)]
+#[example(
+    part1 = 42,
+    input = "cpy 41 a
 inc a
 inc a
 dec a
 jnz a 2
 dec a"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2016/day_12_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day12;
@@ -1,13 +1,13 @@
 //! https://adventofcode.com/2016/day/13
 //! https://adventofcode.com/2016/day/13/input
 
-use std::{
-    collections::{HashSet, VecDeque},
-    fs::read_to_string,
-    time::Instant,
-};
+use std::collections::{HashSet, VecDeque};
 
-fn parse(input: &str) -> usize {
+use utils::Solution;
+
+type Parsed = usize;
+
+fn parse(input: &str) -> Parsed {
     input.parse().unwrap()
 }
 
@@ -56,34 +56,21 @@ fn is_open(x: usize, y: usize, number: usize) -> bool {
 }
 
 pub mod part1 {
-    use super::{bfs, parse};
+    use super::{bfs, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let number = parse(input);
+    pub fn solve(number: Parsed) -> usize {
         bfs(number, (31, 39), usize::MAX).0
     }
 }
 
 pub mod part2 {
-    use super::{bfs, parse};
+    use super::{bfs, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let number = parse(input);
+    pub fn solve(number: Parsed) -> usize {
         bfs(number, (52, 0), 50).1
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "10".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2016/day_13_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[example(input = "10")]
+pub struct Day13;
@@ -1,10 +1,9 @@
 //! https://adventofcode.com/2016/day/14
 //! https://adventofcode.com/2016/day/14/input
 
-use std::{fs::read_to_string, time::Instant};
-
 use md5::compute;
 use rayon::prelude::*;
+use utils::Solution;
 
 #[allow(clippy::ptr_arg)]
 fn find_hash(input: &String, i: usize, levels: usize) -> Vec<char> {
@@ -80,17 +79,7 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "abc".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2016/day_14_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[solution(unparsed)]
+#[example(part1 = 22728, input = "abc")]
+pub struct Day14;
@@ -1,10 +1,10 @@
 //! https://adventofcode.com/2016/day/15
 //! https://adventofcode.com/2016/day/15/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
 #[derive(Copy, Clone, Debug)]
-struct Disc {
+pub struct Disc {
     positions: usize,
     begin: usize,
 }
@@ -19,7 +19,9 @@ impl From<&str> for Disc {
     }
 }
 
-fn parse(input: &str) -> Vec<Disc> {
+type Parsed = Vec<Disc>;
+
+fn parse(input: &str) -> Parsed {
     input.lines().map(Disc::from).collect()
 }
 
@@ -40,19 +42,17 @@ fn find_t(discs: Vec<Disc>) -> usize {
 }
 
 pub mod part1 {
-    use super::{find_t, parse};
+    use super::{find_t, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let discs = parse(input);
+    pub fn solve(discs: Parsed) -> usize {
         find_t(discs)
     }
 }
 
 pub mod part2 {
-    use super::{find_t, parse, Disc};
+    use super::{find_t, Disc, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let mut discs = parse(input);
+    pub fn solve(mut discs: Parsed) -> usize {
         discs.push(Disc {
             positions: 11,
             begin: 0,
@@ -61,19 +61,10 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "Disc #1 has 5 positions; at time=0, it is at position 4.
+#[derive(Solution)]
+#[example(
+    part1 = 5,
+    input = "Disc #1 has 5 positions; at time=0, it is at position 4.
 Disc #2 has 2 positions; at time=0, it is at position 1."
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2016/day_15_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day15;
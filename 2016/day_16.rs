@@ -1,7 +1,7 @@
 //! https://adventofcode.com/2016/day/16
 //! https://adventofcode.com/2016/day/16/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
 fn find_checksum(mut disc: Vec<u8>, disc_length: usize) -> String {
     while disc.len() < disc_length {
@@ -48,17 +48,7 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "10000".to_owned();
-    let (puzzle_input, disc_length) = if test {
-        (test_input, 20)
-    } else {
-        (read_to_string("../inputs/2016/day_16_input.txt").unwrap(), 272)
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input, disc_length));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[solution(unparsed, params = 272, part2 = |input, _| part2::solve(input))]
+#[example(params = 20, part1 = "01100", input = "10000")]
+pub struct Day16;
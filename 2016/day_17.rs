@@ -1,7 +1,7 @@
 //! https://adventofcode.com/2016/day/17
 //! https://adventofcode.com/2016/day/17/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
 const DIRECTIONS: [(char, (isize, isize)); 4] =
     [('U', (0, -1)), ('D', (0, 1)), ('L', (-1, 0)), ('R', (1, 0))];
@@ -62,17 +62,11 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "ulqzkmiv".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2016/day_17_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[solution(unparsed)]
+#[example(
+    part1 = "DRURDRUDDLLDLUURRDULRLDUUDDDRR",
+    part2 = 830,
+    input = "ulqzkmiv"
+)]
+pub struct Day17;
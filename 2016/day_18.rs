@@ -1,9 +1,11 @@
 //! https://adventofcode.com/2016/day/18
 //! https://adventofcode.com/2016/day/18/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
-fn parse(input: &str) -> Vec<bool> {
+type Parsed = Vec<bool>;
+
+fn parse(input: &str) -> Parsed {
     input.chars().map(|char| char == '.').collect()
 }
 
@@ -30,32 +32,26 @@ fn make_rows(mut row: Vec<bool>, rows: usize) -> usize {
 }
 
 pub mod part1 {
-    use super::{make_rows, parse};
+    use super::{make_rows, Parsed};
 
-    pub fn solve(input: &str, rows: usize) -> usize {
-        make_rows(parse(input), rows)
+    pub fn solve(row: Parsed, rows: usize) -> usize {
+        make_rows(row, rows)
     }
 }
 
 pub mod part2 {
-    use super::{make_rows, parse};
+    use super::{make_rows, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        make_rows(parse(input), 400_000)
+    pub fn solve(row: Parsed) -> usize {
+        make_rows(row, 400_000)
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = ".^^.^.^^^^".to_owned();
-    let (puzzle_input, rows) = if test {
-        (test_input, 10)
-    } else {
-        (read_to_string("../inputs/2016/day_18_input.txt").unwrap(), 40)
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input, rows));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[solution(
+    params = 40,
+    parse = |input, _| parse(input),
+    part2 = |row, _| part2::solve(row)
+)]
+#[example(params = 10, part1 = 38, input = ".^^.^.^^^^")]
+pub struct Day18;
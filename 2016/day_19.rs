@@ -1,16 +1,18 @@
 //! https://adventofcode.com/2016/day/19
 //! https://adventofcode.com/2016/day/19/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
 #[derive(Copy, Clone, Debug, Default)]
-struct Elf {
+pub struct Elf {
     prev: usize,
     next: usize,
     presents: usize,
 }
 
-fn parse(input: &str) -> Vec<Elf> {
+type Parsed = Vec<Elf>;
+
+fn parse(input: &str) -> Parsed {
     let elves = input.parse().unwrap();
     let mut result = vec![Elf::default(); elves];
     for (i, elf) in result.iter_mut().enumerate() {
@@ -22,10 +24,9 @@ fn parse(input: &str) -> Vec<Elf> {
 }
 
 pub mod part1 {
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let mut elves = parse(input);
+    pub fn solve(mut elves: Parsed) -> usize {
         let mut current = 0;
         while elves[current].next != current {
             let next = elves[current].next;
@@ -43,8 +44,10 @@ pub mod part1 {
 pub mod part2 {
     use std::collections::VecDeque;
 
-    pub fn solve(input: &str) -> usize {
-        let elves = input.parse().unwrap();
+    use super::Parsed;
+
+    pub fn solve(elves: Parsed) -> usize {
+        let elves = elves.len();
         let split = elves / 2;
         let mut left = VecDeque::with_capacity(split);
         let mut right = VecDeque::with_capacity(split + 1);
@@ -72,17 +75,6 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "5".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2016/day_19_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[example(part1 = 3, part2 = 2, input = "5")]
+pub struct Day19;
@@ -1,10 +1,10 @@
 //! https://adventofcode.com/2016/day/20
 //! https://adventofcode.com/2016/day/20/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
 #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
-struct Range {
+pub struct Range {
     left: usize,
     right: usize,
 }
@@ -19,17 +19,18 @@ impl From<&str> for Range {
     }
 }
 
-fn parse(input: &str) -> Vec<Range> {
+type Parsed = Vec<Range>;
+
+fn parse(input: &str) -> Parsed {
     let mut result: Vec<_> = input.lines().map(Range::from).collect();
     result.sort();
     result
 }
 
 pub mod part1 {
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let ranges = parse(input);
+    pub fn solve(ranges: Parsed) -> usize {
         if ranges[0].left > 0 {
             return 0;
         }
@@ -48,10 +49,9 @@ pub mod part1 {
 }
 
 pub mod part2 {
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let ranges = parse(input);
+    pub fn solve(ranges: Parsed) -> usize {
         if ranges[0].left > 0 {
             return 0;
         }
@@ -71,20 +71,11 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "5-8
+#[derive(Solution)]
+#[example(
+    part1 = 3,
+    input = "5-8
 0-2
 4-7"
-    .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2016/day_20_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day20;
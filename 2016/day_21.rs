@@ -1,55 +1,96 @@
 //! https://adventofcode.com/2016/day/21
 //! https://adventofcode.com/2016/day/21/input
 
-use std::{fs::read_to_string, time::Instant};
+use std::str::FromStr;
 
+use utils::{
+    errors::ParseError,
+    generate::{distinct, Rng, Seeded},
+    parsing::try_parse_lines,
+    FromStr, Solution,
+};
+
+/// A number of steps, as in `1 step` or `3 steps`.
 #[derive(Copy, Clone, Debug)]
-enum Op {
+pub struct Steps(isize);
+
+impl FromStr for Steps {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let steps = value
+            .strip_suffix(" steps")
+            .or_else(|| value.strip_suffix(" step"))
+            .ok_or_else(|| {
+                ParseError::Invalid(format!("Expected `<n> steps`, found `{}`", value))
+                    .at(value, value)
+            })?;
+        steps
+            .parse()
+            .map(Steps)
+            .map_err(|error| ParseError::new("steps", error).at(value, steps))
+    }
+}
+
+#[derive(Copy, Clone, Debug, FromStr)]
+pub enum Op {
+    #[pattern("swap position {0} with position {1}")]
     SwapPos(usize, usize),
-    SwapLetter(usize, usize),
-    RotSteps(isize),
-    RotLetter(usize),
+    #[pattern("swap letter {0} with letter {1}")]
+    SwapLetter(char, char),
+    #[pattern("rotate left {0}")]
+    RotLeft(Steps),
+    #[pattern("rotate right {0}")]
+    RotRight(Steps),
+    #[pattern("rotate based on position of letter {0}")]
+    RotLetter(char),
+    #[pattern("reverse positions {0} through {1}")]
     Rev(usize, usize),
+    #[pattern("move position {0} to position {1}")]
     Mov(usize, usize),
 }
 
-impl From<&str> for Op {
-    fn from(string: &str) -> Self {
-        let parts: Vec<_> = string.split_whitespace().collect();
-        if string.starts_with("swap p") {
-            Op::SwapPos(parts[2].parse().unwrap(), parts[5].parse().unwrap())
-        } else if string.starts_with("swap l") {
-            Op::SwapLetter(
-                parts[2].chars().next().unwrap() as usize - 'a' as usize,
-                parts[5].chars().next().unwrap() as usize - 'a' as usize,
-            )
-        } else if string.starts_with("rotate l") {
-            Op::RotSteps(-parts[2].parse::<isize>().unwrap())
-        } else if string.starts_with("rotate r") {
-            Op::RotSteps(parts[2].parse().unwrap())
-        } else if string.starts_with("rotate b") {
-            Op::RotLetter(parts[6].chars().next().unwrap() as usize - 'a' as usize)
-        } else if string.starts_with("reverse") {
-            Op::Rev(parts[2].parse().unwrap(), parts[4].parse().unwrap())
-        } else if string.starts_with("move") {
-            Op::Mov(parts[2].parse().unwrap(), parts[5].parse().unwrap())
-        } else {
-            panic!("Invalid move: {}", string);
-        }
-    }
+type Parsed = Vec<Op>;
+
+fn parse(input: &str) -> Result<Parsed, ParseError> {
+    try_parse_lines(input)
 }
 
-fn parse(input: &str) -> Vec<Op> {
-    input.lines().map(Op::from).collect()
+/// The index of `letter` in the alphabet.
+fn index(letter: char) -> usize {
+    letter as usize - 'a' as usize
+}
+
+/// `size` operations on the 8 letters of the real password.
+fn generate(rng: &mut Seeded, size: usize) -> String {
+    let len = "abcdefgh".len();
+    let letter = |i: usize| (b'a' + i as u8) as char;
+    (0..size)
+        .map(|_| {
+            let (x, y) = distinct(rng, len);
+            match rng.gen_range(0..6) {
+                0 => format!("swap position {} with position {}", x, y),
+                1 => format!("swap letter {} with letter {}", letter(x), letter(y)),
+                2 => {
+                    let direction = if rng.gen() { "left" } else { "right" };
+                    let plural = if x == 1 { "" } else { "s" };
+                    format!("rotate {} {} step{}", direction, x, plural)
+                }
+                3 => format!("rotate based on position of letter {}", letter(x)),
+                4 => format!("reverse positions {} through {}", x.min(y), x.max(y)),
+                _ => format!("move position {} to position {}", x, y),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
 pub mod part1 {
     use std::cmp::Ordering;
 
-    use super::{parse, Op};
+    use super::{index, Op, Parsed, Steps};
 
-    pub fn solve(input: &str, len: usize) -> String {
-        let operations = parse(input);
+    pub fn solve(operations: Parsed, len: usize) -> String {
         let mut pos2letter: Vec<_> = (0..len).collect();
         let mut letter2pos: Vec<_> = (0..len).collect();
         let len = len as isize;
@@ -66,16 +107,20 @@ pub mod part1 {
                     (pos2letter[left], pos2letter[right]) = (pos2letter[right], pos2letter[left]);
                 }
                 Op::SwapLetter(l_letter, r_letter) => {
+                    let (l_letter, r_letter) = (index(l_letter), index(r_letter));
                     (letter2pos[l_letter], letter2pos[r_letter]) =
                         (letter2pos[r_letter], letter2pos[l_letter]);
                     let (left, right) = (letter2pos[l_letter], letter2pos[r_letter]);
                     (pos2letter[left], pos2letter[right]) = (pos2letter[right], pos2letter[left]);
                 }
-                Op::RotSteps(steps) => {
+                Op::RotLeft(Steps(steps)) => {
+                    rot_offset = (rot_offset - steps).rem_euclid(len);
+                }
+                Op::RotRight(Steps(steps)) => {
                     rot_offset = (rot_offset + steps).rem_euclid(len);
                 }
                 Op::RotLetter(letter) => {
-                    let index = (letter2pos[letter] as isize + rot_offset).rem_euclid(len);
+                    let index = (letter2pos[index(letter)] as isize + rot_offset).rem_euclid(len);
                     rot_offset += 1 + index;
                     if index >= 4 {
                         rot_offset += 1;
@@ -137,10 +182,9 @@ pub mod part1 {
 pub mod part2 {
     use std::cmp::Ordering;
 
-    use super::{parse, Op};
+    use super::{index, Op, Parsed, Steps};
 
-    pub fn solve(input: &str, password: &str) -> String {
-        let operations = parse(input);
+    pub fn solve(operations: Parsed, password: &str) -> String {
         let len = password.len();
         let mut pos2letter: Vec<_> = vec![0; len];
         let mut letter2pos: Vec<_> = vec![0; len];
@@ -174,16 +218,21 @@ pub mod part2 {
                     (pos2letter[left], pos2letter[right]) = (pos2letter[right], pos2letter[left]);
                 }
                 Op::SwapLetter(l_letter, r_letter) => {
+                    let (l_letter, r_letter) = (index(l_letter), index(r_letter));
                     (letter2pos[l_letter], letter2pos[r_letter]) =
                         (letter2pos[r_letter], letter2pos[l_letter]);
                     let (left, right) = (letter2pos[l_letter], letter2pos[r_letter]);
                     (pos2letter[left], pos2letter[right]) = (pos2letter[right], pos2letter[left]);
                 }
-                Op::RotSteps(steps) => {
+                Op::RotLeft(Steps(steps)) => {
+                    rot_offset = (rot_offset + steps).rem_euclid(len);
+                }
+                Op::RotRight(Steps(steps)) => {
                     rot_offset = (rot_offset - steps).rem_euclid(len);
                 }
                 Op::RotLetter(letter) => {
-                    let index = (letter2pos[letter] as isize + rot_offset).rem_euclid(len) as usize;
+                    let index =
+                        (letter2pos[index(letter)] as isize + rot_offset).rem_euclid(len) as usize;
                     rot_offset = (rot_offset - rot_letter_inv[index]).rem_euclid(len);
                 }
                 Op::Rev(left, right) => {
@@ -238,8 +287,19 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "swap position 4 with position 0
+#[derive(Solution)]
+#[solution(
+    params = ("abcdefgh", "fbgdceah"),
+    parse = |input, _| parse(input),
+    part1 = |operations, (password, _)| part1::solve(operations, str::len(password)),
+    part2 = |operations, (_, scrambled)| part2::solve(operations, scrambled),
+    generator = generate
+)]
+#[example(
+    params = ("abcde", "decab"),
+    part1 = "decab",
+    part2 = "abcde",
+    input = "swap position 4 with position 0
 swap letter d with letter b
 reverse positions 0 through 4
 rotate left 1 step
@@ -247,20 +307,5 @@ move position 1 to position 4
 move position 3 to position 0
 rotate based on position of letter b
 rotate based on position of letter d"
-        .to_owned();
-    let (puzzle_input, password, scrambled) = if test {
-        (test_input, "abcde", "decab")
-    } else {
-        (
-            read_to_string("../inputs/2016/day_21_input.txt").unwrap(),
-            "abcdefgh",
-            "fbgdceah",
-        )
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input, password.len()));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input, scrambled));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day21;
@@ -1,10 +1,10 @@
 //! https://adventofcode.com/2016/day/22
 //! https://adventofcode.com/2016/day/22/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
 #[derive(Copy, Clone, Debug, Default)]
-struct Node {
+pub struct Node {
     x: usize,
     y: usize,
     size: usize,
@@ -39,7 +39,9 @@ impl From<&str> for Node {
     }
 }
 
-fn parse(input: &str) -> Vec<Vec<Node>> {
+type Parsed = Vec<Vec<Node>>;
+
+fn parse(input: &str) -> Parsed {
     let nodes: Vec<_> = input.lines().skip(2).map(Node::from).collect();
     let width = nodes.iter().map(|node| node.y).max().unwrap() + 1;
     let height = nodes.iter().map(|node| node.x).max().unwrap() + 1;
@@ -53,10 +55,9 @@ fn parse(input: &str) -> Vec<Vec<Node>> {
 pub mod part1 {
     use std::cmp::Ordering;
 
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let nodes = parse(input);
+    pub fn solve(nodes: Parsed) -> usize {
         let mut ordered: Vec<_> = nodes.iter().flatten().collect();
         ordered.sort_by_key(|node| -(node.used as isize));
         let last = ordered
@@ -88,12 +89,11 @@ pub mod part1 {
 pub mod part2 {
     use std::collections::{HashSet, VecDeque};
 
-    use super::parse;
+    use super::Parsed;
 
     const NEIGHBOURS: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
 
-    pub fn solve(input: &str) -> usize {
-        let nodes = parse(input);
+    pub fn solve(nodes: Parsed) -> usize {
         let mut initial = (0, 0);
         for (i, row) in nodes.iter().enumerate() {
             for (j, node) in row.iter().enumerate() {
@@ -132,8 +132,10 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "root@ebhq-gridcenter# df -h
+#[derive(Solution)]
+#[example(
+    part2 = 7,
+    input = "root@ebhq-gridcenter# df -h
 Filesystem            Size  Used  Avail  Use%
 /dev/grid/node-x0-y0   10T    8T     2T   80%
 /dev/grid/node-x0-y1   11T    6T     5T   54%
@@ -144,16 +146,5 @@ Filesystem            Size  Used  Avail  Use%
 /dev/grid/node-x2-y0   10T    6T     4T   60%
 /dev/grid/node-x2-y1    9T    8T     1T   88%
 /dev/grid/node-x2-y2    9T    6T     3T   66%"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2016/day_22_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day22;
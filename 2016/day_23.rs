@@ -1,10 +1,10 @@
 //! https://adventofcode.com/2016/day/25
 //! https://adventofcode.com/2016/day/25/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
 #[derive(Copy, Clone, Debug)]
-enum Val {
+pub enum Val {
     Lit(isize),
     Reg(usize),
 }
@@ -29,7 +29,7 @@ impl From<&str> for Val {
 }
 
 #[derive(Copy, Clone, Debug)]
-enum Instruction {
+pub enum Instruction {
     Copy(Val, Val),
     Inc(usize),
     Dec(usize),
@@ -67,12 +67,13 @@ impl Instruction {
     }
 }
 
-fn parse(input: &str) -> Vec<Instruction> {
+type Parsed = Vec<Instruction>;
+
+fn parse(input: &str) -> Parsed {
     input.lines().map(Instruction::from).collect()
 }
 
-fn solve_with(input: &str, a: isize) -> isize {
-    let mut instructions = parse(input);
+fn solve_with(mut instructions: Parsed, a: isize) -> isize {
     let mut registers = [0; 4];
     registers[0] = a;
     let mut pc = 0;
@@ -103,39 +104,30 @@ fn solve_with(input: &str, a: isize) -> isize {
 }
 
 pub mod part1 {
-    use super::solve_with;
+    use super::{solve_with, Parsed};
 
-    pub fn solve(input: &str) -> isize {
-        solve_with(input, 7)
+    pub fn solve(instructions: Parsed) -> isize {
+        solve_with(instructions, 7)
     }
 }
 
 pub mod part2 {
-    use super::solve_with;
+    use super::{solve_with, Parsed};
 
-    pub fn solve(input: &str) -> isize {
-        solve_with(input, 12)
+    pub fn solve(instructions: Parsed) -> isize {
+        solve_with(instructions, 12)
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "cpy 2 a
+#[derive(Solution)]
+#[example(
+    part1 = 3,
+    input = "cpy 2 a
 tgl a
 tgl a
 tgl a
 cpy 1 a
 dec a
 dec a"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2016/day_23_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day23;
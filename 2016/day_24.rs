@@ -1,12 +1,13 @@
 //! https://adventofcode.com/2016/day/24
 //! https://adventofcode.com/2016/day/24/input
 
-use std::{collections::VecDeque, fs::read_to_string, time::Instant};
+use std::collections::VecDeque;
 
 use itertools::Itertools;
+use utils::Solution;
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
-enum Cell {
+pub enum Cell {
     Wall,
     Empty,
     Number(usize),
@@ -26,7 +27,9 @@ impl From<char> for Cell {
 type Coord = (usize, usize);
 type Cells = Vec<Vec<Cell>>;
 
-fn parse(input: &str) -> (Cells, Vec<Coord>) {
+type Parsed = (Cells, Vec<Coord>);
+
+fn parse(input: &str) -> Parsed {
     let result: Vec<Vec<_>> = input
         .lines()
         .map(|line| line.chars().map(Cell::from).collect())
@@ -83,8 +86,7 @@ fn bfs(cells: &Cells, number: usize, numbers: &Vec<Coord>) -> Vec<usize> {
     distances
 }
 
-fn solve_generic(input: &str, go_back: bool) -> usize {
-    let (cells, numbers) = parse(input);
+fn solve_generic((cells, numbers): Parsed, go_back: bool) -> usize {
     let mut distances = Vec::with_capacity(numbers.len());
     for number in 0..numbers.len() {
         distances.push(bfs(&cells, number, &numbers));
@@ -108,37 +110,28 @@ fn solve_generic(input: &str, go_back: bool) -> usize {
 }
 
 pub mod part1 {
-    use super::solve_generic;
+    use super::{solve_generic, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        solve_generic(input, false)
+    pub fn solve(parsed: Parsed) -> usize {
+        solve_generic(parsed, false)
     }
 }
 
 pub mod part2 {
-    use super::solve_generic;
+    use super::{solve_generic, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        solve_generic(input, true)
+    pub fn solve(parsed: Parsed) -> usize {
+        solve_generic(parsed, true)
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "###########
+#[derive(Solution)]
+#[example(
+    part1 = 14,
+    input = "###########
 #0.1.....2#
 #.#######.#
 #4.......3#
 ###########"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2016/day_24_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day24;
@@ -1,10 +1,10 @@
 //! https://adventofcode.com/2016/day/25
 //! https://adventofcode.com/2016/day/25/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
 #[derive(Copy, Clone, Debug)]
-enum Val {
+pub enum Val {
     Lit(isize),
     Reg(usize),
 }
@@ -29,7 +29,7 @@ impl From<&str> for Val {
 }
 
 #[derive(Copy, Clone, Debug)]
-enum Instruction {
+pub enum Instruction {
     Copy(Val, Val),
     Inc(usize),
     Dec(usize),
@@ -51,7 +51,9 @@ impl From<&str> for Instruction {
     }
 }
 
-fn parse(input: &str) -> Vec<Instruction> {
+type Parsed = Vec<Instruction>;
+
+fn parse(input: &str) -> Parsed {
     input.lines().map(Instruction::from).collect()
 }
 
@@ -91,10 +93,9 @@ fn solve_with(instructions: &Vec<Instruction>, a: isize) -> bool {
 }
 
 pub mod part1 {
-    use super::{parse, solve_with};
+    use super::{solve_with, Parsed};
 
-    pub fn solve(input: &str) -> isize {
-        let instructions = parse(input);
+    pub fn solve(instructions: Parsed) -> isize {
         for i in 0.. {
             if solve_with(&instructions, i) {
                 return i;
@@ -104,14 +105,6 @@ pub mod part1 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2016/day_25_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[solution(single_part)]
+pub struct Day25;
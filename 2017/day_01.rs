@@ -1,9 +1,11 @@
 //! https://adventofcode.com/2017/day/1
 //! https://adventofcode.com/2017/day/1/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
-fn parse(input: &str) -> Vec<usize> {
+type Parsed = Vec<usize>;
+
+fn parse(input: &str) -> Parsed {
     input
         .chars()
         .map(|char| char.to_digit(10).unwrap() as usize)
@@ -21,34 +23,21 @@ fn sum(digits: &Vec<usize>, step: usize) -> usize {
 }
 
 pub mod part1 {
-    use super::{parse, sum};
+    use super::{sum, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let digits = parse(input);
+    pub fn solve(digits: Parsed) -> usize {
         sum(&digits, 1)
     }
 }
 
 pub mod part2 {
-    use super::{parse, sum};
+    use super::{sum, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let digits = parse(input);
+    pub fn solve(digits: Parsed) -> usize {
         sum(&digits, digits.len() / 2)
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "1221".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2017/day_01_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[example(part1 = 3, part2 = 0, input = "1221")]
+pub struct Day01;
@@ -1,9 +1,11 @@
 //! https://adventofcode.com/2017/day/2
 //! https://adventofcode.com/2017/day/2/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
-fn parse(input: &str) -> Vec<Vec<usize>> {
+type Parsed = Vec<Vec<usize>>;
+
+fn parse(input: &str) -> Parsed {
     input
         .lines()
         .map(|line| {
@@ -15,10 +17,9 @@ fn parse(input: &str) -> Vec<Vec<usize>> {
 }
 
 pub mod part1 {
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let matrix = parse(input);
+    pub fn solve(matrix: Parsed) -> usize {
         let mut sum = 0;
         for row in matrix {
             let (min, max) = (row.iter().min().unwrap(), row.iter().max().unwrap());
@@ -29,10 +30,9 @@ pub mod part1 {
 }
 
 pub mod part2 {
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let matrix = parse(input);
+    pub fn solve(matrix: Parsed) -> usize {
         let mut sum = 0;
         for row in matrix {
             'external: for i in 0..row.len() {
@@ -51,20 +51,11 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "5 9 2 8
+#[derive(Solution)]
+#[example(
+    part2 = 9,
+    input = "5 9 2 8
 9 4 7 3
 3 8 6 5"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2017/day_02_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day02;
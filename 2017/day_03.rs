@@ -1,17 +1,18 @@
 //! https://adventofcode.com/2017/day/3
 //! https://adventofcode.com/2017/day/3/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
-fn parse(input: &str) -> isize {
+type Parsed = isize;
+
+fn parse(input: &str) -> Parsed {
     input.parse().unwrap()
 }
 
 pub mod part1 {
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let target = parse(input);
+    pub fn solve(target: Parsed) -> usize {
         let mut accumulated = 1;
         let mut size = 1;
         while accumulated < target {
@@ -63,22 +64,13 @@ pub mod part1 {
 pub mod part2 {
     use std::collections::HashMap;
 
-    use super::parse;
+    use utils::coords::NEAR;
 
-    const NEIGHBORS: [(isize, isize); 8] = [
-        (1, 0),
-        (1, 1),
-        (0, 1),
-        (-1, 1),
-        (-1, 0),
-        (-1, -1),
-        (0, -1),
-        (1, -1),
-    ];
+    use super::Parsed;
 
     fn sum_neighbors((x, y): (isize, isize), cells: &HashMap<(isize, isize), isize>) -> isize {
         let mut sum = 0;
-        for neighbor in NEIGHBORS.map(|(nx, ny)| (x + nx, y + ny)) {
+        for neighbor in NEAR.map(|(nx, ny)| (x + nx, y + ny)) {
             if let Some(&n) = cells.get(&neighbor) {
                 sum += n;
             }
@@ -86,8 +78,7 @@ pub mod part2 {
         sum
     }
 
-    pub fn solve(input: &str) -> isize {
-        let target = parse(input);
+    pub fn solve(target: Parsed) -> isize {
         let mut cells = HashMap::from([((0, 0), 1)]);
         let (mut x, mut y) = (1, 0);
         let mut layer = 1;
@@ -124,17 +115,6 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "747".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2017/day_03_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[example(part2 = 806, input = "747")]
+pub struct Day03;
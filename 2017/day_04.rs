@@ -1,7 +1,7 @@
 //! https://adventofcode.com/2017/day/4
 //! https://adventofcode.com/2017/day/4/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
 fn parse(input: &str) -> Vec<Vec<&str>> {
     input
@@ -51,17 +51,7 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "a ab abc abd abf abj".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2017/day_04_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[solution(unparsed)]
+#[example(part2 = 1, input = "a ab abc abd abf abj")]
+pub struct Day04;
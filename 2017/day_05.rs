@@ -1,17 +1,18 @@
 //! https://adventofcode.com/2017/day/5
 //! https://adventofcode.com/2017/day/5/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
-fn parse(input: &str) -> Vec<isize> {
+type Parsed = Vec<isize>;
+
+fn parse(input: &str) -> Parsed {
     input.lines().map(|line| line.parse().unwrap()).collect()
 }
 
 pub mod part1 {
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let mut jumps = parse(input);
+    pub fn solve(mut jumps: Parsed) -> usize {
         let mut steps = 0;
         let mut ip = 0;
         while (ip as usize) < jumps.len() {
@@ -24,10 +25,9 @@ pub mod part1 {
 }
 
 pub mod part2 {
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let mut jumps = parse(input);
+    pub fn solve(mut jumps: Parsed) -> usize {
         let mut steps = 0;
         let mut ip = 0;
         while (ip as usize) < jumps.len() {
@@ -40,22 +40,14 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "0
+#[derive(Solution)]
+#[example(
+    part1 = 5,
+    part2 = 10,
+    input = "0
 3
 0
 1
 -3"
-    .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2017/day_05_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day05;
@@ -1,9 +1,13 @@
 //! https://adventofcode.com/2017/day/6
 //! https://adventofcode.com/2017/day/6/input
 
-use std::{collections::HashMap, fs::read_to_string, time::Instant};
+use std::collections::HashMap;
 
-fn parse(input: &str) -> Vec<usize> {
+use utils::Solution;
+
+type Parsed = Vec<usize>;
+
+fn parse(input: &str) -> Parsed {
     input
         .split_whitespace()
         .map(|blocks| blocks.parse().unwrap())
@@ -49,34 +53,21 @@ fn find_loop(memory: &mut Vec<usize>) -> (usize, usize) {
 }
 
 pub mod part1 {
-    use super::{find_loop, parse};
+    use super::{find_loop, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let mut memory = parse(input);
+    pub fn solve(mut memory: Parsed) -> usize {
         find_loop(&mut memory).0
     }
 }
 
 pub mod part2 {
-    use super::{find_loop, parse};
+    use super::{find_loop, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let mut memory = parse(input);
+    pub fn solve(mut memory: Parsed) -> usize {
         find_loop(&mut memory).1
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "0	2	7	0".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2017/day_06_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[example(part1 = 5, part2 = 4, input = "0	2	7	0")]
+pub struct Day06;
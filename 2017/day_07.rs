@@ -1,20 +1,17 @@
 //! https://adventofcode.com/2017/day/7
 //! https://adventofcode.com/2017/day/7/input
 
-use std::{
-    collections::{hash_map::Entry, HashMap, HashSet},
-    fs::read_to_string,
-    time::Instant,
-};
+use std::collections::{hash_map::Entry, HashMap, HashSet};
 
-#[allow(clippy::type_complexity)]
-fn parse(
-    input: &str,
-) -> (
-    HashMap<&str, HashSet<&str>>,
-    HashMap<&str, isize>,
-    HashMap<&str, isize>,
-) {
+use utils::Solution;
+
+type Parsed = (
+    HashMap<String, HashSet<String>>,
+    HashMap<String, isize>,
+    HashMap<String, isize>,
+);
+
+fn parse(input: &str) -> Parsed {
     let mut graph = HashMap::new();
     let mut weights = HashMap::new();
     let mut incoming = HashMap::new();
@@ -28,12 +25,12 @@ fn parse(
             .trim_matches(|c| c == '(' || c == ')')
             .parse()
             .unwrap();
-        graph.insert(name, HashSet::new());
-        weights.insert(name, weight);
+        graph.insert(name.to_owned(), HashSet::new());
+        weights.insert(name.to_owned(), weight);
         if let Some(children) = parts.next() {
             for child in children.split(", ") {
-                graph.get_mut(name).unwrap().insert(child);
-                match incoming.entry(child) {
+                graph.get_mut(name).unwrap().insert(child.to_owned());
+                match incoming.entry(child.to_owned()) {
                     Entry::Occupied(mut entry) => *entry.get_mut() += 1,
                     Entry::Vacant(entry) => {
                         entry.insert(1);
@@ -46,28 +43,27 @@ fn parse(
 }
 
 fn find_root<'a>(
-    graph: &HashMap<&'a str, HashSet<&str>>,
-    incoming: &HashMap<&str, isize>,
+    graph: &'a HashMap<String, HashSet<String>>,
+    incoming: &HashMap<String, isize>,
 ) -> &'a str {
     graph
         .keys()
-        .find(|&&node| *incoming.get(node).unwrap_or(&0) == 0)
+        .find(|&node| *incoming.get(node).unwrap_or(&0) == 0)
         .unwrap()
 }
 
 pub mod part1 {
-    use super::{find_root, parse};
+    use super::{find_root, Parsed};
 
-    pub fn solve(input: &str) -> &str {
-        let (graph, _, incoming) = parse(input);
-        find_root(&graph, &incoming)
+    pub fn solve((graph, _, incoming): Parsed) -> String {
+        find_root(&graph, &incoming).to_owned()
     }
 }
 
 pub mod part2 {
     use std::collections::{HashMap, HashSet};
 
-    use super::{find_root, parse};
+    use super::{find_root, Parsed};
 
     enum Exploration {
         Result(isize),
@@ -76,8 +72,8 @@ pub mod part2 {
 
     fn explore(
         node: &str,
-        graph: &HashMap<&str, HashSet<&str>>,
-        weights: &HashMap<&str, isize>,
+        graph: &HashMap<String, HashSet<String>>,
+        weights: &HashMap<String, isize>,
     ) -> Exploration {
         if graph.get(node).unwrap().is_empty() {
             return Exploration::Weight(*weights.get(node).unwrap());
@@ -102,17 +98,16 @@ pub mod part2 {
                 .max_by_key(|(_, (_, count))| count)
                 .unwrap()
                 .0;
-            let (total_wrong, (&wrong_node, _)) = sub_weights
+            let (total_wrong, (wrong_node, _)) = sub_weights
                 .iter()
                 .min_by_key(|(_, (_, count))| count)
                 .unwrap();
-            let wrong = *weights.get(wrong_node).unwrap();
+            let wrong = *weights.get(*wrong_node).unwrap();
             Exploration::Result(wrong + total_right - total_wrong)
         }
     }
 
-    pub fn solve(input: &str) -> isize {
-        let (graph, weights, incoming) = parse(input);
+    pub fn solve((graph, weights, incoming): Parsed) -> isize {
         if let Exploration::Result(result) = explore(find_root(&graph, &incoming), &graph, &weights)
         {
             return result;
@@ -121,8 +116,11 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "pbga (66)
+#[derive(Solution)]
+#[example(
+    part1 = "tknk",
+    part2 = 60,
+    input = "pbga (66)
 xhth (57)
 ebii (61)
 havc (66)
@@ -135,16 +133,5 @@ jptl (61)
 ugml (68) -> gyxo, ebii, jptl
 gyxo (61)
 cntj (57)"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2017/day_07_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day07;
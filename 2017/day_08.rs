@@ -1,8 +1,11 @@
 //! https://adventofcode.com/2017/day/8
 //! https://adventofcode.com/2017/day/8/input
 
-use std::{collections::HashMap, fs::read_to_string, time::Instant};
+use std::collections::HashMap;
 
+use utils::Solution;
+
+#[derive(Clone)]
 enum Operator {
     Eq,
     Ne,
@@ -26,7 +29,8 @@ impl From<&str> for Operator {
     }
 }
 
-struct Instruction {
+#[derive(Clone)]
+pub struct Instruction {
     dest: String,
     delta: isize,
     target: String,
@@ -70,17 +74,18 @@ impl Instruction {
     }
 }
 
-fn parse(input: &str) -> Vec<Instruction> {
+type Parsed = Vec<Instruction>;
+
+fn parse(input: &str) -> Parsed {
     input.lines().map(Instruction::from).collect()
 }
 
 pub mod part1 {
     use std::collections::HashMap;
 
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> isize {
-        let instructions = parse(input);
+    pub fn solve(instructions: Parsed) -> isize {
         let mut registers = HashMap::new();
         for instruction in instructions {
             if instruction.apply(&registers) {
@@ -94,10 +99,9 @@ pub mod part1 {
 pub mod part2 {
     use std::collections::HashMap;
 
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> isize {
-        let instructions = parse(input);
+    pub fn solve(instructions: Parsed) -> isize {
         let mut registers = HashMap::new();
         let mut max = 0;
         for instruction in instructions {
@@ -110,21 +114,13 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "b inc 5 if a > 1
+#[derive(Solution)]
+#[example(
+    part1 = 1,
+    part2 = 10,
+    input = "b inc 5 if a > 1
 a inc 1 if b < 5
 c dec -10 if a >= 1
 c inc -20 if c == 10"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2017/day_08_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day08;
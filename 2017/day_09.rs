@@ -1,9 +1,10 @@
 //! https://adventofcode.com/2017/day/9
 //! https://adventofcode.com/2017/day/9/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
-struct Group {
+#[derive(Clone)]
+pub struct Group {
     internal: Vec<Group>,
     garbage: usize,
 }
@@ -36,12 +37,14 @@ impl From<&str> for Group {
     }
 }
 
-fn parse(input: &str) -> Group {
+type Parsed = Group;
+
+fn parse(input: &str) -> Parsed {
     Group::from(input)
 }
 
 pub mod part1 {
-    use super::{parse, Group};
+    use super::{Group, Parsed};
 
     fn score(group: &Group, partial: usize) -> usize {
         let mut total = partial;
@@ -51,36 +54,23 @@ pub mod part1 {
         total
     }
 
-    pub fn solve(input: &str) -> usize {
-        let group = parse(input);
+    pub fn solve(group: Parsed) -> usize {
         score(&group, 1)
     }
 }
 
 pub mod part2 {
-    use super::{parse, Group};
+    use super::{Group, Parsed};
 
     fn count_garbage(group: &Group) -> usize {
         group.garbage + group.internal.iter().map(count_garbage).sum::<usize>()
     }
 
-    pub fn solve(input: &str) -> usize {
-        let group = parse(input);
+    pub fn solve(group: Parsed) -> usize {
         count_garbage(&group)
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = r#"{<!!>}"#.to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2017/day_09_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[example(part1 = 1, part2 = 0, input = r#"{<!!>}"#)]
+pub struct Day09;
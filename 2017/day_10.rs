@@ -1,7 +1,7 @@
 //! https://adventofcode.com/2017/day/10
 //! https://adventofcode.com/2017/day/10/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
 fn reverse(numbers: &mut [usize], from: usize, length: usize) {
     for i in 0..length / 2 {
@@ -82,17 +82,7 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "1,2,4".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2017/day_10_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[solution(unparsed)]
+#[example(part2 = "63960835bcdc130f0b66d7ff4f6a5a8e", input = "1,2,4")]
+pub struct Day10;
@@ -1,9 +1,10 @@
 //! https://adventofcode.com/2017/day/11
 //! https://adventofcode.com/2017/day/11/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
-enum Direction {
+#[derive(Clone)]
+pub enum Direction {
     N,
     S,
     Ne,
@@ -39,15 +40,16 @@ impl From<&str> for Direction {
     }
 }
 
-fn parse(input: &str) -> Vec<Direction> {
+type Parsed = Vec<Direction>;
+
+fn parse(input: &str) -> Parsed {
     input.split(',').map(Direction::from).collect()
 }
 
 pub mod part1 {
-    use super::{parse, Direction};
+    use super::{Direction, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let directions = parse(input);
+    pub fn solve(directions: Parsed) -> usize {
         let (mut x, mut y, mut z) = (0, 0, 0);
         for (dx, dy, dz) in directions.iter().map(Direction::to_coord) {
             x += dx;
@@ -59,10 +61,9 @@ pub mod part1 {
 }
 
 pub mod part2 {
-    use super::{parse, Direction};
+    use super::{Direction, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let directions = parse(input);
+    pub fn solve(directions: Parsed) -> usize {
         let (mut x, mut y, mut z) = (0, 0, 0);
         let mut max_distance = 0;
         for (dx, dy, dz) in directions.iter().map(Direction::to_coord) {
@@ -75,17 +76,6 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "ne,ne,sw,sw".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2017/day_11_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[example(part1 = 0, input = "ne,ne,sw,sw")]
+pub struct Day11;
@@ -1,15 +1,15 @@
 //! https://adventofcode.com/2017/day/12
 //! https://adventofcode.com/2017/day/12/input
 
-use std::{
-    collections::{HashSet, VecDeque},
-    fs::read_to_string,
-    time::Instant,
-};
+use std::collections::{HashSet, VecDeque};
+
+use utils::Solution;
 
 type Graph = Vec<HashSet<usize>>;
 
-fn parse(input: &str) -> Graph {
+type Parsed = Graph;
+
+fn parse(input: &str) -> Parsed {
     input
         .lines()
         .map(|line| {
@@ -36,20 +36,18 @@ fn bfs(graph: &Graph, visited: &mut Vec<bool>) -> usize {
 }
 
 pub mod part1 {
-    use super::{bfs, parse};
+    use super::{bfs, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let graph = parse(input);
+    pub fn solve(graph: Parsed) -> usize {
         let mut visited = vec![false; graph.len()];
         bfs(&graph, &mut visited)
     }
 }
 
 pub mod part2 {
-    use super::{bfs, parse};
+    use super::{bfs, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let graph = parse(input);
+    pub fn solve(graph: Parsed) -> usize {
         let mut count = 0;
         let mut groups = 0;
         let mut visited = vec![false; graph.len()];
@@ -61,17 +59,5 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2017/day_12_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+pub struct Day12;
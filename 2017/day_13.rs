@@ -1,15 +1,19 @@
 //! https://adventofcode.com/2017/day/13
 //! https://adventofcode.com/2017/day/13/input
 
-use std::{collections::HashMap, fs::read_to_string, time::Instant};
+use std::collections::HashMap;
+
+use utils::Solution;
 
 #[derive(Copy, Clone, Debug, Default)]
-struct Scanner {
+pub struct Scanner {
     range: usize,
     round_size: usize,
 }
 
-fn parse(input: &str) -> (HashMap<usize, Scanner>, usize) {
+type Parsed = (HashMap<usize, Scanner>, usize);
+
+fn parse(input: &str) -> Parsed {
     let result: HashMap<_, _> = input
         .lines()
         .map(|line| {
@@ -32,10 +36,9 @@ fn parse(input: &str) -> (HashMap<usize, Scanner>, usize) {
 }
 
 pub mod part1 {
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let (scanners, max) = parse(input);
+    pub fn solve((scanners, max): Parsed) -> usize {
         let mut severity = 0;
         for layer @ ps in 0..=max {
             if let Some(scanner) = scanners.get(&layer) {
@@ -51,7 +54,7 @@ pub mod part1 {
 pub mod part2 {
     use std::collections::HashMap;
 
-    use super::{parse, Scanner};
+    use super::{Parsed, Scanner};
 
     fn caught(scanners: &HashMap<usize, Scanner>, max: usize, delay: usize) -> bool {
         for (layer, ps) in (delay..=delay + max).enumerate() {
@@ -64,27 +67,18 @@ pub mod part2 {
         false
     }
 
-    pub fn solve(input: &str) -> usize {
-        let (scanners, max) = parse(input);
+    pub fn solve((scanners, max): Parsed) -> usize {
         (0..).find(|&delay| !caught(&scanners, max, delay)).unwrap()
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "0: 3
+#[derive(Solution)]
+#[example(
+    part1 = 24,
+    part2 = 10,
+    input = "0: 3
 1: 2
 4: 4
 6: 4"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2017/day_13_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day13;
@@ -1,11 +1,13 @@
 //! https://adventofcode.com/2017/day/14
 //! https://adventofcode.com/2017/day/14/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
 use crate::day_10::part2::hash;
 
-fn parse(input: &str) -> [[usize; 128]; 128] {
+type Parsed = [[usize; 128]; 128];
+
+fn parse(input: &str) -> Parsed {
     let mut grid = [[0; 128]; 128];
     for (i, row) in grid.iter_mut().enumerate() {
         let hash = hash(&format!("{}-{}", input, i));
@@ -19,10 +21,9 @@ fn parse(input: &str) -> [[usize; 128]; 128] {
 }
 
 pub mod part1 {
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let grid = parse(input);
+    pub fn solve(grid: Parsed) -> usize {
         grid.iter().map(|row| row.iter().sum::<usize>()).sum()
     }
 }
@@ -30,10 +31,9 @@ pub mod part1 {
 pub mod part2 {
     use std::collections::VecDeque;
 
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let grid = parse(input);
+    pub fn solve(grid: Parsed) -> usize {
         let mut regions = 0;
         let mut visited = [[false; 128]; 128];
         for i in 0..grid.len() {
@@ -63,17 +63,6 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "flqrgnkx".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2017/day_14_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[example(part1 = 8108, part2 = 1242, input = "flqrgnkx")]
+pub struct Day14;
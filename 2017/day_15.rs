@@ -1,9 +1,11 @@
 //! https://adventofcode.com/2017/day/15
 //! https://adventofcode.com/2017/day/15/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
-fn parse(input: &str) -> (usize, usize) {
+type Parsed = (usize, usize);
+
+fn parse(input: &str) -> Parsed {
     let mut lines = input
         .lines()
         .map(|line| line.split_whitespace().last().unwrap().parse().unwrap());
@@ -11,10 +13,9 @@ fn parse(input: &str) -> (usize, usize) {
 }
 
 pub mod part1 {
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let (mut a, mut b) = parse(input);
+    pub fn solve((mut a, mut b): Parsed) -> usize {
         let mut matches = 0;
         for _ in 0..40_000_000 {
             a = (a * 16807) % 2147483647;
@@ -28,10 +29,9 @@ pub mod part1 {
 }
 
 pub mod part2 {
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let (mut a, mut b) = parse(input);
+    pub fn solve((mut a, mut b): Parsed) -> usize {
         let mut matches = 0;
         for _ in 0..5_000_000 {
             loop {
@@ -54,19 +54,11 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "Generator A starts with 65
+#[derive(Solution)]
+#[example(
+    part1 = 588,
+    part2 = 309,
+    input = "Generator A starts with 65
 Generator B starts with 8921"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2017/day_15_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day15;
@@ -1,36 +1,51 @@
 //! https://adventofcode.com/2017/day/16
 //! https://adventofcode.com/2017/day/16/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::{
+    errors::ParseError,
+    generate::{distinct, Rng, Seeded},
+    FromStr, Solution,
+};
 
-#[derive(Copy, Clone, Debug)]
-enum Move {
+#[derive(Copy, Clone, Debug, FromStr)]
+pub enum Move {
+    #[pattern("s{0}")]
     Spin(usize),
+    #[pattern("x{0}/{1}")]
     Exchange(usize, usize),
-    Partner(usize, usize),
+    #[pattern("p{0}/{1}")]
+    Partner(char, char),
 }
 
-impl From<&str> for Move {
-    fn from(string: &str) -> Self {
-        match string.chars().next().unwrap() {
-            's' => Move::Spin(string[1..].parse().unwrap()),
-            'x' => {
-                let mut parts = string[1..]
-                    .split('/')
-                    .map(|program| program.parse().unwrap());
-                Move::Exchange(parts.next().unwrap(), parts.next().unwrap())
-            }
-            'p' => Move::Partner(
-                string.chars().nth(1).unwrap() as usize - 'a' as usize,
-                string.chars().nth(3).unwrap() as usize - 'a' as usize,
-            ),
-            _ => panic!("Invalid dance move: {}", string),
-        }
-    }
+type Parsed = Vec<Move>;
+
+fn parse(input: &str) -> Result<Parsed, ParseError> {
+    input
+        .trim_end()
+        .split(',')
+        .map(|dance_move| {
+            dance_move
+                .parse()
+                .map_err(|error| ParseError::item("move", error).at(input, dance_move))
+        })
+        .collect()
 }
 
-fn parse(input: &str) -> Vec<Move> {
-    input.split(',').map(Move::from).collect()
+/// `size` moves of the 16 programs of the real input.
+fn generate(rng: &mut Seeded, size: usize) -> String {
+    let programs = 16;
+    let program = |i: usize| (b'a' + i as u8) as char;
+    (0..size)
+        .map(|_| {
+            let (x, y) = distinct(rng, programs);
+            match rng.gen_range(0..3) {
+                0 => format!("s{}", rng.gen_range(1..programs)),
+                1 => format!("x{}/{}", x, y),
+                _ => format!("p{}/{}", program(x), program(y)),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
 }
 
 fn dance(programs: &mut [usize], positions: &mut [usize], moves: &[Move]) {
@@ -57,6 +72,10 @@ fn dance(programs: &mut [usize], positions: &mut [usize], moves: &[Move]) {
                 ) = (position_a, position_b);
             }
             Move::Partner(program_a, program_b) => {
+                let (program_a, program_b) = (
+                    program_a as usize - 'a' as usize,
+                    program_b as usize - 'a' as usize,
+                );
                 (positions[program_a], positions[program_b]) =
                     (positions[program_b], positions[program_a]);
                 (
@@ -69,10 +88,9 @@ fn dance(programs: &mut [usize], positions: &mut [usize], moves: &[Move]) {
 }
 
 pub mod part1 {
-    use super::{dance, parse};
+    use super::{dance, Parsed};
 
-    pub fn solve(input: &str, last_letter: char) -> String {
-        let moves = parse(input);
+    pub fn solve(moves: Parsed, last_letter: char) -> String {
         let mut programs = vec![0; last_letter as usize - 'a' as usize + 1];
         let mut positions = vec![0; last_letter as usize - 'a' as usize + 1];
         for i in 0..positions.len() {
@@ -90,10 +108,9 @@ pub mod part1 {
 pub mod part2 {
     use std::collections::{hash_map::Entry, HashMap};
 
-    use super::{dance, parse};
+    use super::{dance, Parsed};
 
-    pub fn solve(input: &str, last_letter: char) -> String {
-        let moves = parse(input);
+    pub fn solve(moves: Parsed, last_letter: char) -> String {
         let mut programs = vec![0; last_letter as usize - 'a' as usize + 1];
         let mut positions = vec![0; last_letter as usize - 'a' as usize + 1];
         for i in 0..positions.len() {
@@ -126,17 +143,11 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "s1,x3/4,pe/b".to_owned();
-    let (puzzle_input, last_letter) = if test {
-        (test_input, 'e')
-    } else {
-        (read_to_string("../inputs/2017/day_16_input.txt").unwrap(), 'p')
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input, last_letter));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input, last_letter));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[solution(
+    params = 'p',
+    parse = |input, _| parse(input),
+    generator = generate
+)]
+#[example(params = 'e', part1 = "baedc", input = "s1,x3/4,pe/b")]
+pub struct Day16;
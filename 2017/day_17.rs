@@ -1,14 +1,16 @@
 //! https://adventofcode.com/2017/day/17
 //! https://adventofcode.com/2017/day/17/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
-fn parse(input: &str) -> usize {
+type Parsed = usize;
+
+fn parse(input: &str) -> Parsed {
     input.parse().unwrap()
 }
 
 pub mod part1 {
-    use super::parse;
+    use super::Parsed;
 
     #[derive(Copy, Clone, Debug, Default)]
     struct Node {
@@ -16,8 +18,7 @@ pub mod part1 {
         next: usize,
     }
 
-    pub fn solve(input: &str) -> usize {
-        let step = parse(input);
+    pub fn solve(step: Parsed) -> usize {
         let mut values = [Node::default(); 2018];
         let mut current_position = 0;
         for v in 1..=2017 {
@@ -35,10 +36,9 @@ pub mod part1 {
 }
 
 pub mod part2 {
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let step = parse(input);
+    pub fn solve(step: Parsed) -> usize {
         let mut result = 0;
         let mut current_position = 0;
         for v in 1..=50_000_000 {
@@ -51,17 +51,6 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "3".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2017/day_17_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[example(part1 = 638, input = "3")]
+pub struct Day17;
@@ -1,10 +1,10 @@
 //! https://adventofcode.com/2017/day/18
 //! https://adventofcode.com/2017/day/18/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
 #[derive(Copy, Clone, Debug)]
-enum Operand {
+pub enum Operand {
     Register(usize),
     Integer(isize),
 }
@@ -19,7 +19,7 @@ impl From<&str> for Operand {
 }
 
 #[derive(Copy, Clone, Debug)]
-enum Instruction {
+pub enum Instruction {
     Snd(Operand),
     Set(usize, Operand),
     Add(usize, Operand),
@@ -47,15 +47,16 @@ impl From<&str> for Instruction {
     }
 }
 
-fn parse(input: &str) -> Vec<Instruction> {
+type Parsed = Vec<Instruction>;
+
+fn parse(input: &str) -> Parsed {
     input.lines().map(Instruction::from).collect()
 }
 
 pub mod part1 {
-    use super::{parse, Instruction, Operand};
+    use super::{Instruction, Operand, Parsed};
 
-    pub fn solve(input: &str) -> isize {
-        let instructions = parse(input);
+    pub fn solve(instructions: Parsed) -> isize {
         let mut registers = [0; 'z' as usize - 'a' as usize + 1];
         let mut frequency = None;
         let mut ip = 0;
@@ -116,10 +117,9 @@ pub mod part1 {
 pub mod part2 {
     use std::collections::VecDeque;
 
-    use super::{parse, Instruction, Operand};
+    use super::{Instruction, Operand, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let instructions = parse(input);
+    pub fn solve(instructions: Parsed) -> usize {
         let mut registers = [[0; 'z' as usize - 'a' as usize + 1]; 2];
         registers[1]['p' as usize - 'a' as usize] = 1;
         let mut ips = [0, 0];
@@ -194,24 +194,15 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "snd 1
+#[derive(Solution)]
+#[example(
+    part2 = 3,
+    input = "snd 1
 snd 2
 snd p
 rcv a
 rcv b
 rcv c
 rcv d"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2017/day_18_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day18;
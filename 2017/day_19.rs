@@ -1,9 +1,13 @@
 //! https://adventofcode.com/2017/day/19
 //! https://adventofcode.com/2017/day/19/input
 
-use std::{collections::HashMap, fs::read_to_string, time::Instant};
+use std::collections::HashMap;
 
-fn parse(input: &str) -> HashMap<(isize, isize), char> {
+use utils::Solution;
+
+type Parsed = HashMap<(isize, isize), char>;
+
+fn parse(input: &str) -> Parsed {
     let mut result = HashMap::new();
     for (i, row) in input.lines().enumerate() {
         for (j, char) in row.chars().enumerate() {
@@ -47,40 +51,30 @@ fn move_packet(map: HashMap<(isize, isize), char>) -> (String, usize) {
 }
 
 pub mod part1 {
-    use super::{move_packet, parse};
+    use super::{move_packet, Parsed};
 
-    pub fn solve(input: &str) -> String {
-        let map = parse(input);
+    pub fn solve(map: Parsed) -> String {
         move_packet(map).0
     }
 }
 
 pub mod part2 {
-    use super::{move_packet, parse};
+    use super::{move_packet, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let map = parse(input);
+    pub fn solve(map: Parsed) -> usize {
         move_packet(map).1
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "    |
+#[derive(Solution)]
+#[example(
+    part1 = "ABCDEF",
+    part2 = 38,
+    input = "    |
     |  +--+
     A  |  C
 F---|----E|--+
     |  |  |  D
     +B-+  +--+"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2017/day_19_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day19;
@@ -1,61 +1,41 @@
 //! https://adventofcode.com/2017/day/20
 //! https://adventofcode.com/2017/day/20/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::{errors::ParseError, parsing::try_parse_lines, FromStr, Solution};
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, FromStr)]
+#[separator(',')]
 struct Triple {
     x: isize,
     y: isize,
     z: isize,
 }
 
-impl From<&str> for Triple {
-    fn from(string: &str) -> Self {
-        let mut parts = string[1..string.len() - 1]
-            .split(',')
-            .map(|v| v.parse().unwrap());
-        Triple {
-            x: parts.next().unwrap(),
-            y: parts.next().unwrap(),
-            z: parts.next().unwrap(),
-        }
-    }
-}
-
 impl Triple {
     fn abs_sum(&self) -> usize {
         self.x.unsigned_abs() + self.y.unsigned_abs() + self.z.unsigned_abs()
     }
 }
 
-#[derive(Copy, Clone, Debug)]
-struct Particle {
+#[derive(Copy, Clone, Debug, FromStr)]
+#[pattern("p=<{position}>, v=<{velocity}>, a=<{acceleration}>")]
+pub struct Particle {
     position: Triple,
     velocity: Triple,
     acceleration: Triple,
 }
 
-impl From<&str> for Particle {
-    fn from(string: &str) -> Self {
-        let mut parts = string.split(", ").map(|a| Triple::from(&a[2..]));
-        Particle {
-            position: parts.next().unwrap(),
-            velocity: parts.next().unwrap(),
-            acceleration: parts.next().unwrap(),
-        }
-    }
-}
+type Parsed = Vec<Particle>;
 
-fn parse(input: &str) -> Vec<Particle> {
-    input.lines().map(Particle::from).collect()
+fn parse(input: &str) -> Result<Parsed, ParseError> {
+    try_parse_lines(input)
 }
 
 pub mod part1 {
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        /*let mut particles = parse(input);
+    pub fn solve(particles: Parsed) -> usize {
+        /*let mut particles = particles;
         let mut steps = vec![0; particles.len()];
         for (i, particle) in particles.iter_mut().enumerate() {
             while particle.acceleration.x != 0
@@ -96,7 +76,6 @@ pub mod part1 {
             })
             .unwrap()
             .0*/
-        let particles = parse(input);
         let mut particles: Vec<_> = particles.iter().enumerate().collect();
         particles.sort_by_key(|(_, particle)| {
             (
@@ -112,10 +91,9 @@ pub mod part1 {
 pub mod part2 {
     use std::collections::{HashMap, HashSet};
 
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let mut particles = parse(input);
+    pub fn solve(mut particles: Parsed) -> usize {
         let mut alive = vec![true; particles.len()];
         let mut points = HashMap::new();
         for _ in 0..1000 {
@@ -144,19 +122,10 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "p=<3,0,0>, v=<2,0,0>, a=<-1,0,0>
+#[derive(Solution)]
+#[example(
+    part1 = 0,
+    input = "p=<3,0,0>, v=<2,0,0>, a=<-1,0,0>
 p=<4,0,0>, v=<0,0,0>, a=<-2,0,0>"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2017/day_20_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day20;
@@ -1,7 +1,7 @@
 //! https://adventofcode.com/2017/day/21
 //! https://adventofcode.com/2017/day/21/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
 #[derive(Copy, Clone, Debug)]
 enum Rotation {
@@ -125,7 +125,9 @@ const TRANSFORMATIONS: [Transformation; 8] = [
     },
 ];
 
-fn parse(input: &str) -> [Vec<usize>; 2] {
+type Parsed = [Vec<usize>; 2];
+
+fn parse(input: &str) -> Parsed {
     fn to_number(rule: &str) -> usize {
         rule.chars()
             .filter_map(|char| match char {
@@ -187,41 +189,28 @@ fn count(image: Vec<Vec<usize>>) -> usize {
     image.iter().map(|line| line.iter().sum::<usize>()).sum()
 }
 
-fn generic_solve(input: &str, iterations: usize) -> usize {
-    let rules = parse(input);
+fn generic_solve(rules: Parsed, iterations: usize) -> usize {
     let image = expand(rules, iterations);
     count(image)
 }
 
 pub mod part1 {
-    use super::generic_solve;
+    use super::{generic_solve, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        generic_solve(input, 5)
+    pub fn solve(rules: Parsed) -> usize {
+        generic_solve(rules, 5)
     }
 }
 
 pub mod part2 {
-    use super::generic_solve;
+    use super::{generic_solve, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        generic_solve(input, 18)
+    pub fn solve(rules: Parsed) -> usize {
+        generic_solve(rules, 18)
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "../.# => ##./#../...
-.#./..#/### => #..#/..../..../#..#"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2017/day_21_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[example(input = "../.# => ##./#../...
+.#./..#/### => #..#/..../..../#..#")]
+pub struct Day21;
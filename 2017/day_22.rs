@@ -1,11 +1,15 @@
 //! https://adventofcode.com/2017/day/22
 //! https://adventofcode.com/2017/day/22/input
 
-use std::{collections::HashSet, fs::read_to_string, time::Instant};
+use std::collections::HashSet;
+
+use utils::Solution;
 
 type Coord = (isize, isize);
 
-fn parse(input: &str) -> (HashSet<Coord>, Coord) {
+type Parsed = (HashSet<Coord>, Coord);
+
+fn parse(input: &str) -> Parsed {
     let mut result = HashSet::new();
     for (i, line) in input.lines().enumerate() {
         for (j, node) in line.chars().enumerate() {
@@ -24,10 +28,9 @@ fn parse(input: &str) -> (HashSet<Coord>, Coord) {
 }
 
 pub mod part1 {
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let (mut infected, (mut vi, mut vj)) = parse(input);
+    pub fn solve((mut infected, (mut vi, mut vj)): Parsed) -> usize {
         let (mut di, mut dj) = (-1, 0);
         let mut infections = 0;
         for _ in 0..10000 {
@@ -48,7 +51,7 @@ pub mod part1 {
 pub mod part2 {
     use std::collections::{hash_map::Entry, HashMap};
 
-    use super::parse;
+    use super::Parsed;
 
     #[derive(Copy, Clone, Debug)]
     enum State {
@@ -57,8 +60,7 @@ pub mod part2 {
         Flagged,
     }
 
-    pub fn solve(input: &str) -> usize {
-        let (infected, (mut vi, mut vj)) = parse(input);
+    pub fn solve((infected, (mut vi, mut vj)): Parsed) -> usize {
         let mut infected: HashMap<_, _> = infected
             .iter()
             .map(|&coord| (coord, State::Infected))
@@ -92,20 +94,12 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "..#
+#[derive(Solution)]
+#[example(
+    part1 = 5587,
+    part2 = 2511944,
+    input = "..#
 #..
 ..."
-    .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2017/day_22_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day22;
@@ -1,10 +1,10 @@
 //! https://adventofcode.com/2017/day/23
 //! https://adventofcode.com/2017/day/23/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
 #[derive(Copy, Clone, Debug)]
-enum Operand {
+pub enum Operand {
     Register(usize),
     Integer(isize),
 }
@@ -19,7 +19,7 @@ impl From<&str> for Operand {
 }
 
 #[derive(Copy, Clone, Debug)]
-enum Instruction {
+pub enum Instruction {
     Set(usize, Operand),
     Sub(usize, Operand),
     Mul(usize, Operand),
@@ -41,15 +41,16 @@ impl From<&str> for Instruction {
     }
 }
 
-fn parse(input: &str) -> Vec<Instruction> {
+type Parsed = Vec<Instruction>;
+
+fn parse(input: &str) -> Parsed {
     input.lines().map(Instruction::from).collect()
 }
 
 pub mod part1 {
-    use super::{parse, Instruction, Operand};
+    use super::{Instruction, Operand, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let instructions = parse(input);
+    pub fn solve(instructions: Parsed) -> usize {
         let mut registers = [0; 'h' as usize - 'a' as usize + 1];
         let mut muls = 0;
         let mut ip = 0;
@@ -95,9 +96,9 @@ pub mod part1 {
 }
 
 pub mod part2 {
-    use super::{parse, Instruction, Operand};
+    use super::{Instruction, Operand, Parsed};
 
-    pub fn solve(input: &str) -> usize {
+    pub fn solve(instructions: Parsed) -> usize {
         fn sieve(primes: &mut Vec<isize>, factor: isize) {
             for value in primes {
                 if *value != 0 && *value != factor && *value % factor == 0 {
@@ -106,7 +107,6 @@ pub mod part2 {
             }
         }
 
-        let instructions = parse(input);
         let mut registers = [0; 'h' as usize - 'a' as usize + 1];
         registers[0] = 1;
         let mut ip = 0;
@@ -167,17 +167,5 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2017/day_23_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+pub struct Day23;
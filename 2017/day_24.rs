@@ -1,11 +1,13 @@
 //! https://adventofcode.com/2017/day/24
 //! https://adventofcode.com/2017/day/24/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
 type Port = (usize, usize);
 
-fn parse(input: &str) -> Vec<Port> {
+type Parsed = Vec<Port>;
+
+fn parse(input: &str) -> Parsed {
     input
         .lines()
         .map(|line| {
@@ -21,7 +23,7 @@ fn parse(input: &str) -> Vec<Port> {
 pub mod part1 {
     use std::collections::HashSet;
 
-    use super::{parse, Port};
+    use super::{Parsed, Port};
 
     fn explore(ports: &[Port]) -> usize {
         fn internal(
@@ -55,8 +57,7 @@ pub mod part1 {
         internal(ports, 0, 0, 0, &mut HashSet::new())
     }
 
-    pub fn solve(input: &str) -> usize {
-        let ports = parse(input);
+    pub fn solve(ports: Parsed) -> usize {
         explore(&ports)
     }
 }
@@ -64,7 +65,7 @@ pub mod part1 {
 pub mod part2 {
     use std::collections::HashSet;
 
-    use super::{parse, Port};
+    use super::{Parsed, Port};
 
     fn explore(ports: &[Port]) -> usize {
         fn internal(
@@ -98,14 +99,16 @@ pub mod part2 {
         internal(ports, 0, 0, (0, 0), &mut HashSet::new()).1
     }
 
-    pub fn solve(input: &str) -> usize {
-        let ports = parse(input);
+    pub fn solve(ports: Parsed) -> usize {
         explore(&ports)
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "0/2
+#[derive(Solution)]
+#[example(
+    part1 = 31,
+    part2 = 19,
+    input = "0/2
 2/2
 2/3
 3/4
@@ -113,16 +116,5 @@ pub fn main(test: bool) {
 0/1
 10/1
 9/10"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2017/day_24_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day24;
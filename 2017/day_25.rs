@@ -1,15 +1,17 @@
 //! https://adventofcode.com/2017/day/25
 //! https://adventofcode.com/2017/day/25/input
 
-use std::{collections::HashMap, fs::read_to_string, time::Instant};
+use std::collections::HashMap;
 
-use crate::LINE_ENDING;
+use utils::Solution;
 
 type TM = HashMap<(u8, usize), (usize, isize, u8)>;
 
-fn parse(input: &str) -> (TM, u8, usize) {
+type Parsed = (TM, u8, usize);
+
+fn parse(input: &str) -> Parsed {
     let mut result = HashMap::new();
-    let separator = LINE_ENDING.repeat(2);
+    let separator = "\n".repeat(2);
     let mut parts = input.split(&separator);
     let mut begin = parts.next().unwrap().lines();
     let initial_state = begin
@@ -92,10 +94,9 @@ fn parse(input: &str) -> (TM, u8, usize) {
 pub mod part1 {
     use std::collections::HashSet;
 
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let (tm, mut state, mut steps) = parse(input);
+    pub fn solve((tm, mut state, mut steps): Parsed) -> usize {
         let mut cp = 0;
         let mut ones = HashSet::new();
         while steps > 0 {
@@ -115,8 +116,11 @@ pub mod part1 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "Begin in state A.
+#[derive(Solution)]
+#[solution(single_part)]
+#[example(
+    part1 = 3,
+    input = "Begin in state A.
 Perform a diagnostic checksum after 6 steps.
 
 In state A:
@@ -138,14 +142,5 @@ In state B:
     - Write the value 1.
     - Move one slot to the right.
     - Continue with state A."
-        .to_owned()
-        .replace('\n', "\r\n");
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2017/day_25_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day25;
@@ -1,27 +1,28 @@
 //! https://adventofcode.com/2018/day/1
 //! https://adventofcode.com/2018/day/1/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
-fn parse(input: &str) -> Vec<isize> {
+type Parsed = Vec<isize>;
+
+fn parse(input: &str) -> Parsed {
     input.lines().map(|line| line.parse().unwrap()).collect()
 }
 
 pub mod part1 {
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> isize {
-        parse(input).iter().sum()
+    pub fn solve(parsed: Parsed) -> isize {
+        parsed.iter().sum()
     }
 }
 
 pub mod part2 {
     use std::collections::HashSet;
 
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> isize {
-        let frequencies = parse(input);
+    pub fn solve(frequencies: Parsed) -> isize {
         let mut seen = HashSet::new();
         let mut frequency = 0;
         let mut i = 0;
@@ -36,17 +37,5 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2018/day_01_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+pub struct Day01;
@@ -1,7 +1,7 @@
 //! https://adventofcode.com/2018/day/2
 //! https://adventofcode.com/2018/day/2/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
 pub mod part1 {
     use std::collections::HashMap;
@@ -49,24 +49,16 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "abcdef
+#[derive(Solution)]
+#[solution(unparsed)]
+#[example(
+    part1 = 12,
+    input = "abcdef
 bababc
 abbcde
 abcccd
 aabcdd
 abcdee
 ababab"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2018/day_02_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day02;
@@ -1,12 +1,11 @@
 //! https://adventofcode.com/2018/day/3
 //! https://adventofcode.com/2018/day/3/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::{errors::ParseError, parsing::try_parse_lines, FromStr, Solution};
 
-use regex::Regex;
-
-#[derive(Copy, Clone, Debug, Default)]
-struct Rectangle {
+#[derive(Copy, Clone, Debug, Default, FromStr)]
+#[pattern("#{id} @ {left},{top}: {width}x{height}")]
+pub struct Rectangle {
     id: usize,
     left: usize,
     top: usize,
@@ -14,30 +13,18 @@ struct Rectangle {
     height: usize,
 }
 
-fn parse(input: &str) -> Vec<Rectangle> {
-    let pattern = Regex::new(r"#(\d+) @ (\d+),(\d+): (\d+)x(\d+)").unwrap();
-    input
-        .lines()
-        .map(|line| {
-            let captures = pattern.captures(line).unwrap();
-            Rectangle {
-                id: captures[1].parse().unwrap(),
-                left: captures[2].parse().unwrap(),
-                top: captures[3].parse().unwrap(),
-                width: captures[4].parse().unwrap(),
-                height: captures[5].parse().unwrap(),
-            }
-        })
-        .collect()
+type Parsed = Vec<Rectangle>;
+
+fn parse(input: &str) -> Result<Parsed, ParseError> {
+    try_parse_lines(input)
 }
 
 pub mod part1 {
     use std::collections::HashMap;
 
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let rectangles = parse(input);
+    pub fn solve(rectangles: Parsed) -> usize {
         let mut fabric = HashMap::new();
         for rectangle in rectangles {
             for i in rectangle.top..rectangle.top + rectangle.height {
@@ -53,10 +40,9 @@ pub mod part1 {
 pub mod part2 {
     use std::collections::HashMap;
 
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let rectangles = parse(input);
+    pub fn solve(rectangles: Parsed) -> usize {
         let mut fabric = HashMap::new();
         for rectangle in &rectangles {
             for i in rectangle.top..rectangle.top + rectangle.height {
@@ -87,20 +73,12 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "#1 @ 1,3: 4x4
+#[derive(Solution)]
+#[example(
+    part1 = 4,
+    part2 = 3,
+    input = "#1 @ 1,3: 4x4
 #2 @ 3,1: 4x4
 #3 @ 5,5: 2x2"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2018/day_03_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day03;
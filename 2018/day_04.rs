@@ -1,9 +1,8 @@
 //! https://adventofcode.com/2018/day/4
 //! https://adventofcode.com/2018/day/4/input
 
-use std::{fs::read_to_string, time::Instant};
-
 use regex::Regex;
+use utils::Solution;
 
 #[derive(Ord, PartialOrd, Eq, PartialEq, Debug, Copy, Clone)]
 enum Event {
@@ -25,7 +24,7 @@ impl From<&str> for Event {
 }
 
 #[derive(Ord, PartialOrd, Eq, PartialEq, Debug, Copy, Clone)]
-struct Record {
+pub struct Record {
     year: usize,
     month: usize,
     day: usize,
@@ -52,7 +51,9 @@ impl From<&str> for Record {
     }
 }
 
-fn parse(input: &str) -> Vec<Record> {
+type Parsed = Vec<Record>;
+
+fn parse(input: &str) -> Parsed {
     let mut result: Vec<_> = input.lines().map(Record::from).collect();
     result.sort();
     result
@@ -89,10 +90,9 @@ fn sum_records(records: Vec<Record>) -> Vec<[usize; 60]> {
 }
 
 pub mod part1 {
-    use super::{parse, sum_records};
+    use super::{sum_records, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let records = parse(input);
+    pub fn solve(records: Parsed) -> usize {
         let states = sum_records(records);
         let (id, most_sleepy_guard) = states
             .iter()
@@ -110,10 +110,9 @@ pub mod part1 {
 }
 
 pub mod part2 {
-    use super::{parse, sum_records};
+    use super::{sum_records, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let records = parse(input);
+    pub fn solve(records: Parsed) -> usize {
         let states = sum_records(records);
         let (id, minute) = states
             .iter()
@@ -135,8 +134,11 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "[1518-11-01 00:00] Guard #10 begins shift
+#[derive(Solution)]
+#[example(
+    part1 = 240,
+    part2 = 4455,
+    input = "[1518-11-01 00:00] Guard #10 begins shift
 [1518-11-01 00:05] falls asleep
 [1518-11-01 00:55] wakes up
 [1518-11-01 00:25] wakes up
@@ -153,16 +155,5 @@ pub fn main(test: bool) {
 [1518-11-04 00:36] falls asleep
 [1518-11-05 00:03] Guard #99 begins shift
 [1518-11-05 00:45] falls asleep"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2018/day_04_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day04;
@@ -1,9 +1,11 @@
 //! https://adventofcode.com/2018/day/5
 //! https://adventofcode.com/2018/day/5/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
-fn parse(input: &str) -> Vec<i8> {
+type Parsed = Vec<i8>;
+
+fn parse(input: &str) -> Parsed {
     input.chars().map(|char| char as i8).collect()
 }
 
@@ -37,19 +39,17 @@ fn solve_ignoring(bytes: &Vec<i8>, ignore_upper: i8) -> usize {
 }
 
 pub mod part1 {
-    use super::{parse, solve_ignoring};
+    use super::{solve_ignoring, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let bytes = parse(input);
+    pub fn solve(bytes: Parsed) -> usize {
         solve_ignoring(&bytes, ' ' as i8)
     }
 }
 
 pub mod part2 {
-    use super::{parse, solve_ignoring};
+    use super::{solve_ignoring, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let bytes = parse(input);
+    pub fn solve(bytes: Parsed) -> usize {
         ('A' as i8..='Z' as i8)
             .map(|ignored| solve_ignoring(&bytes, ignored))
             .min()
@@ -57,17 +57,6 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "dabAcCaCBAcCcaDA".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2018/day_05_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[example(part1 = 10, part2 = 4, input = "dabAcCaCBAcCcaDA")]
+pub struct Day05;
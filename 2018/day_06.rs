@@ -1,9 +1,11 @@
 //! https://adventofcode.com/2018/day/6
 //! https://adventofcode.com/2018/day/6/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
-fn parse(input: &str) -> Vec<(usize, usize)> {
+type Parsed = Vec<(usize, usize)>;
+
+fn parse(input: &str) -> Parsed {
     input
         .lines()
         .map(|line| {
@@ -14,10 +16,9 @@ fn parse(input: &str) -> Vec<(usize, usize)> {
 }
 
 pub mod part1 {
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let points = parse(input);
+    pub fn solve(points: Parsed) -> usize {
         let (max_i, max_j) = points
             .iter()
             .fold((usize::MIN, usize::MIN), |(max_i, max_j), (x, y)| {
@@ -84,10 +85,9 @@ pub mod part1 {
 }
 
 pub mod part2 {
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str, max_distance: usize) -> usize {
-        let points = parse(input);
+    pub fn solve(points: Parsed, max_distance: usize) -> usize {
         let (max_i, max_j) = points
             .iter()
             .fold((usize::MIN, usize::MIN), |(max_i, max_j), (x, y)| {
@@ -110,23 +110,21 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "1, 1
+#[derive(Solution)]
+#[solution(
+    params = 10000,
+    parse = |input, _| parse(input),
+    part1 = |points, _| part1::solve(points)
+)]
+#[example(
+    params = 32,
+    part1 = 17,
+    part2 = 16,
+    input = "1, 1
 1, 6
 8, 3
 3, 4
 5, 5
 8, 9"
-        .to_owned();
-    let (puzzle_input, max_distance) = if test {
-        (test_input, 32)
-    } else {
-        (read_to_string("../inputs/2018/day_06_input.txt").unwrap(), 10000)
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input, max_distance));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day06;
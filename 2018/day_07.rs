@@ -1,13 +1,13 @@
 //! https://adventofcode.com/2018/day/7
 //! https://adventofcode.com/2018/day/7/input
 
-use std::{
-    collections::{HashMap, HashSet},
-    fs::read_to_string,
-    time::Instant,
-};
+use std::collections::{HashMap, HashSet};
 
-fn parse(input: &str) -> HashMap<usize, HashSet<usize>> {
+use utils::Solution;
+
+type Parsed = HashMap<usize, HashSet<usize>>;
+
+fn parse(input: &str) -> Parsed {
     let mut result = HashMap::new();
     for line in input.lines() {
         let mut parts = line.split_whitespace();
@@ -20,10 +20,9 @@ fn parse(input: &str) -> HashMap<usize, HashSet<usize>> {
 }
 
 pub mod part1 {
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> String {
-        let mut graph = parse(input);
+    pub fn solve(mut graph: Parsed) -> String {
         let mut result = String::new();
         while !graph.is_empty() {
             let min = *graph
@@ -43,10 +42,9 @@ pub mod part1 {
 }
 
 pub mod part2 {
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str, workers: usize, seconds: usize) -> usize {
-        let mut graph = parse(input);
+    pub fn solve(mut graph: Parsed, workers: usize, seconds: usize) -> usize {
         let mut workers = vec![(0, 0); workers];
         let mut total_seconds = 0;
         while !graph.is_empty() || workers.iter().map(|(seconds, _)| seconds).sum::<usize>() != 0 {
@@ -75,24 +73,23 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "Step C must be finished before step A can begin.
+#[derive(Solution)]
+#[solution(
+    params = (5, 60),
+    parse = |input, _| parse(input),
+    part1 = |graph, _| part1::solve(graph),
+    part2 = |graph, (workers, seconds)| part2::solve(graph, workers, seconds)
+)]
+#[example(
+    params = (2, 0),
+    part1 = "CABDFE",
+    part2 = 15,
+    input = "Step C must be finished before step A can begin.
 Step C must be finished before step F can begin.
 Step A must be finished before step B can begin.
 Step A must be finished before step D can begin.
 Step B must be finished before step E can begin.
 Step D must be finished before step E can begin.
 Step F must be finished before step E can begin."
-        .to_owned();
-    let (puzzle_input, workers, seconds) = if test {
-        (test_input, 2, 0)
-    } else {
-        (read_to_string("../inputs/2018/day_07_input.txt").unwrap(), 5, 60)
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input, workers, seconds));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day07;
@@ -1,9 +1,11 @@
 //! https://adventofcode.com/2018/day/8
 //! https://adventofcode.com/2018/day/8/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
-fn parse(input: &str) -> Vec<usize> {
+type Parsed = Vec<usize>;
+
+fn parse(input: &str) -> Parsed {
     input
         .split_whitespace()
         .map(|n| n.parse().unwrap())
@@ -11,7 +13,7 @@ fn parse(input: &str) -> Vec<usize> {
 }
 
 pub mod part1 {
-    use super::parse;
+    use super::Parsed;
 
     fn count_metadata(numbers: &Vec<usize>, mut i: usize) -> (usize, usize) {
         if i >= numbers.len() {
@@ -35,14 +37,13 @@ pub mod part1 {
         }
     }
 
-    pub fn solve(input: &str) -> usize {
-        let numbers = parse(input);
+    pub fn solve(numbers: Parsed) -> usize {
         count_metadata(&numbers, 0).1
     }
 }
 
 pub mod part2 {
-    use super::parse;
+    use super::Parsed;
 
     fn value_of(numbers: &Vec<usize>, mut i: usize) -> (usize, usize) {
         if i >= numbers.len() {
@@ -78,23 +79,11 @@ pub mod part2 {
         }
     }
 
-    pub fn solve(input: &str) -> usize {
-        let numbers = parse(input);
+    pub fn solve(numbers: Parsed) -> usize {
         value_of(&numbers, 0).1
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2018/day_08_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[example(part1 = 138, part2 = 66, input = "2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2")]
+pub struct Day08;
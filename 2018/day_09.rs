@@ -1,9 +1,11 @@
 //! https://adventofcode.com/2018/day/9
 //! https://adventofcode.com/2018/day/9/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
-fn parse(input: &str) -> (usize, usize) {
+type Parsed = (usize, usize);
+
+fn parse(input: &str) -> Parsed {
     let mut parts = input.split_whitespace();
     (
         parts.next().unwrap().parse().unwrap(),
@@ -50,34 +52,21 @@ fn play(players: usize, marbles: usize) -> usize {
 }
 
 pub mod part1 {
-    use super::{parse, play};
+    use super::{play, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let (players, marbles) = parse(input);
+    pub fn solve((players, marbles): Parsed) -> usize {
         play(players, marbles)
     }
 }
 
 pub mod part2 {
-    use super::{parse, play};
+    use super::{play, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let (players, marbles) = parse(input);
+    pub fn solve((players, marbles): Parsed) -> usize {
         play(players, marbles * 100)
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "10 players; last marble is worth 1618 points".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2018/day_09_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[example(part1 = 8317, input = "10 players; last marble is worth 1618 points")]
+pub struct Day09;
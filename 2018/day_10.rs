@@ -1,12 +1,12 @@
 //! https://adventofcode.com/2018/day/10
 //! https://adventofcode.com/2018/day/10/input
 
-use std::{fs::read_to_string, time::Instant};
-
 use itertools::Itertools;
 use regex::Regex;
+use utils::Solution;
 
-struct Light {
+#[derive(Clone)]
+pub struct Light {
     x: isize,
     y: isize,
     horizontal: isize,
@@ -26,7 +26,9 @@ impl From<&str> for Light {
     }
 }
 
-fn parse(input: &str) -> Vec<Light> {
+type Parsed = Vec<Light>;
+
+fn parse(input: &str) -> Parsed {
     input.lines().map(Light::from).collect()
 }
 
@@ -70,25 +72,23 @@ fn move_lights(lights: &mut Vec<Light>) -> (usize, String) {
 }
 
 pub mod part1 {
-    use super::{move_lights, parse};
+    use super::{move_lights, Parsed};
 
-    pub fn solve(input: &str) -> String {
-        let mut lights = parse(input);
+    pub fn solve(mut lights: Parsed) -> String {
         move_lights(&mut lights).1
     }
 }
 
 pub mod part2 {
-    use super::{move_lights, parse};
+    use super::{move_lights, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let mut lights = parse(input);
+    pub fn solve(mut lights: Parsed) -> usize {
         move_lights(&mut lights).0
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "position=< 9,  1> velocity=< 0,  2>
+#[derive(Solution)]
+#[example(input = "position=< 9,  1> velocity=< 0,  2>
 position=< 7,  0> velocity=<-1,  0>
 position=< 3, -2> velocity=<-1,  1>
 position=< 6, 10> velocity=<-2, -1>
@@ -118,17 +118,5 @@ position=< 5,  0> velocity=< 1,  0>
 position=<-6,  0> velocity=< 2,  0>
 position=< 5,  9> velocity=< 1, -2>
 position=<14,  7> velocity=<-2,  0>
-position=<-3,  6> velocity=< 2, -1>"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2018/day_10_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+position=<-3,  6> velocity=< 2, -1>")]
+pub struct Day10;
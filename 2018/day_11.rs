@@ -1,9 +1,11 @@
 //! https://adventofcode.com/2018/day/11
 //! https://adventofcode.com/2018/day/11/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
-fn parse(input: &str) -> isize {
+type Parsed = isize;
+
+fn parse(input: &str) -> Parsed {
     input.parse().unwrap()
 }
 
@@ -55,20 +57,20 @@ fn find_max_rect(grid: &[[isize; 300]], size: usize) -> (usize, usize, isize) {
 }
 
 pub mod part1 {
-    use super::{find_max_rect, make_grid, parse};
+    use super::{find_max_rect, make_grid, Parsed};
 
-    pub fn solve(input: &str) -> String {
-        let grid = make_grid(parse(input));
+    pub fn solve(serial: Parsed) -> String {
+        let grid = make_grid(serial);
         let result = find_max_rect(&grid, 3);
         format!("{},{}", result.0, result.1)
     }
 }
 
 pub mod part2 {
-    use super::{find_max_rect, make_grid, parse};
+    use super::{find_max_rect, make_grid, Parsed};
 
-    pub fn solve(input: &str) -> String {
-        let grid = make_grid(parse(input));
+    pub fn solve(serial: Parsed) -> String {
+        let grid = make_grid(serial);
         let (result, size) = (2..=300)
             .map(|size| (find_max_rect(&grid, size), size))
             .max_by_key(|((_, _, value), _)| *value)
@@ -77,17 +79,6 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "42".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2018/day_11_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[example(part1 = "21,61", input = "42")]
+pub struct Day11;
@@ -1,15 +1,14 @@
 //! https://adventofcode.com/2018/day/12
 //! https://adventofcode.com/2018/day/12/input
 
-use std::{
-    collections::{hash_map::Entry, HashMap, VecDeque},
-    fs::read_to_string,
-    time::Instant,
-};
+use std::collections::{hash_map::Entry, HashMap, VecDeque};
 
 use itertools::Itertools;
+use utils::Solution;
 
-fn parse(input: &str) -> (VecDeque<bool>, [bool; 32]) {
+type Parsed = (VecDeque<bool>, [bool; 32]);
+
+fn parse(input: &str) -> Parsed {
     let mut lines = input.lines();
     let initial_state = lines.next().unwrap()[15..]
         .chars()
@@ -80,25 +79,25 @@ fn apply_generations(mut state: VecDeque<bool>, rules: [bool; 32], generations:
 }
 
 pub mod part1 {
-    use super::{apply_generations, parse};
+    use super::{apply_generations, Parsed};
 
-    pub fn solve(input: &str) -> isize {
-        let (initial_state, rules) = parse(input);
+    pub fn solve((initial_state, rules): Parsed) -> isize {
         apply_generations(initial_state, rules, 20)
     }
 }
 
 pub mod part2 {
-    use super::{apply_generations, parse};
+    use super::{apply_generations, Parsed};
 
-    pub fn solve(input: &str) -> isize {
-        let (initial_state, rules) = parse(input);
+    pub fn solve((initial_state, rules): Parsed) -> isize {
         apply_generations(initial_state, rules, 50000000000)
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "initial state: #..#.#..##......###...###
+#[derive(Solution)]
+#[example(
+    part1 = 325,
+    input = "initial state: #..#.#..##......###...###
 
 ...## => #
 ..#.. => #
@@ -114,16 +113,5 @@ pub fn main(test: bool) {
 ###.. => #
 ###.# => #
 ####. => #"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2018/day_12_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day12;
@@ -1,7 +1,9 @@
 //! https://adventofcode.com/2018/day/13
 //! https://adventofcode.com/2018/day/13/input
 
-use std::{cmp::Ordering, collections::BinaryHeap, fs::read_to_string, time::Instant};
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+use utils::Solution;
 
 #[derive(Copy, Clone, Debug)]
 enum Turn {
@@ -21,7 +23,7 @@ impl Turn {
 }
 
 #[derive(Copy, Clone, Debug)]
-struct Cart {
+pub struct Cart {
     position: (isize, isize),
     direction: (isize, isize),
     next_turn: Turn,
@@ -47,7 +49,9 @@ impl Ord for Cart {
     }
 }
 
-fn parse(input: &str) -> (Vec<Vec<char>>, BinaryHeap<Cart>) {
+type Parsed = (Vec<Vec<char>>, BinaryHeap<Cart>);
+
+fn parse(input: &str) -> Parsed {
     let mut heap = BinaryHeap::new();
     let map = input
         .lines()
@@ -149,10 +153,9 @@ fn tick(
 pub mod part1 {
     use std::collections::BinaryHeap;
 
-    use super::{parse, tick};
+    use super::{tick, Parsed};
 
-    pub fn solve(input: &str) -> String {
-        let (map, mut carts) = parse(input);
+    pub fn solve((map, mut carts): Parsed) -> String {
         let mut support = BinaryHeap::with_capacity(carts.len());
         loop {
             if let Some((y, x)) = tick(&map, &mut carts, &mut support).pop() {
@@ -165,10 +168,9 @@ pub mod part1 {
 pub mod part2 {
     use std::collections::BinaryHeap;
 
-    use super::{parse, tick};
+    use super::{tick, Parsed};
 
-    pub fn solve(input: &str) -> String {
-        let (map, mut carts) = parse(input);
+    pub fn solve((map, mut carts): Parsed) -> String {
         let mut support = BinaryHeap::with_capacity(carts.len());
         while carts.len() > 1 {
             let crashes = tick(&map, &mut carts, &mut support);
@@ -185,24 +187,15 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = r"/>-<\  
+#[derive(Solution)]
+#[example(
+    part2 = "6,4",
+    input = r"/>-<\  
 |   |  
 | /<+-\
 | | | v
 \>+</ |
   |   ^
   \<->/"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2018/day_13_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day13;
@@ -1,7 +1,7 @@
 //! https://adventofcode.com/2018/day/14
 //! https://adventofcode.com/2018/day/14/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
 fn parse(input: &str) -> usize {
     input.parse().unwrap()
@@ -76,17 +76,7 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "59414".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2018/day_14_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[solution(unparsed)]
+#[example(part2 = 2018, input = "59414")]
+pub struct Day14;
@@ -5,17 +5,16 @@ use std::{
     cell::RefCell,
     cmp::Ordering,
     collections::{hash_map::Entry, HashMap, HashSet, VecDeque},
-    fs::read_to_string,
     rc::Rc,
-    time::Instant,
 };
 
 use itertools::Itertools;
+use utils::Solution;
 
 const ADJACENT: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
 
 #[derive(Copy, Clone, Debug)]
-struct Unit {
+pub struct Unit {
     elf: bool,
     hp: isize,
     position: (usize, usize),
@@ -57,7 +56,9 @@ enum RoundResult {
     GoblinsWin,
 }
 
-fn parse(input: &str) -> (Vec<Vec<bool>>, Vec<Unit>) {
+type Parsed = (Vec<Vec<bool>>, Vec<Unit>);
+
+fn parse(input: &str) -> Parsed {
     let mut walls =
         vec![vec![false; input.lines().next().unwrap().chars().count()]; input.lines().count()];
     let mut units = Vec::new();
@@ -205,7 +206,7 @@ fn turn(
 pub mod part1 {
     use std::{cell::RefCell, rc::Rc};
 
-    use super::{parse, turn, RoundResult, TurnResult, Unit};
+    use super::{turn, Parsed, RoundResult, TurnResult, Unit};
 
     fn round(
         walls: &mut [Vec<bool>],
@@ -227,8 +228,7 @@ pub mod part1 {
         RoundResult::NoWinner
     }
 
-    pub fn solve(input: &str) -> usize {
-        let (mut walls, units) = parse(input);
+    pub fn solve((mut walls, units): Parsed) -> usize {
         let mut units: Vec<_> = units
             .into_iter()
             .map(|unit| Rc::new(RefCell::new(unit)))
@@ -236,6 +236,11 @@ pub mod part1 {
         let mut rounds = 0;
         while let RoundResult::NoWinner = round(&mut walls, &mut units) {
             rounds += 1;
+            utils::debug!(
+                "round {}: {} units left",
+                rounds,
+                units.iter().filter(|unit| unit.borrow().hp > 0).count()
+            );
         }
         let hp = units
             .iter()
@@ -248,7 +253,7 @@ pub mod part1 {
 pub mod part2 {
     use std::{cell::RefCell, rc::Rc};
 
-    use super::{parse, turn, RoundResult, TurnResult, Unit};
+    use super::{turn, Parsed, RoundResult, TurnResult, Unit};
 
     fn round(
         walls: &mut [Vec<bool>],
@@ -273,10 +278,10 @@ pub mod part2 {
         RoundResult::NoWinner
     }
 
-    pub fn solve(input: &str) -> usize {
-        let (mut walls, units) = parse(input);
+    pub fn solve((mut walls, units): Parsed) -> usize {
         let mut damage = 4;
         loop {
+            let _span = utils::span!(Debug, "elves dealing {} damage", damage);
             let mut units: Vec<_> = units
                 .iter()
                 .map(|unit| {
@@ -293,17 +298,22 @@ pub mod part2 {
                 match result {
                     RoundResult::NoWinner => {
                         rounds += 1;
+                        utils::trace!("round {}", rounds);
                     }
                     RoundResult::ElvesWin => {
+                        utils::info!("the elves win with {} damage", damage);
                         return rounds
                             * units
                                 .iter()
                                 .filter_map(|unit| {
                                     (unit.borrow().hp > 0).then_some(unit.borrow().hp as usize)
                                 })
-                                .sum::<usize>()
+                                .sum::<usize>();
+                    }
+                    RoundResult::GoblinsWin => {
+                        utils::debug!("an elf died in round {}", rounds + 1);
+                        break;
                     }
-                    RoundResult::GoblinsWin => break,
                 }
             }
             damage += 1;
@@ -311,66 +321,18 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    //     let test_input = "#######
-    // #.G...#
-    // #...EG#
-    // #.#.#G#
-    // #..G#E#
-    // #.....#
-    // #######"
-    //         .to_owned();
-    //     let test_input = "#######
-    // #G..#E#
-    // #E#E.E#
-    // #G.##.#
-    // #...#E#
-    // #...E.#
-    // #######"
-    //         .to_owned();
-    //     let test_input = "#######
-    // #E..EG#
-    // #.#G.E#
-    // #E.##E#
-    // #G..#.#
-    // #..E#.#
-    // #######"
-    //         .to_owned();
-    //     let test_input = "#######
-    // #E.G#.#
-    // #.#G..#
-    // #G.#.G#
-    // #G..#.#
-    // #...E.#
-    // #######"
-    //         .to_owned();
-    //     let test_input = "#######
-    // #.E...#
-    // #.#..G#
-    // #.###.#
-    // #E#G#G#
-    // #...#G#
-    // #######"
-    //         .to_owned();
-    let test_input = "#########
-    #G......#
-    #.E.#...#
-    #..##..G#
-    #...##..#
-    #...#...#
-    #.G...G.#
-    #.....G.#
-    #########"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2018/day_15_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[example(
+    part1 = 18740,
+    part2 = 1140,
+    input = "#########
+#G......#
+#.E.#...#
+#..##..G#
+#...##..#
+#...#...#
+#.G...G.#
+#.....G.#
+#########"
+)]
+pub struct Day15;
@@ -1,14 +1,12 @@
 //! https://adventofcode.com/2018/day/16
 //! https://adventofcode.com/2018/day/16/input
 
-use std::{fs::read_to_string, time::Instant};
-
-use crate::LINE_ENDING;
+use utils::Solution;
 
 type Registers = [usize; 4];
 
 #[derive(Copy, Clone, Debug)]
-struct Instruction {
+pub struct Instruction {
     opcode: usize,
     a: usize,
     b: usize,
@@ -28,7 +26,7 @@ impl From<&str> for Instruction {
 }
 
 #[derive(Copy, Clone, Debug)]
-struct Sample {
+pub struct Sample {
     before: Registers,
     instruction: Instruction,
     after: Registers,
@@ -132,10 +130,12 @@ impl Instruction {
     }
 }
 
-fn parse(input: &str) -> (Vec<Sample>, Vec<Instruction>) {
-    let separator = LINE_ENDING.repeat(4);
+type Parsed = (Vec<Sample>, Vec<Instruction>);
+
+fn parse(input: &str) -> Parsed {
+    let separator = "\n".repeat(4);
     let mut parts = input.split(&separator);
-    let separator = LINE_ENDING.repeat(2);
+    let separator = "\n".repeat(2);
     let samples = parts.next().unwrap().split(&separator);
     let instructions = parts.next().unwrap().lines();
     (
@@ -145,10 +145,9 @@ fn parse(input: &str) -> (Vec<Sample>, Vec<Instruction>) {
 }
 
 pub mod part1 {
-    use super::{parse, Sample, OPCODES};
+    use super::{Parsed, Sample, OPCODES};
 
-    pub fn solve(input: &str) -> usize {
-        let (samples, _) = parse(input);
+    pub fn solve((samples, _): Parsed) -> usize {
         let mut result = 0;
         for Sample {
             before,
@@ -179,10 +178,9 @@ pub mod part1 {
 pub mod part2 {
     use std::collections::HashSet;
 
-    use super::{parse, Behavior, Sample, OPCODES};
+    use super::{Behavior, Parsed, Sample, OPCODES};
 
-    pub fn solve(input: &str) -> usize {
-        let (samples, instructions) = parse(input);
+    pub fn solve((samples, instructions): Parsed) -> usize {
         let mut valid = [
             HashSet::new(),
             HashSet::new(),
@@ -242,25 +240,15 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "Before: [3, 2, 1, 1]
+#[derive(Solution)]
+#[example(
+    part1 = 1,
+    input = "Before: [3, 2, 1, 1]
 9 2 1 2
 After:  [3, 2, 2, 1]
 
 
 
 1 2 3 4"
-        .to_owned()
-        .replace('\n', "\r\n");
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2018/day_16_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day16;
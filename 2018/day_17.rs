@@ -1,11 +1,9 @@
 //! https://adventofcode.com/2018/day/17
 //! https://adventofcode.com/2018/day/17/input
 
-use std::{
-    collections::{hash_map::Entry, HashMap, HashSet},
-    fs::read_to_string,
-    time::Instant,
-};
+use std::collections::{hash_map::Entry, HashMap, HashSet};
+
+use utils::Solution;
 
 type Coord = (usize, usize);
 
@@ -22,7 +20,9 @@ enum Direction {
     Right,
 }
 
-fn parse(input: &str) -> (HashSet<Coord>, usize, usize) {
+type Parsed = (HashSet<Coord>, usize, usize);
+
+fn parse(input: &str) -> Parsed {
     let mut result = HashSet::new();
     for line in input.lines() {
         let mut parts = line.split(", ");
@@ -133,10 +133,9 @@ fn dfs(
 pub mod part1 {
     use std::collections::HashMap;
 
-    use super::{dfs, parse, Direction};
+    use super::{dfs, Direction, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let (clay, min_y, max_y) = parse(input);
+    pub fn solve((clay, min_y, max_y): Parsed) -> usize {
         let mut explored = HashMap::new();
         dfs(&clay, (500, 0), max_y, Direction::Down, &mut explored);
         explored.len() - min_y
@@ -146,10 +145,9 @@ pub mod part1 {
 pub mod part2 {
     use std::collections::HashMap;
 
-    use super::{dfs, parse, Direction, WaterState};
+    use super::{dfs, Direction, Parsed, WaterState};
 
-    pub fn solve(input: &str) -> usize {
-        let (clay, _, max_y) = parse(input);
+    pub fn solve((clay, _, max_y): Parsed) -> usize {
         let mut explored = HashMap::new();
         dfs(&clay, (500, 0), max_y, Direction::Down, &mut explored);
         explored
@@ -159,8 +157,11 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "x=495, y=2..7
+#[derive(Solution)]
+#[example(
+    part1 = 57,
+    part2 = 29,
+    input = "x=495, y=2..7
 y=7, x=495..501
 x=501, y=3..7
 x=498, y=2..4
@@ -168,16 +169,5 @@ x=506, y=1..2
 x=498, y=10..13
 x=504, y=10..13
 y=13, x=498..504"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2018/day_17_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day17;
@@ -1,10 +1,10 @@
 //! https://adventofcode.com/2018/day/18
 //! https://adventofcode.com/2018/day/18/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::{coords::u_iter_near, Solution};
 
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialOrd, PartialEq, Ord)]
-enum Acre {
+pub enum Acre {
     Ground,
     Trees,
     Lumberyard,
@@ -23,24 +23,20 @@ impl From<char> for Acre {
     }
 }
 
-fn parse(input: &str) -> Acres {
+type Parsed = Acres;
+
+fn parse(input: &str) -> Parsed {
     input
         .lines()
         .map(|line| line.chars().map(Acre::from).collect())
         .collect()
 }
 
-fn neighbors(i: usize, j: usize, size: usize) -> impl Iterator<Item = (usize, usize)> {
-    (i.saturating_sub(1)..=(i + 1).min(size - 1))
-        .flat_map(move |i| (j.saturating_sub(1)..=(j + 1).min(size - 1)).map(move |j| (i, j)))
-        .filter(move |neighbor| *neighbor != (i, j))
-}
-
 fn minute(acres: &mut Acres, support: &mut Acres) {
     for i in 0..acres.len() {
         for (j, acre) in acres[i].iter().enumerate() {
             let (_, trees, lumberyards) =
-                neighbors(i, j, acres.len()).fold((0, 0, 0), |mut acc, (ni, nj)| {
+                u_iter_near(i, j, acres.len(), acres.len()).fold((0, 0, 0), |mut acc, (ni, nj)| {
                     match acres[ni][nj] {
                         Acre::Ground => acc.0 += 1,
                         Acre::Trees => acc.1 += 1,
@@ -63,10 +59,9 @@ fn minute(acres: &mut Acres, support: &mut Acres) {
 }
 
 pub mod part1 {
-    use super::{minute, parse, Acre};
+    use super::{minute, Acre, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let mut acres = parse(input);
+    pub fn solve(mut acres: Parsed) -> usize {
         let mut support = acres.clone();
         for _ in 0..10 {
             minute(&mut acres, &mut support);
@@ -86,10 +81,9 @@ pub mod part1 {
 pub mod part2 {
     use std::collections::{hash_map::Entry, HashMap};
 
-    use super::{minute, parse, Acre};
+    use super::{minute, Acre, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let mut acres = parse(input);
+    pub fn solve(mut acres: Parsed) -> usize {
         let mut support = acres.clone();
         let mut states = HashMap::new();
         let mut min = 0;
@@ -121,8 +115,10 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = ".#.#...|#.
+#[derive(Solution)]
+#[example(
+    part1 = 1147,
+    input = ".#.#...|#.
 .....#|##|
 .|..|...#.
 ..|#.....#
@@ -132,16 +128,5 @@ pub fn main(test: bool) {
 ||...#|.#|
 |.||||..|.
 ...#.|..|."
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2018/day_18_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day18;
@@ -1,12 +1,12 @@
 //! https://adventofcode.com/2018/day/19
 //! https://adventofcode.com/2018/day/19/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
 type Registers = [usize; 6];
 
 #[derive(Copy, Clone, Debug)]
-struct Instruction {
+pub struct Instruction {
     opcode: Behavior,
     a: usize,
     b: usize,
@@ -106,7 +106,9 @@ impl From<&str> for Behavior {
     }
 }
 
-fn parse(input: &str) -> (usize, Vec<Instruction>) {
+type Parsed = (usize, Vec<Instruction>);
+
+fn parse(input: &str) -> Parsed {
     let mut lines = input.lines();
     (
         lines
@@ -122,10 +124,9 @@ fn parse(input: &str) -> (usize, Vec<Instruction>) {
 }
 
 pub mod part1 {
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let (ip, instructions) = parse(input);
+    pub fn solve((ip, instructions): Parsed) -> usize {
         let mut registers = [0; 6];
         while registers[ip] < instructions.len() {
             instructions[registers[ip]].apply(&mut registers);
@@ -137,10 +138,9 @@ pub mod part1 {
 }
 
 pub mod part2 {
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let (ip, instructions) = parse(input);
+    pub fn solve((ip, instructions): Parsed) -> usize {
         let mut registers = [0; 6];
         registers[0] = 1;
         while registers[ip] < instructions.len() && registers[1] < 10000 {
@@ -163,25 +163,13 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "#ip 0
+#[derive(Solution)]
+#[example(input = "#ip 0
 seti 5 0 1
 seti 6 0 2
 addi 0 1 0
 addr 1 2 3
 setr 1 0 0
 seti 8 0 4
-seti 9 0 5"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2018/day_19_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+seti 9 0 5")]
+pub struct Day19;
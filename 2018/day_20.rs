@@ -1,16 +1,14 @@
 //! https://adventofcode.com/2018/day/20
 //! https://adventofcode.com/2018/day/20/input
 
-use std::{
-    collections::{HashMap, HashSet},
-    fs::read_to_string,
-    time::Instant,
-};
+use std::collections::{HashMap, HashSet};
+
+use utils::Solution;
 
 type Coord = (isize, isize);
 
 #[derive(Clone, Debug)]
-enum Match {
+pub enum Match {
     N,
     E,
     S,
@@ -88,7 +86,9 @@ impl From<char> for Match {
     }
 }
 
-fn parse(input: &str) -> Match {
+type Parsed = Match;
+
+fn parse(input: &str) -> Parsed {
     Match::parse(input.chars().skip(1).collect())
 }
 
@@ -202,7 +202,7 @@ fn explore(regex: &Match, coord: Coord) -> HashMap<Coord, HashSet<Coord>> {
 pub mod part1 {
     use std::collections::{HashMap, HashSet, VecDeque};
 
-    use super::{explore, parse, Coord};
+    use super::{explore, Coord, Parsed};
 
     fn bfs(graph: &HashMap<Coord, HashSet<Coord>>, start: Coord) -> usize {
         let mut queue = VecDeque::from([start]);
@@ -225,8 +225,7 @@ pub mod part1 {
         distance
     }
 
-    pub fn solve(input: &str) -> usize {
-        let regex = parse(input);
+    pub fn solve(regex: Parsed) -> usize {
         let graph = explore(&regex, (0, 0));
         bfs(&graph, (0, 0)) - 2
     }
@@ -235,7 +234,7 @@ pub mod part1 {
 pub mod part2 {
     use std::collections::{HashMap, HashSet, VecDeque};
 
-    use super::{explore, parse, Coord};
+    use super::{explore, Coord, Parsed};
 
     fn bfs(graph: &HashMap<Coord, HashSet<Coord>>, start: Coord) -> usize {
         let mut at_least_1000 = 0;
@@ -262,24 +261,15 @@ pub mod part2 {
         at_least_1000
     }
 
-    pub fn solve(input: &str) -> usize {
-        let regex = parse(input);
+    pub fn solve(regex: Parsed) -> usize {
         let graph = explore(&regex, (0, 0));
         bfs(&graph, (0, 0))
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "^WSSEESWWWNW(S|NENNEEEENN(ESSSSW(NWSW|SSEN)|WSWWN(E|WWS(E|SS))))$".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2018/day_20_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[example(
+    part1 = 31,
+    input = "^WSSEESWWWNW(S|NENNEEEENN(ESSSSW(NWSW|SSEN)|WSWWN(E|WWS(E|SS))))$"
+)]
+pub struct Day20;
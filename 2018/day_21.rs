@@ -1,12 +1,12 @@
 //! https://adventofcode.com/2018/day/21
 //! https://adventofcode.com/2018/day/21/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
 type Registers = [usize; 6];
 
 #[derive(Copy, Clone, Debug)]
-struct Instruction {
+pub struct Instruction {
     opcode: Behavior,
     a: usize,
     b: usize,
@@ -106,7 +106,9 @@ impl From<&str> for Behavior {
     }
 }
 
-fn parse(input: &str) -> (usize, Vec<Instruction>) {
+type Parsed = (usize, Vec<Instruction>);
+
+fn parse(input: &str) -> Parsed {
     let mut lines = input.lines();
     (
         lines
@@ -122,10 +124,9 @@ fn parse(input: &str) -> (usize, Vec<Instruction>) {
 }
 
 pub mod part1 {
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let (ip, instructions) = parse(input);
+    pub fn solve((ip, instructions): Parsed) -> usize {
         let mut registers = [0; 6];
         while registers[ip] < instructions.len() {
             instructions[registers[ip]].apply(&mut registers);
@@ -141,10 +142,9 @@ pub mod part1 {
 pub mod part2 {
     use std::collections::HashSet;
 
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let (ip, instructions) = parse(input);
+    pub fn solve((ip, instructions): Parsed) -> usize {
         let mut registers = [0; 6];
         let mut r4s = HashSet::new();
         let mut last = 0;
@@ -163,17 +163,5 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2018/day_21_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+pub struct Day21;
@@ -1,7 +1,7 @@
 //! https://adventofcode.com/2018/day/22
 //! https://adventofcode.com/2018/day/22/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
 #[derive(Copy, Clone, Debug)]
 enum Region {
@@ -48,7 +48,9 @@ impl From<usize> for Tool {
     }
 }
 
-fn parse(input: &str) -> (usize, (usize, usize)) {
+type Parsed = (usize, (usize, usize));
+
+fn parse(input: &str) -> Parsed {
     let mut lines = input.lines();
     let depth = lines
         .next()
@@ -98,10 +100,9 @@ fn build_cave(depth: usize, target: (usize, usize), (i, j): (usize, usize)) -> V
 }
 
 pub mod part1 {
-    use super::{build_cave, parse};
+    use super::{build_cave, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let (depth, target) = parse(input);
+    pub fn solve((depth, target): Parsed) -> usize {
         let cave = build_cave(depth, target, target);
         let mut risk_level = 0;
         for i in 0..cave.len() {
@@ -114,9 +115,9 @@ pub mod part1 {
 }
 
 pub mod part2 {
-    use super::{build_cave, parse, Region, Tool};
+    use utils::coords::CROSS_NEAR;
 
-    const ADJACENT: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+    use super::{build_cave, Parsed, Region, Tool};
 
     fn explore(
         cave: &[Vec<Region>],
@@ -140,7 +141,7 @@ pub mod part2 {
             return minutes + 7 * (tool != Tool::Torch) as usize;
         }
         for next in
-            ADJACENT.map(|(ai, aj)| ((i as isize + ai) as usize, (j as isize + aj) as usize))
+            CROSS_NEAR.map(|(ai, aj)| ((i as isize + ai) as usize, (j as isize + aj) as usize))
         {
             for tool_diff in 0..=2 {
                 let next_tool = (tool as usize + tool_diff).into();
@@ -160,8 +161,7 @@ pub mod part2 {
         min_global
     }
 
-    pub fn solve(input: &str) -> usize {
-        let (depth, target) = parse(input);
+    pub fn solve((depth, target): Parsed) -> usize {
         //   smaller is better        smaller is better          higher is better, but not too much
         let (width_coefficient, height_coefficient, len_coefficient) = (5, 1, 5);
         let cave = build_cave(
@@ -181,19 +181,10 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "depth: 510
+#[derive(Solution)]
+#[example(
+    part1 = 114,
+    input = "depth: 510
 target: 10,10"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2018/day_22_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day22;
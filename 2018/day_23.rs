@@ -1,19 +1,20 @@
 //! https://adventofcode.com/2018/day/23
 //! https://adventofcode.com/2018/day/23/input
 
-use std::{fs::read_to_string, time::Instant};
-
 use regex::Regex;
+use utils::Solution;
 
 #[derive(Copy, Clone, Debug)]
-struct Nanobot {
+pub struct Nanobot {
     x: isize,
     y: isize,
     z: isize,
     radius: isize,
 }
 
-fn parse(input: &str) -> Vec<Nanobot> {
+type Parsed = Vec<Nanobot>;
+
+fn parse(input: &str) -> Parsed {
     let pattern = Regex::new(r"pos=<(-?\d+),(-?\d+),(-?\d+)>, r=(-?\d+)").unwrap();
     input
         .lines()
@@ -30,10 +31,9 @@ fn parse(input: &str) -> Vec<Nanobot> {
 }
 
 pub mod part1 {
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let nanobots = parse(input);
+    pub fn solve(nanobots: Parsed) -> usize {
         let strongest = nanobots
             .iter()
             .max_by_key(|nanobot| nanobot.radius)
@@ -53,7 +53,7 @@ pub mod part1 {
 pub mod part2 {
     use std::{cmp::Ordering, collections::BinaryHeap};
 
-    use super::{parse, Nanobot};
+    use super::{Nanobot, Parsed};
 
     #[derive(Clone, Debug)]
     struct Space {
@@ -230,30 +230,20 @@ pub mod part2 {
         unreachable!()
     }
 
-    pub fn solve(input: &str) -> isize {
-        let nanobots = parse(input);
+    pub fn solve(nanobots: Parsed) -> isize {
         let result = find_best(nanobots);
         result.distance
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "pos=<10,12,12>, r=2
+#[derive(Solution)]
+#[example(
+    part2 = 36,
+    input = "pos=<10,12,12>, r=2
 pos=<12,14,12>, r=2
 pos=<16,12,12>, r=4
 pos=<14,14,14>, r=6
 pos=<50,50,50>, r=200
 pos=<10,10,10>, r=5"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2018/day_23_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day23;
@@ -1,11 +1,8 @@
 //! https://adventofcode.com/2018/day/24
 //! https://adventofcode.com/2018/day/24/input
 
-use std::{fs::read_to_string, time::Instant};
-
 use regex::Regex;
-
-use crate::LINE_ENDING;
+use utils::Solution;
 
 type Groups = Vec<Group>;
 
@@ -32,7 +29,7 @@ impl From<&str> for Damage {
 }
 
 #[derive(Clone, Debug)]
-struct Group {
+pub struct Group {
     units: isize,
     hp: isize,
     damage: isize,
@@ -74,8 +71,10 @@ impl From<(&str, bool)> for Group {
     }
 }
 
-fn parse(input: &str) -> Groups {
-    let separator = LINE_ENDING.repeat(2);
+type Parsed = Groups;
+
+fn parse(input: &str) -> Parsed {
+    let separator = "\n".repeat(2);
     let mut armies = input.split(&separator);
 
     armies
@@ -193,10 +192,9 @@ fn fight(groups: &mut Groups) {
 }
 
 pub mod part1 {
-    use super::{fight, parse};
+    use super::{fight, Parsed};
 
-    pub fn solve(input: &str) -> isize {
-        let mut groups = parse(input);
+    pub fn solve(mut groups: Parsed) -> isize {
         groups.sort_by_key(|group| group.initiative);
         groups.reverse();
         fight(&mut groups);
@@ -205,10 +203,9 @@ pub mod part1 {
 }
 
 pub mod part2 {
-    use super::{fight, parse};
+    use super::{fight, Parsed};
 
-    pub fn solve(input: &str) -> isize {
-        let mut groups = parse(input);
+    pub fn solve(mut groups: Parsed) -> isize {
         groups.sort_by_key(|group| group.initiative);
         groups.reverse();
         let mut boost = 1;
@@ -227,25 +224,16 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "Immune System:
+#[derive(Solution)]
+#[example(
+    part1 = 5216,
+    part2 = 51,
+    input = "Immune System:
 17 units each with 5390 hit points (weak to radiation, bludgeoning) with an attack that does 4507 fire damage at initiative 2
 989 units each with 1274 hit points (immune to fire; weak to bludgeoning, slashing) with an attack that does 25 slashing damage at initiative 3
 
 Infection:
 801 units each with 4706 hit points (weak to radiation) with an attack that does 116 bludgeoning damage at initiative 1
 4485 units each with 2961 hit points (immune to radiation; weak to fire, cold) with an attack that does 12 slashing damage at initiative 4"
-        .to_owned()
-        .replace('\n',"\r\n");
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2018/day_24_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day24;
@@ -1,11 +1,13 @@
 //! https://adventofcode.com/2018/day/25
 //! https://adventofcode.com/2018/day/25/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
 type Point = (isize, isize, isize, isize);
 
-fn parse(input: &str) -> Vec<Point> {
+type Parsed = Vec<Point>;
+
+fn parse(input: &str) -> Parsed {
     input
         .lines()
         .map(|line| {
@@ -18,14 +20,13 @@ fn parse(input: &str) -> Vec<Point> {
 pub mod part1 {
     use std::collections::{HashMap, HashSet, VecDeque};
 
-    use super::{parse, Point};
+    use super::{Parsed, Point};
 
     fn manhattan(p1: &Point, p2: &Point) -> isize {
         (p1.0 - p2.0).abs() + (p1.1 - p2.1).abs() + (p1.2 - p2.2).abs() + (p1.3 - p2.3).abs()
     }
 
-    pub fn solve(input: &str) -> usize {
-        let points = parse(input);
+    pub fn solve(points: Parsed) -> usize {
         let mut graph = HashMap::new();
         for (i, p1) in points.iter().enumerate() {
             for (j, p2) in points.iter().enumerate().skip(i + 1) {
@@ -55,8 +56,11 @@ pub mod part1 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "1,-1,-1,-2
+#[derive(Solution)]
+#[solution(single_part)]
+#[example(
+    part1 = 8,
+    input = "1,-1,-1,-2
 -2,-2,0,1
 0,2,1,3
 -2,3,-2,1
@@ -66,13 +70,5 @@ pub fn main(test: bool) {
 -2,2,3,-1
 1,2,2,0
 -1,-2,0,-2"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2018/day_25_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day25;
@@ -1,26 +1,27 @@
 //! https://adventofcode.com/2019/day/1
 //! https://adventofcode.com/2019/day/1/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
-fn parse(input: &str) -> Vec<usize> {
+type Parsed = Vec<usize>;
+
+fn parse(input: &str) -> Parsed {
     input.lines().map(|l| l.parse().unwrap()).collect()
 }
 
 pub mod part1 {
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let mass = parse(input);
+    pub fn solve(mass: Parsed) -> usize {
         mass.iter().map(|mass| (mass / 3) - 2).sum()
     }
 }
 
 pub mod part2 {
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let mut fuel: Vec<_> = parse(input).into_iter().map(|m| m as isize).collect();
+    pub fn solve(mass: Parsed) -> usize {
+        let mut fuel: Vec<_> = mass.into_iter().map(|m| m as isize).collect();
         let mut total = 0;
         loop {
             fuel = fuel
@@ -39,17 +40,5 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2019/day_01_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+pub struct Day01;
@@ -1,17 +1,18 @@
 //! https://adventofcode.com/2019/day/2
 //! https://adventofcode.com/2019/day/2/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
-fn parse(input: &str) -> Vec<usize> {
+type Parsed = Vec<usize>;
+
+fn parse(input: &str) -> Parsed {
     input.split(',').map(|n| n.parse().unwrap()).collect()
 }
 
 pub mod part1 {
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let mut program = parse(input);
+    pub fn solve(mut program: Parsed) -> usize {
         program[1] = 12;
         program[2] = 2;
         let mut pc = 0;
@@ -37,10 +38,9 @@ pub mod part1 {
 pub mod part2 {
     use itertools::Itertools;
 
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let program = parse(input);
+    pub fn solve(program: Parsed) -> usize {
         for (noun, verb) in (0..=99).cartesian_product(0..=99) {
             let mut program = program.clone();
             program[1] = noun;
@@ -71,17 +71,6 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "1,9,10,3,2,3,11,0,99,30,40,50".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2019/day_02_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[example(input = "1,9,10,3,2,3,11,0,99,30,40,50")]
+pub struct Day02;
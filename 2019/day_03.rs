@@ -1,11 +1,13 @@
 //! https://adventofcode.com/2019/day/3
 //! https://adventofcode.com/2019/day/3/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
 type Wire = Vec<(isize, isize)>;
 
-fn parse(input: &str) -> (Wire, Wire) {
+type Parsed = (Wire, Wire);
+
+fn parse(input: &str) -> Parsed {
     let mut wires = input.lines().map(|line| {
         line.split(',').map(|d| {
             let direction = d.chars().next().unwrap();
@@ -28,10 +30,9 @@ fn parse(input: &str) -> (Wire, Wire) {
 pub mod part1 {
     use std::collections::HashSet;
 
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let (first, second) = parse(input);
+    pub fn solve((first, second): Parsed) -> usize {
         let mut first_points = HashSet::with_capacity(first.len());
         let (mut x, mut y) = (0, 0);
         for (dx, dy) in first {
@@ -77,10 +78,9 @@ pub mod part1 {
 pub mod part2 {
     use std::collections::HashMap;
 
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let (first, second) = parse(input);
+    pub fn solve((first, second): Parsed) -> usize {
         let mut first_points = HashMap::with_capacity(first.len());
         let (mut x, mut y) = (0, 0);
         let mut steps = 0;
@@ -127,19 +127,11 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "R75,D30,R83,U83,L12,D49,R71,U7,L72
+#[derive(Solution)]
+#[example(
+    part1 = 159,
+    part2 = 610,
+    input = "R75,D30,R83,U83,L12,D49,R71,U7,L72
 U62,R66,U55,R34,D71,R55,D58,R83"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2019/day_03_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day03;
@@ -1,15 +1,17 @@
 //! https://adventofcode.com/2019/day/4
 //! https://adventofcode.com/2019/day/4/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
-fn parse(input: &str) -> (usize, usize) {
+type Parsed = (usize, usize);
+
+fn parse(input: &str) -> Parsed {
     let mut parts = input.split('-').map(|part| part.parse().unwrap());
     (parts.next().unwrap(), parts.next().unwrap())
 }
 
 pub mod part1 {
-    use super::parse;
+    use super::Parsed;
 
     fn is_valid(password: usize) -> bool {
         let p: Vec<_> = password.to_string().chars().collect();
@@ -25,8 +27,7 @@ pub mod part1 {
         pair
     }
 
-    pub fn solve(input: &str) -> usize {
-        let (left, right) = parse(input);
+    pub fn solve((left, right): Parsed) -> usize {
         let mut valid = 0;
         for p in left..=right {
             if is_valid(p) {
@@ -38,7 +39,7 @@ pub mod part1 {
 }
 
 pub mod part2 {
-    use super::parse;
+    use super::Parsed;
 
     fn is_valid(password: usize) -> bool {
         let p: Vec<_> = password.to_string().chars().collect();
@@ -59,8 +60,7 @@ pub mod part2 {
         pair
     }
 
-    pub fn solve(input: &str) -> usize {
-        let (left, right) = parse(input);
+    pub fn solve((left, right): Parsed) -> usize {
         let mut valid = 0;
         for p in left..=right {
             if is_valid(p) {
@@ -71,17 +71,5 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2019/day_04_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+pub struct Day04;
@@ -1,29 +1,29 @@
 //! https://adventofcode.com/2019/day/6
 //! https://adventofcode.com/2019/day/6/input
 
-use std::{
-    collections::{hash_map::Entry, HashMap, HashSet, VecDeque},
-    fs::read_to_string,
-    time::Instant,
-};
+use std::collections::{hash_map::Entry, HashMap, HashSet, VecDeque};
 
-type Orbits<'a> = HashMap<&'a str, Vec<&'a str>>;
+use utils::Solution;
 
-fn parse(input: &str) -> Orbits {
+type Orbits = HashMap<String, Vec<String>>;
+
+type Parsed = Orbits;
+
+fn parse(input: &str) -> Parsed {
     let mut direct = Orbits::new();
     for (origin, target) in input.lines().map(|line| {
         let mut parts = line.split(')');
         (parts.next().unwrap(), parts.next().unwrap())
     }) {
-        match direct.entry(origin) {
+        match direct.entry(origin.to_owned()) {
             Entry::Occupied(mut entry) => {
-                entry.get_mut().push(target);
+                entry.get_mut().push(target.to_owned());
             }
             Entry::Vacant(entry) => {
-                entry.insert(vec![target]);
+                entry.insert(vec![target.to_owned()]);
             }
         }
-        if let Entry::Vacant(entry) = direct.entry(target) {
+        if let Entry::Vacant(entry) = direct.entry(target.to_owned()) {
             entry.insert(vec![]);
         }
     }
@@ -42,7 +42,7 @@ fn bfs(orbits: Orbits, from: &str, to: &str) -> usize {
         total_distance += distance;
         let new_distance = distance + 1;
         for new_object in orbits.get(object).unwrap() {
-            if !visited.contains(new_object) {
+            if !visited.contains(new_object.as_str()) {
                 queue.push_back((new_distance, new_object));
             }
         }
@@ -51,36 +51,36 @@ fn bfs(orbits: Orbits, from: &str, to: &str) -> usize {
 }
 
 pub mod part1 {
-    use super::{bfs, parse};
+    use super::{bfs, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let orbits = parse(input);
+    pub fn solve(orbits: Parsed) -> usize {
         bfs(orbits, "COM", "")
     }
 }
 
 pub mod part2 {
-    use super::{bfs, parse, Orbits};
+    use super::{bfs, Orbits, Parsed};
 
     fn reverse(orbits: Orbits) -> Orbits {
         let mut reversed = orbits.clone();
         for (src, dests) in orbits {
             for dest in dests {
-                reversed.get_mut(dest).unwrap().push(src);
+                reversed.get_mut(&dest).unwrap().push(src.clone());
             }
         }
         reversed
     }
 
-    pub fn solve(input: &str) -> usize {
-        let mut orbits = parse(input);
+    pub fn solve(mut orbits: Parsed) -> usize {
         orbits = reverse(orbits);
         bfs(orbits, "YOU", "SAN") - 2
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "COM)B
+#[derive(Solution)]
+#[example(
+    part2 = 4,
+    input = "COM)B
 B)C
 C)D
 D)E
@@ -93,16 +93,5 @@ J)K
 K)L
 K)YOU
 I)SAN"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2019/day_06_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day06;
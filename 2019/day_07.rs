@@ -1,13 +1,18 @@
 //! https://adventofcode.com/2019/day/7
 //! https://adventofcode.com/2019/day/7/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
+
+use crate::int_code::{parse, IntCode};
+
+type Parsed = IntCode;
 
 pub mod part1 {
     use itertools::Itertools;
     use rayon::prelude::*;
 
-    use crate::int_code::{parse, IntCode};
+    use super::Parsed;
+    use crate::int_code::IntCode;
 
     fn run_with_phases(vm: &IntCode, phases: Vec<i64>) -> i64 {
         let mut output = 0;
@@ -21,8 +26,7 @@ pub mod part1 {
         output
     }
 
-    pub fn solve(input: &str) -> i64 {
-        let vm = parse(input);
+    pub fn solve(vm: Parsed) -> i64 {
         (0..=4)
             .permutations(5)
             .collect::<Vec<_>>()
@@ -39,7 +43,8 @@ pub mod part2 {
     use itertools::Itertools;
     use rayon::prelude::*;
 
-    use crate::int_code::{parse, IntCode, Interrupt};
+    use super::Parsed;
+    use crate::int_code::{IntCode, Interrupt};
 
     fn run_with_phases(vm: &IntCode, phases: Vec<i64>) -> i64 {
         let mut vms: Vec<_> = vec![vm.clone(); phases.len()];
@@ -70,8 +75,7 @@ pub mod part2 {
         vms[vms.len() - 1].last_output().unwrap()
     }
 
-    pub fn solve(input: &str) -> i64 {
-        let vm = parse(input);
+    pub fn solve(vm: Parsed) -> i64 {
         (5..=9)
             .permutations(5)
             .collect::<Vec<_>>()
@@ -82,19 +86,9 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input =
-        "3,52,1001,52,-5,52,3,53,1,52,56,54,1007,54,5,55,1005,55,26,1001,54,-5,54,1105,1,12,1,53,54,53,1008,54,0,55,1001,55,1,55,2,53,55,53,4,53,1001,56,-1,56,1005,56,6,99,0,0,0,0,10"
-            .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2019/day_07_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[example(
+    part2 = 18216,
+    input = "3,52,1001,52,-5,52,3,53,1,52,56,54,1007,54,5,55,1005,55,26,1001,54,-5,54,1105,1,12,1,53,54,53,1008,54,0,55,1001,55,1,55,2,53,55,53,4,53,1001,56,-1,56,1005,56,6,99,0,0,0,0,10"
+)]
+pub struct Day07;
@@ -1,9 +1,11 @@
 //! https://adventofcode.com/2019/day/8
 //! https://adventofcode.com/2019/day/8/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
-fn parse(input: &str) -> Vec<usize> {
+type Parsed = Vec<usize>;
+
+fn parse(input: &str) -> Parsed {
     input
         .chars()
         .map(|char| char.to_digit(10).unwrap() as usize)
@@ -33,10 +35,9 @@ fn make_layers(pixels: Vec<usize>, w: usize, h: usize) -> (Vec<Vec<Vec<usize>>>,
 }
 
 pub mod part1 {
-    use super::{make_layers, parse};
+    use super::{make_layers, Parsed};
 
-    pub fn solve(input: &str, w: usize, h: usize) -> usize {
-        let pixels = parse(input);
+    pub fn solve(pixels: Parsed, w: usize, h: usize) -> usize {
         let (layers, layer) = make_layers(pixels, w, h);
         layers[layer]
             .iter()
@@ -54,10 +55,9 @@ pub mod part1 {
 pub mod part2 {
     use itertools::Itertools;
 
-    use super::{make_layers, parse};
+    use super::{make_layers, Parsed};
 
-    pub fn solve(input: &str, w: usize, h: usize) -> String {
-        let pixels = parse(input);
+    pub fn solve(pixels: Parsed, w: usize, h: usize) -> String {
         let (layers, _) = make_layers(pixels, w, h);
         let mut image = vec![vec![0; w]; h];
         for (i, row) in image.iter_mut().enumerate() {
@@ -83,17 +83,16 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "0222112222120000".to_owned();
-    let (puzzle_input, (w, h)) = if test {
-        (test_input, (2, 2))
-    } else {
-        (read_to_string("../inputs/2019/day_08_input.txt").unwrap(), (25, 6))
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input, w, h));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input, w, h));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[solution(
+    params = (25, 6),
+    parse = |input, _| parse(input),
+    part1 = |pixels, (w, h)| part1::solve(pixels, w, h),
+    part2 = |pixels, (w, h)| part2::solve(pixels, w, h)
+)]
+#[example(
+    params = (2, 2),
+    part1 = 4,
+    input = "0222112222120000"
+)]
+pub struct Day08;
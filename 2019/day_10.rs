@@ -1,11 +1,15 @@
 //! https://adventofcode.com/2019/day/10
 //! https://adventofcode.com/2019/day/10/input
 
-use std::{collections::HashSet, fs::read_to_string, time::Instant};
+use std::collections::HashSet;
+
+use utils::{math::gcd, Solution};
 
 type Coord = (isize, isize);
 
-fn parse(input: &str) -> (HashSet<Coord>, isize, isize) {
+type Parsed = (HashSet<Coord>, isize, isize);
+
+fn parse(input: &str) -> Parsed {
     (
         input
             .lines()
@@ -22,20 +26,12 @@ fn parse(input: &str) -> (HashSet<Coord>, isize, isize) {
     )
 }
 
-fn gcd(a: isize, b: isize) -> isize {
-    if a == 0 {
-        b
-    } else {
-        gcd(b % a, a)
-    }
-}
-
 fn reduce(di: isize, dj: isize) -> (isize, isize) {
     match (di, dj) {
         (0, _) => (0, 1),
         (_, 0) => (1, 0),
         (di, dj) => {
-            let gcd = gcd(di, dj);
+            let gcd = gcd(dj, di);
             (di / gcd, dj / gcd)
         }
     }
@@ -73,19 +69,17 @@ fn find_best(asteroids: &HashSet<Coord>, h: isize, w: isize) -> (Coord, usize) {
 }
 
 pub mod part1 {
-    use super::{find_best, parse};
+    use super::{find_best, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let (asteroids, h, w) = parse(input);
+    pub fn solve((asteroids, h, w): Parsed) -> usize {
         find_best(&asteroids, h, w).1
     }
 }
 
 pub mod part2 {
-    use super::{find_best, parse};
+    use super::{find_best, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let (mut asteroids, h, w) = parse(input);
+    pub fn solve((mut asteroids, h, w): Parsed) -> usize {
         let (bi, bj) = find_best(&asteroids, h, w).0;
         asteroids.remove(&(bi, bj));
         let mut asteroids: Vec<_> = asteroids
@@ -126,8 +120,11 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = ".#..##.###...#######
+#[derive(Solution)]
+#[example(
+    part1 = 210,
+    part2 = 802,
+    input = ".#..##.###...#######
 ##.############..##.
 .#.######.########.#
 .###.#######.####.#.
@@ -147,16 +144,5 @@ pub fn main(test: bool) {
 .#.#.###########.###
 #.#.#.#####.####.###
 ###.##.####.##.#..##"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2019/day_10_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day10;
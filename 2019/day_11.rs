@@ -1,12 +1,15 @@
 //! https://adventofcode.com/2019/day/11
 //! https://adventofcode.com/2019/day/11/input
 
-use std::{collections::HashSet, fs::read_to_string, time::Instant};
+use std::collections::HashSet;
 
-use crate::int_code::{parse, Interrupt};
+use utils::Solution;
 
-fn paint(input: &str, painted: &mut HashSet<(i32, i32)>, white: &mut HashSet<(i32, i32)>) {
-    let mut vm = parse(input);
+use crate::int_code::{parse, IntCode, Interrupt};
+
+type Parsed = IntCode;
+
+fn paint(mut vm: Parsed, painted: &mut HashSet<(i32, i32)>, white: &mut HashSet<(i32, i32)>) {
     let (mut i, mut j, mut di, mut dj) = (0, 0, -1, 0);
     let mut first_output = true;
     loop {
@@ -40,12 +43,12 @@ fn paint(input: &str, painted: &mut HashSet<(i32, i32)>, white: &mut HashSet<(i3
 pub mod part1 {
     use std::collections::HashSet;
 
-    use super::paint;
+    use super::{paint, Parsed};
 
-    pub fn solve(input: &str) -> usize {
+    pub fn solve(vm: Parsed) -> usize {
         let mut painted = HashSet::new();
         let mut white = HashSet::new();
-        paint(input, &mut painted, &mut white);
+        paint(vm, &mut painted, &mut white);
         painted.len()
     }
 }
@@ -55,12 +58,12 @@ pub mod part2 {
 
     use itertools::Itertools;
 
-    use super::paint;
+    use super::{paint, Parsed};
 
-    pub fn solve(input: &str) -> String {
+    pub fn solve(vm: Parsed) -> String {
         let mut painted = HashSet::new();
         let mut white = HashSet::from([(0, 0)]);
-        paint(input, &mut painted, &mut white);
+        paint(vm, &mut painted, &mut white);
         let min_row = white.iter().map(|&(i, _)| i).min().unwrap();
         let min_col = white.iter().map(|&(_, j)| j).min().unwrap();
         let max_row = white.iter().map(|&(i, _)| i).max().unwrap();
@@ -78,17 +81,5 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2019/day_11_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+pub struct Day11;
@@ -1,12 +1,11 @@
 //! https://adventofcode.com/2019/day/12
 //! https://adventofcode.com/2019/day/12/input
 
-use std::{fs::read_to_string, time::Instant};
-
 use regex::Regex;
+use utils::Solution;
 
 #[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq)]
-struct Moon {
+pub struct Moon {
     x: isize,
     y: isize,
     z: isize,
@@ -28,7 +27,9 @@ impl From<&str> for Moon {
     }
 }
 
-fn parse(input: &str) -> [Moon; 4] {
+type Parsed = [Moon; 4];
+
+fn parse(input: &str) -> Parsed {
     let mut result = [Moon::default(); 4];
     for (i, line) in input.lines().enumerate() {
         result[i] = line.into();
@@ -58,10 +59,9 @@ fn step(moons: &mut [Moon; 4]) {
 }
 
 pub mod part1 {
-    use super::{parse, step};
+    use super::{step, Parsed};
 
-    pub fn solve(input: &str, steps: usize) -> usize {
-        let mut moons = parse(input);
+    pub fn solve(mut moons: Parsed, steps: usize) -> usize {
         for _ in 0..steps {
             step(&mut moons);
         }
@@ -76,22 +76,11 @@ pub mod part1 {
 }
 
 pub mod part2 {
-    use super::{parse, step};
+    use utils::math::lcm;
 
-    fn gcd(a: usize, b: usize) -> usize {
-        if a == 0 {
-            b
-        } else {
-            gcd(b % a, a)
-        }
-    }
+    use super::{step, Parsed};
 
-    fn lcm(a: usize, b: usize) -> usize {
-        a / gcd(a, b) * b
-    }
-
-    pub fn solve(input: &str) -> usize {
-        let mut moons = parse(input);
+    pub fn solve(mut moons: Parsed) -> usize {
         let initial_x: Vec<_> = moons.iter().map(|moon| (moon.x, moon.dx)).collect();
         let initial_y: Vec<_> = moons.iter().map(|moon| (moon.y, moon.dy)).collect();
         let initial_z: Vec<_> = moons.iter().map(|moon| (moon.z, moon.dz)).collect();
@@ -118,21 +107,19 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "<x=-8, y=-10, z=0>
+#[derive(Solution)]
+#[solution(
+    params = 1000,
+    parse = |input, _| parse(input),
+    part2 = |moons, _| part2::solve(moons)
+)]
+#[example(
+    params = 100,
+    part1 = 1940,
+    part2 = 4686774924,
+    input = "<x=-8, y=-10, z=0>
 <x=5, y=5, z=10>
 <x=2, y=-7, z=3>
 <x=9, y=-8, z=-3>"
-        .to_owned();
-    let (puzzle_input, steps) = if test {
-        (test_input, 100)
-    } else {
-        (read_to_string("../inputs/2019/day_12_input.txt").unwrap(), 1000)
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input, steps));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day12;
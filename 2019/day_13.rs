@@ -1,13 +1,16 @@
 //! https://adventofcode.com/2019/day/13
 //! https://adventofcode.com/2019/day/13/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
+
+use crate::int_code::{parse, IntCode};
+
+type Parsed = IntCode;
 
 pub mod part1 {
-    use crate::int_code::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let mut vm = parse(input);
+    pub fn solve(mut vm: Parsed) -> usize {
         vm.run_until_complete();
         vm.get_output()
             .iter()
@@ -18,10 +21,11 @@ pub mod part1 {
 }
 
 pub mod part2 {
-    use crate::int_code::{parse, Interrupt};
+    use super::Parsed;
+    use crate::int_code::Interrupt;
 
-    pub fn solve(input: &str) -> i64 {
-        let mut vm = parse(&"2".chars().chain(input.chars().skip(1)).collect::<String>());
+    pub fn solve(mut vm: Parsed) -> i64 {
+        vm.set(0, 2);
         let (mut ball, mut pad): (i64, i64) = (0, 0);
         let mut outputs = [0; 3];
         let mut output = 0;
@@ -52,17 +56,5 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2019/day_13_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+pub struct Day13;
@@ -1,16 +1,16 @@
 //! https://adventofcode.com/2019/day/14
 //! https://adventofcode.com/2019/day/14/input
 
-use std::{
-    collections::{BinaryHeap, HashMap, HashSet},
-    fs::read_to_string,
-    time::Instant,
-};
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use utils::Solution;
 
 type Input = (usize, String);
 type Reactions = HashMap<String, (usize, Vec<Input>)>;
 
-fn parse(input: &str) -> Reactions {
+type Parsed = Reactions;
+
+fn parse(input: &str) -> Parsed {
     input
         .lines()
         .map(|line| {
@@ -99,20 +99,18 @@ fn find_ore(
 }
 
 pub mod part1 {
-    use super::{find_ore, parse, topological_order};
+    use super::{find_ore, topological_order, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let reactions = parse(input);
+    pub fn solve(reactions: Parsed) -> usize {
         let order = topological_order(&reactions);
         find_ore(&reactions, &order, &"FUEL".into(), 1)
     }
 }
 
 pub mod part2 {
-    use super::{find_ore, parse, topological_order};
+    use super::{find_ore, topological_order, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let reactions = parse(input);
+    pub fn solve(reactions: Parsed) -> usize {
         let order = topological_order(&reactions);
         let fuel = &String::from("FUEL");
         let ore_required = find_ore(&reactions, &order, fuel, 1);
@@ -137,23 +135,14 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "10 ORE => 10 A
+#[derive(Solution)]
+#[example(
+    part1 = 31,
+    input = "10 ORE => 10 A
 1 ORE => 1 B
 7 A, 1 B => 1 C
 7 A, 1 C => 1 D
 7 A, 1 D => 1 E
 7 A, 1 E => 1 FUEL"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2019/day_14_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day14;
@@ -1,13 +1,13 @@
 //! https://adventofcode.com/2019/day/15
 //! https://adventofcode.com/2019/day/15/input
 
-use std::{
-    collections::{hash_map::Entry, HashMap},
-    fs::read_to_string,
-    time::Instant,
-};
+use std::collections::{hash_map::Entry, HashMap};
 
-use crate::int_code::{IntCode, Interrupt};
+use utils::Solution;
+
+use crate::int_code::{parse, IntCode, Interrupt};
+
+type Parsed = IntCode;
 
 type Coord = (isize, isize);
 
@@ -69,13 +69,9 @@ fn build_maze(mut vm: IntCode) -> (HashMap<Coord, Status>, Coord) {
 pub mod part1 {
     use std::collections::{HashSet, VecDeque};
 
-    use crate::{
-        day_15::{build_maze, Status, NEIGHBORS},
-        int_code::parse,
-    };
+    use crate::day_15::{build_maze, Parsed, Status, NEIGHBORS};
 
-    pub fn solve(input: &str) -> usize {
-        let vm = parse(input);
+    pub fn solve(vm: Parsed) -> usize {
         let (maze, _) = build_maze(vm);
         let mut queue = VecDeque::from([((0, 0), 0)]);
         let mut visited = HashSet::new();
@@ -101,13 +97,9 @@ pub mod part1 {
 pub mod part2 {
     use std::collections::{HashSet, VecDeque};
 
-    use crate::{
-        day_15::{build_maze, Status, NEIGHBORS},
-        int_code::parse,
-    };
+    use crate::day_15::{build_maze, Parsed, Status, NEIGHBORS};
 
-    pub fn solve(input: &str) -> usize {
-        let vm = parse(input);
+    pub fn solve(vm: Parsed) -> usize {
         let (maze, oxygen) = build_maze(vm);
         let mut queue = VecDeque::from([(oxygen, 0)]);
         let mut visited = HashSet::new();
@@ -131,17 +123,5 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2019/day_15_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+pub struct Day15;
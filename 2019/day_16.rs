@@ -1,9 +1,11 @@
 //! https://adventofcode.com/2019/day/16
 //! https://adventofcode.com/2019/day/16/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
-fn parse(input: &str) -> Vec<isize> {
+type Parsed = Vec<isize>;
+
+fn parse(input: &str) -> Parsed {
     input
         .chars()
         .map(|char| char.to_digit(10).unwrap() as isize)
@@ -11,7 +13,7 @@ fn parse(input: &str) -> Vec<isize> {
 }
 
 pub mod part1 {
-    use super::parse;
+    use super::Parsed;
 
     const PATTERN: [isize; 4] = [0, 1, 0, -1];
 
@@ -58,8 +60,7 @@ pub mod part1 {
         list.clone_from(support);
     }
 
-    pub fn solve(input: &str) -> isize {
-        let mut list = parse(input);
+    pub fn solve(mut list: Parsed) -> isize {
         let mut support = list.clone();
         for _ in 0..100 {
             phase(&mut list, &mut support);
@@ -74,10 +75,9 @@ pub mod part1 {
 }
 
 pub mod part2 {
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> isize {
-        let list = parse(input);
+    pub fn solve(list: Parsed) -> isize {
         let offset = list
             .iter()
             .take(7)
@@ -111,17 +111,6 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "03036732577212944063491565474664".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2019/day_16_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[example(part2 = 84462026, input = "03036732577212944063491565474664")]
+pub struct Day16;
@@ -1,9 +1,11 @@
 //! https://adventofcode.com/2019/day/17
 //! https://adventofcode.com/2019/day/17/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
-use crate::int_code::IntCode;
+use crate::int_code::{parse, IntCode};
+
+type Parsed = IntCode;
 
 type Coord = (usize, usize);
 type Direction = (isize, isize);
@@ -38,13 +40,9 @@ fn make_image(mut vm: IntCode) -> (Vec<Vec<char>>, Coord, Direction) {
 }
 
 pub mod part1 {
-    use crate::{
-        day_17::{make_image, NEIGHBOURS},
-        int_code::parse,
-    };
+    use super::{make_image, Parsed, NEIGHBOURS};
 
-    pub fn solve(input: &str) -> usize {
-        let vm = parse(input);
+    pub fn solve(vm: Parsed) -> usize {
         let (image, _, _) = make_image(vm);
         let mut result = 0;
         for (i, row) in image.iter().enumerate().skip(1).take(image.len() - 2) {
@@ -68,10 +66,11 @@ pub mod part1 {
 }
 
 pub mod part2 {
-    use crate::int_code::parse_with_input;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let inputs: Vec<_> = [
+    pub fn solve(mut robot: Parsed) -> usize {
+        robot.set(0, 2);
+        [
             "A,A,B,C,C,A,C,B,C,B\n",
             "L,4,L,4,L,6,R,10,L,6\n",
             "L,12,L,6,R,10,L,6\n",
@@ -80,11 +79,7 @@ pub mod part2 {
         ]
         .into_iter()
         .flat_map(|line| line.chars().map(|char| char as i64))
-        .collect();
-        let mut robot = parse_with_input(
-            &"2".chars().chain(input.chars().skip(1)).collect::<String>(),
-            inputs.into(),
-        );
+        .for_each(|input| robot.push_input(input));
         robot.run_until_complete();
         robot.last_output().unwrap() as usize
     }
@@ -93,13 +88,9 @@ pub mod part2 {
     mod by_hand {
         use itertools::Itertools;
 
-        use crate::{
-            day_17::{make_image, Coord, Direction},
-            int_code::parse,
-        };
+        use crate::day_17::{make_image, Coord, Direction, Parsed};
 
-        pub(crate) fn solve(input: &str) {
-            let vm = parse(input);
+        pub(crate) fn solve(vm: Parsed) {
             let (image, (mut i, mut j), (mut di, mut dj)) = make_image(vm);
             let mut moves = vec![];
             let mut steps = 0;
@@ -189,17 +180,5 @@ pub mod part2 {
 ..................................#####......
 */
 
-pub fn main(test: bool) {
-    let test_input = "".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2019/day_17_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+pub struct Day17;
@@ -4,14 +4,14 @@
 use std::{
     cmp::Ordering,
     collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
-    fs::read_to_string,
-    time::Instant,
 };
 
+use utils::Solution;
+
 const NEIGHBOURS: [(isize, isize); 4] = [(0, -1), (0, 1), (-1, 0), (1, 0)];
 
 #[derive(Copy, Clone, Debug)]
-enum State {
+pub enum State {
     Wall,
     Space,
     Key(usize),
@@ -20,7 +20,9 @@ enum State {
 
 type Coord = (usize, usize);
 
-fn parse(input: &str) -> (Vec<Vec<State>>, Coord, u32) {
+type Parsed = (Vec<Vec<State>>, Coord, u32);
+
+fn parse(input: &str) -> Parsed {
     let mut entrance = (0, 0);
     let mut maze =
         vec![vec![State::Wall; input.lines().next().unwrap().len()]; input.lines().count()];
@@ -194,20 +196,18 @@ fn solve_with_robots(
 }
 
 pub mod part1 {
-    use super::{build_graph, parse, solve_with_robots};
+    use super::{build_graph, solve_with_robots, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let (maze, entrance, total_keys) = parse(input);
+    pub fn solve((maze, entrance, total_keys): Parsed) -> usize {
         let graph = build_graph(&maze, vec![entrance], total_keys);
         solve_with_robots(total_keys, graph, vec![total_keys])
     }
 }
 
 pub mod part2 {
-    use super::{build_graph, parse, solve_with_robots, State};
+    use super::{build_graph, solve_with_robots, Parsed, State};
 
-    pub fn solve(input: &str) -> usize {
-        let (mut maze, (ei, ej), total_keys) = parse(input);
+    pub fn solve((mut maze, (ei, ej), total_keys): Parsed) -> usize {
         for (i, j) in [
             (ei, ej),
             (ei, ej + 1),
@@ -235,24 +235,15 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "###############
+#[derive(Solution)]
+#[example(
+    part2 = 24,
+    input = "###############
 #d.ABC.#.....a#
 ######...######
 ######.@.######
 ######...######
 #b.....#.....c#
 ###############"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2019/day_18_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day18;
@@ -1,16 +1,19 @@
 //! https://adventofcode.com/2019/day/19
 //! https://adventofcode.com/2019/day/19/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
+
+use crate::int_code::{parse, IntCode};
+
+type Parsed = IntCode;
 
 pub mod part1 {
     use itertools::Itertools;
     use rayon::prelude::*;
 
-    use crate::int_code::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let vm = parse(input);
+    pub fn solve(vm: Parsed) -> usize {
         (0..50)
             .cartesian_product(0..50)
             .collect::<Vec<_>>()
@@ -30,7 +33,8 @@ pub mod part2 {
     use itertools::Itertools;
     use rayon::prelude::*;
 
-    use crate::int_code::{parse, IntCode};
+    use super::Parsed;
+    use crate::int_code::IntCode;
 
     type Coord = (i64, i64);
 
@@ -76,8 +80,7 @@ pub mod part2 {
         unreachable!()
     }
 
-    pub fn solve(input: &str) -> i64 {
-        let vm = parse(input);
+    pub fn solve(vm: Parsed) -> i64 {
         let (mut left, mut right) = find_first(vm.clone());
         for _ in 0..99 {
             left = next_left(vm.clone(), left)
@@ -120,17 +123,5 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2019/day_19_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+pub struct Day19;
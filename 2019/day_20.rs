@@ -4,16 +4,18 @@
 use std::{
     cmp::Reverse,
     collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
-    fs::read_to_string,
-    time::Instant,
 };
 
+use utils::Solution;
+
 type Coord = (usize, usize);
 
 const NEIGHBOURS: [(isize, isize); 4] = [(0, 1), (0, -1), (1, 0), (-1, 0)];
 
 #[allow(clippy::type_complexity)]
-fn parse(input: &str) -> (Vec<Vec<bool>>, HashMap<Coord, (Coord, isize)>, Coord, Coord) {
+type Parsed = (Vec<Vec<bool>>, HashMap<Coord, (Coord, isize)>, Coord, Coord);
+
+fn parse(input: &str) -> Parsed {
     let matrix: Vec<Vec<_>> = input.lines().map(|line| line.chars().collect()).collect();
     let mut open =
         vec![vec![false; matrix.iter().map(|line| line.len()).max().unwrap()]; matrix.len()];
@@ -105,10 +107,9 @@ fn parse(input: &str) -> (Vec<Vec<bool>>, HashMap<Coord, (Coord, isize)>, Coord,
 pub mod part1 {
     use std::collections::VecDeque;
 
-    use super::{parse, NEIGHBOURS};
+    use super::{Parsed, NEIGHBOURS};
 
-    pub fn solve(input: &str) -> usize {
-        let (open, portals, start, end) = parse(input);
+    pub fn solve((open, portals, start, end): Parsed) -> usize {
         let mut visited = vec![vec![false; open[0].len()]; open.len()];
         let mut queue = VecDeque::from([(start, 0)]);
         while let Some((coord @ (i, j), distance)) = queue.pop_front() {
@@ -138,10 +139,9 @@ pub mod part1 {
 pub mod part2 {
     use std::collections::{HashMap, VecDeque};
 
-    use super::{parse, NEIGHBOURS};
+    use super::{Parsed, NEIGHBOURS};
 
-    pub fn solve(input: &str) -> usize {
-        let (open, portals, start, end) = parse(input);
+    pub fn solve((open, portals, start, end): Parsed) -> usize {
         let mut visited = vec![vec![HashMap::new(); open[0].len()]; open.len()];
         let mut queue = VecDeque::from([(start, 0, 0)]);
         while let Some((coord @ (i, j), distance, level)) = queue.pop_front() {
@@ -171,8 +171,10 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "             Z L X W       C
+#[derive(Solution)]
+#[example(
+    part2 = 396,
+    input = "             Z L X W       C
              Z P Q B       K
   ###########.#.#.#.#######.###############
   #...#.......#.#.......#.#.......#.#.#...#
@@ -209,16 +211,5 @@ RE....#.#                           #......RF
   #############.#.#.###.###################
                A O F   N
                A A D   M                     "
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2019/day_20_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day20;
@@ -1,12 +1,14 @@
 //! https://adventofcode.com/2019/day/21
 //! https://adventofcode.com/2019/day/21/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
-use crate::int_code::parse_with_input;
+use crate::int_code::{parse, IntCode};
 
-fn solve_with_program(input: &str, program: &str) -> i64 {
-    let inputs: Vec<_> = program
+type Parsed = IntCode;
+
+fn solve_with_program(mut spring_droid: Parsed, program: &str) -> i64 {
+    program
         .lines()
         .into_iter()
         .flat_map(|line| {
@@ -14,29 +16,28 @@ fn solve_with_program(input: &str, program: &str) -> i64 {
             line.push('\n');
             line.chars().map(|char| char as i64).collect::<Vec<_>>()
         })
-        .collect();
-    let mut spring_droid = parse_with_input(input, inputs.into());
+        .for_each(|input| spring_droid.push_input(input));
     spring_droid.run_until_complete();
     spring_droid.last_output().unwrap()
 }
 
 pub mod part1 {
-    use super::solve_with_program;
+    use super::{solve_with_program, Parsed};
 
-    pub fn solve(input: &str) -> i64 {
+    pub fn solve(spring_droid: Parsed) -> i64 {
         let program = r"OR C T
 AND A T
 NOT T J
 AND D J
 WALK";
-        solve_with_program(input, program)
+        solve_with_program(spring_droid, program)
     }
 }
 
 pub mod part2 {
-    use super::solve_with_program;
+    use super::{solve_with_program, Parsed};
 
-    pub fn solve(input: &str) -> i64 {
+    pub fn solve(spring_droid: Parsed) -> i64 {
         let program = r"NOT B J
 NOT C T
 OR T J
@@ -45,21 +46,9 @@ AND H J
 NOT A T
 OR T J
 RUN";
-        solve_with_program(input, program)
+        solve_with_program(spring_droid, program)
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2019/day_21_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+pub struct Day21;
@@ -1,10 +1,10 @@
 //! https://adventofcode.com/2019/day/22
 //! https://adventofcode.com/2019/day/22/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
 #[derive(Copy, Clone, Debug)]
-enum Technique {
+pub enum Technique {
     Dins,
     Cut(isize),
     Dwi(usize),
@@ -22,17 +22,18 @@ impl From<&str> for Technique {
     }
 }
 
-fn parse(input: &str) -> Vec<Technique> {
+type Parsed = Vec<Technique>;
+
+fn parse(input: &str) -> Parsed {
     input.lines().map(Technique::from).collect()
 }
 
 pub mod part1 {
     use std::collections::VecDeque;
 
-    use super::{parse, Technique};
+    use super::{Parsed, Technique};
 
-    pub fn solve(input: &str, cards: usize, card: usize) -> usize {
-        let techniques = parse(input);
+    pub fn solve(techniques: Parsed, cards: usize, card: usize) -> usize {
         let mut deck: VecDeque<_> = (0..cards).collect();
         let mut support = vec![0; deck.len()];
         for technique in techniques {
@@ -67,7 +68,7 @@ pub mod part1 {
 }
 
 pub mod part2 {
-    use super::{parse, Technique};
+    use super::{Parsed, Technique};
 
     fn modular_pow(mut base: i128, mut exp: i128, modulus: i128) -> i128 {
         if modulus == 1 {
@@ -116,16 +117,24 @@ pub mod part2 {
         }
     }
 
-    pub fn solve(input: &str, cards: i128, card: i128, deck_size: i128) -> i128 {
-        let techniques = parse(input);
+    pub fn solve(techniques: Parsed, cards: i128, card: i128, deck_size: i128) -> i128 {
         let (a, b) = to_linear_polynomial(cards, &techniques);
         let (a, b) = polypow(a, b, deck_size, cards);
         (card * a + b) % cards
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "deal into new stack
+#[derive(Solution)]
+#[solution(
+    params = (10007, 2019),
+    parse = |input, _| parse(input),
+    part1 = |techniques, (cards, card)| part1::solve(techniques, cards, card),
+    part2 = |techniques, _| part2::solve(techniques, 119315717514047, 2020, 101741582076661)
+)]
+#[example(
+    params = (10, 3),
+    part1 = 8,
+    input = "deal into new stack
 cut -2
 deal with increment 7
 cut 8
@@ -135,23 +144,5 @@ cut 3
 deal with increment 9
 deal with increment 3
 cut -1"
-        .to_owned();
-    let (puzzle_input, cards, card) = if test {
-        (test_input, 10, 3)
-    } else {
-        (
-            read_to_string("../inputs/2019/day_22_input.txt").unwrap(),
-            10007,
-            2019,
-        )
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input, cards, card));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!(
-        "{}",
-        part2::solve(&puzzle_input, 119315717514047, 2020, 101741582076661)
-    );
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day22;
@@ -1,15 +1,20 @@
 //! https://adventofcode.com/2019/day/23
 //! https://adventofcode.com/2019/day/23/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
+
+use crate::int_code::{parse, IntCode};
+
+type Parsed = IntCode;
 
 pub mod part1 {
     use std::collections::VecDeque;
 
-    use crate::int_code::{parse, Interrupt};
+    use super::Parsed;
+    use crate::int_code::Interrupt;
 
-    pub fn solve(input: &str) -> i64 {
-        let mut computers = vec![parse(input); 50];
+    pub fn solve(computer: Parsed) -> i64 {
+        let mut computers = vec![computer; 50];
         let mut input_queues: Vec<_> = (0..50).map(|address| VecDeque::from([address])).collect();
         loop {
             for (i, computer) in computers.iter_mut().enumerate() {
@@ -39,10 +44,11 @@ pub mod part1 {
 pub mod part2 {
     use std::collections::VecDeque;
 
-    use crate::int_code::{parse, Interrupt};
+    use super::Parsed;
+    use crate::int_code::Interrupt;
 
-    pub fn solve(input: &str) -> i64 {
-        let mut computers = vec![parse(input); 50];
+    pub fn solve(computer: Parsed) -> i64 {
+        let mut computers = vec![computer; 50];
         let mut input_queues: Vec<_> = (0..50).map(|address| VecDeque::from([address])).collect();
         let mut nat = (i64::MIN, i64::MIN);
         let mut last_y_sent = 0;
@@ -88,17 +94,5 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2019/day_23_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+pub struct Day23;
@@ -1,17 +1,19 @@
 //! https://adventofcode.com/2019/day/24
 //! https://adventofcode.com/2019/day/24/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
 const NEIGHBORS: [(isize, isize); 4] = [(0, 1), (0, -1), (1, 0), (-1, 0)];
 
 #[derive(Copy, Clone, Debug)]
-enum State {
+pub enum State {
     Bug,
     Empty,
 }
 
-fn parse(input: &str) -> [[State; 5]; 5] {
+type Parsed = [[State; 5]; 5];
+
+fn parse(input: &str) -> Parsed {
     let mut grid = [[State::Empty; 5]; 5];
     for (y, line) in input.lines().enumerate() {
         for (x, c) in line.chars().enumerate() {
@@ -28,7 +30,7 @@ fn parse(input: &str) -> [[State; 5]; 5] {
 pub mod part1 {
     use std::{collections::HashSet, mem::swap};
 
-    use super::{parse, State, NEIGHBORS};
+    use super::{Parsed, State, NEIGHBORS};
 
     fn biodiversity_rating(eris: &[[State; 5]; 5]) -> usize {
         let mut po2 = 1;
@@ -84,8 +86,7 @@ pub mod part1 {
         swap(eris, support);
     }
 
-    pub fn solve(input: &str) -> usize {
-        let mut eris = parse(input);
+    pub fn solve(mut eris: Parsed) -> usize {
         let mut seen = HashSet::new();
         let mut support = [[State::Empty; 5]; 5];
         loop {
@@ -102,7 +103,7 @@ pub mod part1 {
 pub mod part2 {
     use std::{collections::HashSet, mem::swap};
 
-    use super::{parse, State, NEIGHBORS};
+    use super::{Parsed, State, NEIGHBORS};
 
     type Coord = (isize, usize, usize);
 
@@ -236,8 +237,7 @@ pub mod part2 {
         swap(bugs, &mut supports);
     }
 
-    pub fn solve(input: &str) -> usize {
-        let eris = parse(input);
+    pub fn solve(eris: Parsed) -> usize {
         let mut bugs = HashSet::new();
         for (i, row) in eris.iter().enumerate() {
             for (j, state) in row.iter().enumerate() {
@@ -253,22 +253,13 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "....#
+#[derive(Solution)]
+#[example(
+    part1 = 2129920,
+    input = "....#
 #..#.
 #..##
 ..#..
 #...."
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2019/day_24_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day24;
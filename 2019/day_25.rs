@@ -1,16 +1,19 @@
 //! https://adventofcode.com/2019/day/25
 //! https://adventofcode.com/2019/day/25/input
 
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
+
+use crate::int_code::{parse, IntCode};
+
+type Parsed = IntCode;
 
 pub mod part1 {
     use itertools::Itertools;
     use regex::Regex;
 
-    use crate::int_code::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let mut vm = parse(input);
+    pub fn solve(mut vm: Parsed) -> usize {
         let take_items = r"west
 take hypercube
 west
@@ -106,14 +109,6 @@ drop hypercube
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2019/day_25_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[solution(single_part)]
+pub struct Day25;
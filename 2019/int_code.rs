@@ -53,7 +53,7 @@ pub(crate) enum Interrupt {
 }
 
 #[derive(Clone, Debug)]
-pub(crate) struct IntCode {
+pub struct IntCode {
     instructions: HashMap<i64, i64>,
     pc: i64,
     input_queue: VecDeque<i64>,
@@ -76,16 +76,14 @@ impl IntCode {
         }
     }
 
-    pub(crate) fn with_input(instructions: Vec<i64>, input: VecDeque<i64>) -> Self {
-        let mut result = Self::new(instructions);
-        result.input_queue = input;
-        result
-    }
-
     pub(crate) fn last_output(&self) -> Option<i64> {
         self.output.back().cloned()
     }
 
+    pub(crate) fn set(&mut self, address: i64, value: i64) {
+        self.instructions.insert(address, value);
+    }
+
     pub(crate) fn push_input(&mut self, value: i64) {
         self.input_queue.push_back(value);
     }
@@ -206,7 +204,10 @@ impl IntCode {
                     let value = self.get_param(value);
                     self.relative_base += value;
                 }
-                Instruction::Halt => return Interrupt::Halt,
+                Instruction::Halt => {
+                    self.pc -= consumed;
+                    return Interrupt::Halt;
+                }
             }
         }
         Interrupt::Halt
@@ -254,12 +255,5 @@ impl Instruction {
 }
 
 pub(crate) fn parse(input: &str) -> IntCode {
-    parse_with_input(input, VecDeque::new())
-}
-
-pub(crate) fn parse_with_input(input: &str, input_queue: VecDeque<i64>) -> IntCode {
-    IntCode::with_input(
-        input.split(',').map(|n| n.parse().unwrap()).collect(),
-        input_queue,
-    )
+    IntCode::new(input.split(',').map(|n| n.parse().unwrap()).collect())
 }
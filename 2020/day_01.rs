@@ -1,10 +1,17 @@
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
+
+type Parsed = Vec<usize>;
+
+fn parse(input: &str) -> Parsed {
+    input.lines().map(|l| l.parse().unwrap()).collect()
+}
 
 pub mod part1 {
     use std::cmp::Ordering;
 
-    pub fn solve(input: &str) -> usize {
-        let mut entries: Vec<usize> = input.lines().map(|l| l.parse().unwrap()).collect();
+    use super::Parsed;
+
+    pub fn solve(mut entries: Parsed) -> usize {
         entries.sort();
         let mut left = 0;
         let mut right = entries.len() - 1;
@@ -23,8 +30,9 @@ pub mod part1 {
 pub mod part2 {
     use std::cmp::Ordering;
 
-    pub fn solve(input: &str) -> usize {
-        let mut entries: Vec<usize> = input.lines().map(|l| l.parse().unwrap()).collect();
+    use super::Parsed;
+
+    pub fn solve(mut entries: Parsed) -> usize {
         entries.sort();
         for (i, entry) in entries.iter().enumerate() {
             let mut left = i + 1;
@@ -42,23 +50,15 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "1721
+#[derive(Solution)]
+#[example(
+    part1 = 514579,
+    part2 = 241861950,
+    input = "1721
 979
 366
 299
 675
 1456"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2020/day_01_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day01;
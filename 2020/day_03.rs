@@ -1,8 +1,15 @@
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
+
+type Parsed = Vec<Vec<char>>;
+
+fn parse(input: &str) -> Parsed {
+    input.lines().map(|l| l.chars().collect()).collect()
+}
 
 pub mod part1 {
-    pub fn solve(input: &str) -> usize {
-        let map: Vec<Vec<_>> = input.lines().map(|l| l.chars().collect()).collect();
+    use super::Parsed;
+
+    pub fn solve(map: Parsed) -> usize {
         let mut trees = 0;
         let mut coord = (0, 0);
         while coord.0 < map.len() {
@@ -17,8 +24,9 @@ pub mod part1 {
 }
 
 pub mod part2 {
-    pub fn solve(input: &str) -> usize {
-        let map: Vec<Vec<_>> = input.lines().map(|l| l.chars().collect()).collect();
+    use super::Parsed;
+
+    pub fn solve(map: Parsed) -> usize {
         let mut result = 1;
         for (r, c) in &[(1, 1), (1, 3), (1, 5), (1, 7), (2, 1)] {
             let mut trees = 0;
@@ -36,8 +44,11 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "..##.......
+#[derive(Solution)]
+#[example(
+    part1 = 7,
+    part2 = 336,
+    input = "..##.......
 #...#...#..
 .#....#..#.
 ..#.#...#.#
@@ -48,17 +59,5 @@ pub fn main(test: bool) {
 #.##...#...
 #...##....#
 .#..#...#.#"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2020/day_03_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
-
+)]
+pub struct Day03;
@@ -1,11 +1,18 @@
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
+
+type Parsed = Vec<Vec<String>>;
+
+fn parse(input: &str) -> Parsed {
+    input
+        .split("\n\n")
+        .map(|l| l.split_whitespace().map(str::to_owned).collect())
+        .collect()
+}
 
 pub mod part1 {
-    pub fn solve(input: &str) -> usize {
-        let passports: Vec<Vec<_>> = input
-            .split("\r\n\r\n")
-            .map(|l| l.split(|c| c == ' ' || c == '\n').collect())
-            .collect();
+    use super::Parsed;
+
+    pub fn solve(passports: Parsed) -> usize {
         passports
             .iter()
             .filter(|p| {
@@ -19,7 +26,9 @@ pub mod part1 {
 pub mod part2 {
     use regex::Regex;
 
-    fn validate(passport: &Vec<&str>) -> bool {
+    use super::Parsed;
+
+    fn validate(passport: &Vec<String>) -> bool {
         let pattern = Regex::new(r"^(byr:(19[2-9]\d|200[0-2])|iyr:(201\d|2020)|eyr:(202\d|2030)|hgt:((1[5-8]\d|19[0-3])cm|(59|6\d|7[0-6])in)|hcl:#[a-f0-9]{6}|ecl:(amb|blu|brn|gry|grn|hzl|oth)|pid:\d{9})$").unwrap();
         for field in passport {
             if !field.starts_with("cid:") && !pattern.is_match(field) {
@@ -82,10 +91,9 @@ pub mod part2 {
         true
     }
 
-    pub fn solve(input: &str) -> usize {
-        input
-            .split("\r\n\r\n")
-            .map(|l| l.split_whitespace().collect::<Vec<_>>())
+    pub fn solve(passports: Parsed) -> usize {
+        passports
+            .into_iter()
             .filter(|p| {
                 p.len() == 8
                     || (p.iter().filter(|f| f.starts_with("cid:")).count() == 0 && p.len() == 7)
@@ -95,20 +103,7 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "yecl:gry pid:998952368 eyr:2026 hcl:#fffffd
-byr:1940 iyr:2014 cid:147 hgt:174cm"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2020/day_04_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
-
+#[derive(Solution)]
+#[example(input = "yecl:gry pid:998952368 eyr:2026 hcl:#fffffd
+byr:1940 iyr:2014 cid:147 hgt:174cm")]
+pub struct Day04;
@@ -1,6 +1,12 @@
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
-fn find_seat_id(seat: &&str) -> usize {
+type Parsed = Vec<String>;
+
+fn parse(input: &str) -> Parsed {
+    input.lines().map(str::to_owned).collect()
+}
+
+fn find_seat_id(seat: &str) -> usize {
     let (mut front, mut back) = (0, 127);
     let (mut left, mut right) = (0, 7);
     for c in seat.chars() {
@@ -18,11 +24,14 @@ fn find_seat_id(seat: &&str) -> usize {
 pub mod part1 {
     use rayon::prelude::*;
 
-    use super::find_seat_id;
+    use super::{find_seat_id, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let seats: Vec<_> = input.lines().collect();
-        seats.par_iter().map(find_seat_id).max().unwrap()
+    pub fn solve(seats: Parsed) -> usize {
+        seats
+            .par_iter()
+            .map(|seat| find_seat_id(seat))
+            .max()
+            .unwrap()
     }
 }
 
@@ -31,15 +40,10 @@ pub mod part2 {
 
     use rayon::prelude::*;
 
-    use super::find_seat_id;
+    use super::{find_seat_id, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let mut seats: Vec<_> = input
-            .lines()
-            .collect::<Vec<_>>()
-            .par_iter()
-            .map(find_seat_id)
-            .collect();
+    pub fn solve(seats: Parsed) -> usize {
+        let mut seats: Vec<_> = seats.par_iter().map(|seat| find_seat_id(seat)).collect();
         seats.sort_unstable();
         let (min, _max) = (seats[0], seats[seats.len() - 1]);
         let (mut left, mut right) = (0, seats.len() - 1);
@@ -61,17 +65,6 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "BBFFBBFRLL".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2020/day_05_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[example(part1 = 820, input = "BBFFBBFRLL")]
+pub struct Day05;
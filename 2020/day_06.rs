@@ -1,31 +1,35 @@
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
+
+type Parsed = Vec<Vec<Vec<char>>>;
+
+fn parse(input: &str) -> Parsed {
+    input
+        .split(&"\n".repeat(2))
+        .map(|g| g.lines().map(|l| l.chars().collect()).collect())
+        .collect()
+}
 
 pub mod part1 {
     use std::collections::HashSet;
 
-    use crate::LINE_ENDING;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        input
-            .split(&LINE_ENDING.repeat(2))
-            .map(|g| {
-                g.lines()
-                    .flat_map(|l| l.chars())
-                    .collect::<HashSet<_>>()
-                    .len()
-            })
+    pub fn solve(groups: Parsed) -> usize {
+        groups
+            .into_iter()
+            .map(|g| g.into_iter().flatten().collect::<HashSet<_>>().len())
             .sum()
     }
 }
 
 pub mod part2 {
-    use crate::LINE_ENDING;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        input
-            .split(&LINE_ENDING.repeat(2))
+    pub fn solve(groups: Parsed) -> usize {
+        groups
+            .into_iter()
             .map(|g| {
-                let mut people = g.lines().map(|l| l.chars().collect::<Vec<_>>());
+                let mut people = g.into_iter();
                 let mut first = people.next().unwrap();
                 people.for_each(|p| first.retain(|e| p.contains(e)));
                 first.len()
@@ -34,8 +38,11 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "abc
+#[derive(Solution)]
+#[example(
+    part1 = 11,
+    part2 = 6,
+    input = "abc
 
 a
 b
@@ -50,16 +57,5 @@ a
 a
 
 b"
-    .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2020/day_06_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day06;
@@ -1,36 +1,54 @@
-use std::{fs::read_to_string, time::Instant};
+use std::collections::HashMap;
+
+use regex::Regex;
+use utils::Solution;
+
+type Parsed = HashMap<String, Vec<(usize, String)>>;
+
+fn parse(input: &str) -> Parsed {
+    let line_pattern = Regex::new(r"(\w+ \w+) bags contain (.+)\.").unwrap();
+    let content_pattern = Regex::new(r"(\d+) (\w+ \w+) bags?").unwrap();
+    input
+        .lines()
+        .map(|l| {
+            let captures = line_pattern.captures(l).unwrap();
+            let container = captures.get(1).unwrap().as_str().to_owned();
+            let contents = captures
+                .get(2)
+                .unwrap()
+                .as_str()
+                .split(", ")
+                .filter_map(|c| {
+                    let content_info = content_pattern.captures(c);
+                    content_info.map(|content| {
+                        (
+                            content.get(1).unwrap().as_str().parse().unwrap(),
+                            content.get(2).unwrap().as_str().to_owned(),
+                        )
+                    })
+                })
+                .collect();
+            (container, contents)
+        })
+        .collect()
+}
 
 pub mod part1 {
     use std::collections::{HashMap, HashSet};
 
-    use regex::Regex;
+    use super::Parsed;
 
-    fn parse(input: &str) -> HashMap<&str, HashSet<&str>> {
+    pub fn solve(bags: Parsed) -> usize {
         let mut map = HashMap::new();
-        let pattern = Regex::new(r"\w{3,} \w+ bag").unwrap();
-        input
-            .lines()
-            .map(|l| {
-                let mut matches = pattern.find_iter(l).map(|m| m.as_str());
-                let first = matches.next().unwrap();
-                let others: Vec<_> = matches.collect();
-                (first, others)
-            })
-            .for_each(|(first, others)| {
-                others.iter().for_each(|&o| {
-                    if !map.contains_key(o) {
-                        map.insert(o, HashSet::new());
-                    }
-                    map.get_mut(o).unwrap().insert(first);
-                })
-            });
-        map
-    }
-
-    pub fn solve(input: &str) -> usize {
-        let map = parse(input);
+        for (container, contents) in &bags {
+            for (_, content) in contents {
+                map.entry(content.as_str())
+                    .or_insert_with(HashSet::new)
+                    .insert(container.as_str());
+            }
+        }
         let mut can_be_reached = HashSet::new();
-        can_be_reached.insert("shiny gold bag");
+        can_be_reached.insert("shiny gold");
         let mut previous_len = 0;
         while can_be_reached.len() != previous_len {
             previous_len = can_be_reached.len();
@@ -45,40 +63,9 @@ pub mod part1 {
 }
 
 pub mod part2 {
-    use std::collections::{HashMap, HashSet};
-
-    use regex::Regex;
-
-    fn parse(input: &str) -> HashMap<&str, HashSet<(usize, &str)>> {
-        let line_pattern = Regex::new(r"(\w+ \w+) bags contain (.+)\.").unwrap();
-        let content_pattern = Regex::new(r"(\d+) (\w+ \w+) bags?").unwrap();
-        let mut map = HashMap::new();
-        input.lines().for_each(|l| {
-            let captures = line_pattern.captures(l).unwrap();
-            let container = captures.get(1).unwrap().as_str();
-            map.insert(
-                container,
-                captures
-                    .get(2)
-                    .unwrap()
-                    .as_str()
-                    .split(", ")
-                    .filter_map(|c| {
-                        let content_info = content_pattern.captures(c);
-                        content_info.map(|content| {
-                            (
-                                content.get(1).unwrap().as_str().parse().unwrap(),
-                                content.get(2).unwrap().as_str(),
-                            )
-                        })
-                    })
-                    .collect(),
-            );
-        });
-        map
-    }
+    use super::Parsed;
 
-    fn explore(map: &HashMap<&str, HashSet<(usize, &str)>>, bag: &str) -> usize {
+    fn explore(map: &Parsed, bag: &str) -> usize {
         let mut bags = 1;
         for (quantity, content) in map.get(bag).unwrap() {
             bags += quantity * explore(map, content);
@@ -86,30 +73,20 @@ pub mod part2 {
         bags
     }
 
-    pub fn solve(input: &str) -> usize {
-        let bags = parse(input);
+    pub fn solve(bags: Parsed) -> usize {
         explore(&bags, "shiny gold") - 1
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "shiny gold bags contain 2 dark red bags.
+#[derive(Solution)]
+#[example(
+    part2 = 126,
+    input = "shiny gold bags contain 2 dark red bags.
 dark red bags contain 2 dark orange bags.
 dark orange bags contain 2 dark yellow bags.
 dark yellow bags contain 2 dark green bags.
 dark green bags contain 2 dark blue bags.
 dark blue bags contain 2 dark violet bags.
 dark violet bags contain no other bags."
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2020/day_07_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day07;
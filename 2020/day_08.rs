@@ -1,34 +1,24 @@
-use std::{fs::read_to_string, time::Instant};
+use utils::{errors::ParseError, parsing::try_parse_lines, FromStr, Solution};
 
 type Argument = isize;
 
-enum Operation {
+#[derive(Copy, Clone, FromStr)]
+pub enum Operation {
+    #[keyword("acc")]
     Acc,
+    #[keyword("jmp")]
     Jmp,
+    #[keyword("nop")]
     Nop,
 }
 
-struct Instruction(Operation, Argument);
+#[derive(Copy, Clone, FromStr)]
+pub struct Instruction(Operation, Argument);
 
-impl From<&str> for Instruction {
-    fn from(string: &str) -> Self {
-        let mut parts = string.split(' ');
-        let op = parts.next().unwrap();
-        let arg = parts.next().unwrap().parse().unwrap();
-        Instruction(
-            match op.chars().next().unwrap() {
-                'a' => Operation::Acc,
-                'j' => Operation::Jmp,
-                'n' => Operation::Nop,
-                _ => panic!("Invalid instruction: {}", op),
-            },
-            arg,
-        )
-    }
-}
+type Parsed = Vec<Instruction>;
 
-fn parse(input: &str) -> Vec<Instruction> {
-    input.lines().map(Instruction::from).collect()
+fn parse(input: &str) -> Result<Parsed, ParseError> {
+    try_parse_lines(input)
 }
 
 fn run(instructions: &Vec<Instruction>) -> (bool, isize) {
@@ -50,19 +40,17 @@ fn run(instructions: &Vec<Instruction>) -> (bool, isize) {
 }
 
 pub mod part1 {
-    use super::{parse, run};
+    use super::{run, Parsed};
 
-    pub fn solve(input: &str) -> isize {
-        let instructions = parse(input);
+    pub fn solve(instructions: Parsed) -> isize {
         run(&instructions).1
     }
 }
 
 pub mod part2 {
-    use super::{parse, run, Operation};
+    use super::{run, Operation, Parsed};
 
-    pub fn solve(input: &str) -> isize {
-        let mut instructions = parse(input);
+    pub fn solve(mut instructions: Parsed) -> isize {
         for i in 0..instructions.len() {
             let before = match &instructions[i].0 {
                 Operation::Jmp => {
@@ -85,8 +73,11 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "nop +0
+#[derive(Solution)]
+#[example(
+    part1 = 5,
+    part2 = 8,
+    input = "nop +0
 acc +1
 jmp +4
 acc +3
@@ -95,16 +86,5 @@ acc -99
 acc +1
 jmp -4
 acc +6"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2020/day_08_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day08;
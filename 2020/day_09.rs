@@ -1,6 +1,10 @@
-use std::{cmp::Ordering, fs::read_to_string, time::Instant};
+use std::cmp::Ordering;
 
-fn parse(input: &str) -> Vec<usize> {
+use utils::Solution;
+
+type Parsed = Vec<usize>;
+
+fn parse(input: &str) -> Parsed {
     input.lines().map(|n| n.parse().unwrap()).collect()
 }
 
@@ -39,10 +43,9 @@ fn has_pair(previous: &[usize], number: usize, size: usize) -> bool {
 }
 
 pub mod part1 {
-    use super::{find_invalid, parse};
+    use super::{find_invalid, Parsed};
 
-    pub fn solve(input: &str, size: usize) -> usize {
-        let numbers = parse(input);
+    pub fn solve(numbers: Parsed, size: usize) -> usize {
         find_invalid(&numbers, size)
     }
 }
@@ -50,10 +53,9 @@ pub mod part1 {
 pub mod part2 {
     use std::cmp::Ordering;
 
-    use super::{find_invalid, parse};
+    use super::{find_invalid, Parsed};
 
-    pub fn solve(input: &str, size: usize) -> usize {
-        let numbers = parse(input);
+    pub fn solve(numbers: Parsed, size: usize) -> usize {
         let invalid = find_invalid(&numbers, size);
         let (mut left, mut right) = (0, 1);
         let mut tot = numbers[left] + numbers[right];
@@ -75,8 +77,13 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "35
+#[derive(Solution)]
+#[solution(params = 25, parse = |input, _| parse(input))]
+#[example(
+    params = 5,
+    part1 = 127,
+    part2 = 62,
+    input = "35
 20
 15
 25
@@ -96,19 +103,5 @@ pub fn main(test: bool) {
 277
 309
 576"
-    .to_owned();
-    let (puzzle_input, size) = if test {
-        (test_input, 5)
-    } else {
-        (
-            read_to_string("../inputs/2020/day_09_input.txt").unwrap(),
-            25,
-        )
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input, size));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input, size));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day09;
@@ -1,6 +1,8 @@
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
-fn parse(input: &str) -> Vec<usize> {
+type Parsed = Vec<usize>;
+
+fn parse(input: &str) -> Parsed {
     let mut jolts: Vec<_> = input.lines().map(|n| n.parse().unwrap()).collect();
     jolts.push(0);
     jolts.sort();
@@ -9,10 +11,9 @@ fn parse(input: &str) -> Vec<usize> {
 }
 
 pub mod part1 {
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let jolts = parse(input);
+    pub fn solve(jolts: Parsed) -> usize {
         let result = jolts
             .iter()
             .fold((0, (0, 0)), |(prev, (ones, threes)), &e| {
@@ -31,10 +32,9 @@ pub mod part1 {
 }
 
 pub mod part2 {
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let jolts = parse(input);
+    pub fn solve(jolts: Parsed) -> usize {
         let mut dynamic = vec![0; jolts.len()];
         dynamic[0] = 1;
         for i in 1..jolts.len() {
@@ -51,8 +51,11 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "28
+#[derive(Solution)]
+#[example(
+    part1 = 220,
+    part2 = 19208,
+    input = "28
 33
 18
 42
@@ -83,16 +86,5 @@ pub fn main(test: bool) {
 34
 10
 3"
-    .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2020/day_10_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day10;
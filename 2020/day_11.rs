@@ -1,42 +1,17 @@
-use std::{
-    fmt::{Display, Formatter},
-    time::Instant,
-};
-use std::fs::read_to_string;
+use utils::{errors::ParseError, from_char, parsing::try_parse_matrix, Solution};
 
+#[from_char]
 #[derive(Clone, Debug, Copy)]
-enum Seat {
-    Floor,
-    Empty,
-    Occupied,
+pub enum Seat {
+    Floor = '.',
+    Empty = 'L',
+    Occupied = '#',
 }
 
-impl From<char> for Seat {
-    fn from(c: char) -> Self {
-        match c {
-            'L' => Seat::Empty,
-            '#' => Seat::Occupied,
-            '.' => Seat::Floor,
-            _ => panic!("Invalid seat: {}", c),
-        }
-    }
-}
+type Parsed = Vec<Vec<Seat>>;
 
-impl Display for Seat {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Seat::Floor => write!(f, "."),
-            Seat::Empty => write!(f, "L"),
-            Seat::Occupied => write!(f, "#"),
-        }
-    }
-}
-
-fn parse(input: &str) -> Vec<Vec<Seat>> {
-    input
-        .lines()
-        .map(|l| l.chars().map(Seat::from).collect())
-        .collect()
+fn parse(input: &str) -> Result<Parsed, ParseError> {
+    try_parse_matrix(input)
 }
 
 fn count(seats: Vec<Vec<Seat>>) -> usize {
@@ -47,7 +22,7 @@ fn count(seats: Vec<Vec<Seat>>) -> usize {
 }
 
 pub mod part1 {
-    use super::{count, parse, Seat};
+    use super::{count, Parsed, Seat};
 
     fn count_occupied_neighbors(seats: &[Vec<Seat>], i: usize, j: usize) -> usize {
         let mut count = 0;
@@ -81,8 +56,7 @@ pub mod part1 {
         }
         (changed, support_vec)
     }
-    pub fn solve(input: &str) -> usize {
-        let mut seats = parse(input);
+    pub fn solve(mut seats: Parsed) -> usize {
         let mut changed = true;
         while changed {
             let moved = step(seats);
@@ -94,21 +68,14 @@ pub mod part1 {
 }
 
 pub mod part2 {
-    use super::{count, parse, Seat};
+    use utils::coords::NEAR;
+
+    use super::{count, Parsed, Seat};
 
     fn count_occupied_neighbors(seats: &[Vec<Seat>], i: usize, j: usize) -> usize {
         let (i, j) = (i as isize, j as isize);
         let mut count = 0;
-        for direction in [
-            (-1, 0),
-            (-1, 1),
-            (0, 1),
-            (1, 1),
-            (1, 0),
-            (1, -1),
-            (0, -1),
-            (-1, -1),
-        ] {
+        for direction in NEAR {
             let (mut i, mut j) = (i + direction.0, j + direction.1);
             while i >= 0
                 && j >= 0
@@ -152,8 +119,7 @@ pub mod part2 {
         (changed, support_vec)
     }
 
-    pub fn solve(input: &str) -> usize {
-        let mut seats = parse(input);
+    pub fn solve(mut seats: Parsed) -> usize {
         let mut changed = true;
         while changed {
             let moved = step(seats);
@@ -164,8 +130,11 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "L.LL.LL.LL
+#[derive(Solution)]
+#[example(
+    part1 = 37,
+    part2 = 26,
+    input = "L.LL.LL.LL
 LLLLLLL.LL
 L.L.L..L..
 LLLL.LL.LL
@@ -175,16 +144,5 @@ L.LLLLL.LL
 LLLLLLLLLL
 L.LLLLLL.L
 L.LLLLL.LL"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2020/day_11_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day11;
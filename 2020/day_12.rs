@@ -1,7 +1,7 @@
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
 #[derive(Debug, Copy, Clone)]
-enum Instruction {
+pub enum Instruction {
     Move(isize, isize, isize),
     Forward(isize),
     Right,
@@ -33,15 +33,16 @@ impl From<&str> for Instruction {
     }
 }
 
-fn parse(input: &str) -> Vec<Instruction> {
+type Parsed = Vec<Instruction>;
+
+fn parse(input: &str) -> Parsed {
     input.lines().map(Instruction::from).collect()
 }
 
 pub mod part1 {
-    use super::{parse, Instruction};
+    use super::{Instruction, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let instructions = parse(input);
+    pub fn solve(instructions: Parsed) -> usize {
         let mut position = (0, 0);
         let mut direction = (0, 1);
         for instr in instructions {
@@ -60,10 +61,9 @@ pub mod part1 {
 }
 
 pub mod part2 {
-    use super::{parse, Instruction};
+    use super::{Instruction, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let instructions = parse(input);
+    pub fn solve(instructions: Parsed) -> usize {
         let mut ship = (0, 0);
         let mut waypoint = (-1, 10);
         for instr in instructions {
@@ -81,22 +81,14 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "F10
+#[derive(Solution)]
+#[example(
+    part1 = 25,
+    part2 = 286,
+    input = "F10
 N3
 F7
 R90
 F11"
-    .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2020/day_12_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day12;
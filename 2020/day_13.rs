@@ -1,6 +1,8 @@
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
-fn parse(input: &str) -> (usize, Vec<Option<usize>>) {
+type Parsed = (usize, Vec<Option<usize>>);
+
+fn parse(input: &str) -> Parsed {
     let mut lines = input.lines();
     let timestamp = lines.next().unwrap().parse().unwrap();
     (
@@ -17,10 +19,9 @@ fn parse(input: &str) -> (usize, Vec<Option<usize>>) {
     )
 }
 pub mod part1 {
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let (timestamp, buses) = parse(input);
+    pub fn solve((timestamp, buses): Parsed) -> usize {
         let (wait, bus) = buses
             .iter()
             .flatten()
@@ -32,7 +33,7 @@ pub mod part1 {
 }
 
 pub mod part2 {
-    use super::parse;
+    use super::Parsed;
 
     fn find_earliest(
         (mut partial, mcm): (usize, usize),
@@ -45,8 +46,7 @@ pub mod part2 {
         (partial, mcm * prime)
     }
 
-    pub fn solve(input: &str) -> usize {
-        let (_, buses) = parse(input);
+    pub fn solve((_, buses): Parsed) -> usize {
         buses
             .iter()
             .enumerate()
@@ -56,19 +56,11 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "939
+#[derive(Solution)]
+#[example(
+    part1 = 295,
+    part2 = 1068781,
+    input = "939
 7,13,x,x,59,x,31,19"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2020/day_13_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day13;
@@ -1,4 +1,4 @@
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
 #[derive(Clone, Copy, Debug)]
 enum Operation {
@@ -13,7 +13,7 @@ struct Value {
 }
 
 #[derive(Clone, Copy, Debug)]
-struct Instruction {
+pub struct Instruction {
     operation: Operation,
     value: Value,
 }
@@ -68,12 +68,14 @@ impl From<&str> for Instruction {
     }
 }
 
-fn parse(input: &str) -> Vec<Instruction> {
+type Parsed = Vec<Instruction>;
+
+fn parse(input: &str) -> Parsed {
     input.lines().map(Instruction::from).collect()
 }
 
 pub mod part1 {
-    use super::{parse, Instruction, Operation, Value};
+    use super::{Instruction, Operation, Parsed, Value};
 
     fn apply_mask(mask: &[u8; 36], value: &[u8; 36]) -> u64 {
         let mut result = 0;
@@ -87,8 +89,7 @@ pub mod part1 {
         result
     }
 
-    pub fn solve(input: &str) -> u64 {
-        let instructions = parse(input);
+    pub fn solve(instructions: Parsed) -> u64 {
         let indexes = instructions.iter().filter_map(|i| match i.operation {
             Operation::Mem(index) => Some(index),
             _mask => None,
@@ -114,7 +115,7 @@ pub mod part1 {
 pub mod part2 {
     use std::collections::HashMap;
 
-    use super::{parse, Instruction, Operation, Value};
+    use super::{Instruction, Operation, Parsed, Value};
 
     fn write(memory: &mut HashMap<usize, u64>, address: usize, mask: &[u8; 36], value: u64) {
         fn recursive(
@@ -149,8 +150,7 @@ pub mod part2 {
         recursive(memory, &mut mask.clone(), address, 0, value, 0);
     }
 
-    pub fn solve(input: &str) -> u64 {
-        let instructions = parse(input);
+    pub fn solve(instructions: Parsed) -> u64 {
         let mut memory = HashMap::new();
         let mut mask = [2; 36];
         instructions.iter().for_each(|i| match i {
@@ -167,21 +167,12 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "mask = 000000000000000000000000000000X1001X
+#[derive(Solution)]
+#[example(
+    part2 = 208,
+    input = "mask = 000000000000000000000000000000X1001X
 mem[42] = 100
 mask = 00000000000000000000000000000000X0XX
 mem[26] = 1"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2020/day_14_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day14;
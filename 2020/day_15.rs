@@ -1,7 +1,10 @@
-use std::{collections::HashMap, time::Instant};
-use std::fs::read_to_string;
+use std::collections::HashMap;
 
-fn parse(input: &str) -> HashMap<usize, usize> {
+use utils::Solution;
+
+type Parsed = HashMap<usize, usize>;
+
+fn parse(input: &str) -> Parsed {
     input
         .split(',')
         .enumerate()
@@ -10,10 +13,9 @@ fn parse(input: &str) -> HashMap<usize, usize> {
 }
 
 pub mod part1 {
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let mut turns = parse(input);
+    pub fn solve(mut turns: Parsed) -> usize {
         let mut number = 0;
         let mut turn = turns.len() + 1;
         while turn < 2020 {
@@ -28,10 +30,9 @@ pub mod part1 {
 }
 
 pub mod part2 {
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let mut turns = parse(input);
+    pub fn solve(mut turns: Parsed) -> usize {
         let mut number = 0;
         let mut turn = turns.len() + 1;
         while turn < 30000000 {
@@ -45,17 +46,6 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "0,3,6".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2020/day_15_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[example(part1 = 436, input = "0,3,6")]
+pub struct Day15;
@@ -1,14 +1,12 @@
 use std::{
     hash::{Hash, Hasher},
     ops::RangeInclusive,
-    time::Instant,
 };
-use std::fs::read_to_string;
 
-use crate::LINE_ENDING;
+use utils::Solution;
 
 #[derive(Debug, Clone, Eq)]
-struct Rule {
+pub struct Rule {
     name: String,
     left: RangeInclusive<usize>,
     right: RangeInclusive<usize>,
@@ -26,7 +24,9 @@ impl Hash for Rule {
     }
 }
 
-fn parse(input: &str) -> (Vec<Rule>, Vec<usize>, Vec<Vec<usize>>) {
+type Parsed = (Vec<Rule>, Vec<usize>, Vec<Vec<usize>>);
+
+fn parse(input: &str) -> Parsed {
     fn parse_rule(rule: &str) -> Rule {
         let mut parts = rule.split(": ");
         let name = parts.next().unwrap().to_owned();
@@ -37,7 +37,7 @@ fn parse(input: &str) -> (Vec<Rule>, Vec<usize>, Vec<Vec<usize>>) {
         let right = right.next().unwrap().parse().unwrap()..=right.next().unwrap().parse().unwrap();
         Rule { name, left, right }
     }
-    let separator = LINE_ENDING.repeat(2);
+    let separator = "\n".repeat(2);
     let mut sections = input.split(&separator);
     let rules = sections.next().unwrap().lines().map(parse_rule).collect();
     let ticket: Vec<_> = sections
@@ -60,10 +60,9 @@ fn parse(input: &str) -> (Vec<Rule>, Vec<usize>, Vec<Vec<usize>>) {
 }
 
 pub mod part1 {
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let (rules, _ticket, tickets) = parse(input);
+    pub fn solve((rules, _ticket, tickets): Parsed) -> usize {
         tickets
             .iter()
             .flatten()
@@ -79,10 +78,9 @@ pub mod part1 {
 pub mod part2 {
     use std::collections::{HashMap, HashSet};
 
-    use super::parse;
+    use super::Parsed;
 
-    pub fn solve(input: &str) -> usize {
-        let (rules, ticket, mut tickets) = parse(input);
+    pub fn solve((rules, ticket, mut tickets): Parsed) -> usize {
         tickets.retain(|ticket| {
             ticket.iter().all(|&n| {
                 rules
@@ -125,8 +123,8 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "class: 0-1 or 4-19
+#[derive(Solution)]
+#[example(input = "class: 0-1 or 4-19
 row: 0-5 or 8-19
 seat: 0-13 or 16-19
 
@@ -136,18 +134,5 @@ your ticket:
 nearby tickets:
 3,9,18
 15,1,5
-5,14,9"
-        .to_owned()
-        .replace('\n', "\r\n");
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2020/day_16_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+5,14,9")]
+pub struct Day16;
@@ -1,8 +1,26 @@
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
+
+type Parsed = Vec<(isize, isize)>;
+
+fn parse(input: &str) -> Parsed {
+    input
+        .lines()
+        .enumerate()
+        .flat_map(|(x, line)| {
+            line.chars()
+                .enumerate()
+                .map(move |(y, c)| ((x as isize, y as isize), c == '#'))
+        })
+        .filter(|(_, b)| *b)
+        .map(|(a, _)| a)
+        .collect()
+}
 
 pub mod part1 {
     use std::collections::{HashMap, HashSet};
 
+    use super::Parsed;
+
     fn step(
         map: &mut HashSet<(isize, isize, isize)>,
         support: &mut HashMap<(isize, isize, isize), usize>,
@@ -31,18 +49,8 @@ pub mod part1 {
             });
     }
 
-    pub fn solve(input: &str) -> usize {
-        let mut map = input
-            .lines()
-            .enumerate()
-            .flat_map(|(x, line)| {
-                line.chars()
-                    .enumerate()
-                    .map(move |(y, c)| ((x as isize, y as isize, 0), c == '#'))
-            })
-            .filter(|(_, b)| *b)
-            .map(|(a, _)| a)
-            .collect();
+    pub fn solve(cubes: Parsed) -> usize {
+        let mut map = cubes.into_iter().map(|(x, y)| (x, y, 0)).collect();
         let mut support = HashMap::new();
         for _ in 0..6 {
             step(&mut map, &mut support);
@@ -54,6 +62,8 @@ pub mod part1 {
 pub mod part2 {
     use std::collections::{HashMap, HashSet};
 
+    use super::Parsed;
+
     fn step(
         map: &mut HashSet<(isize, isize, isize, isize)>,
         support: &mut HashMap<(isize, isize, isize, isize), usize>,
@@ -86,18 +96,8 @@ pub mod part2 {
             });
     }
 
-    pub fn solve(input: &str) -> usize {
-        let mut map = input
-            .lines()
-            .enumerate()
-            .flat_map(|(x, line)| {
-                line.chars()
-                    .enumerate()
-                    .map(move |(y, c)| ((x as isize, y as isize, 0, 0), c == '#'))
-            })
-            .filter(|(_, b)| *b)
-            .map(|(a, _)| a)
-            .collect();
+    pub fn solve(cubes: Parsed) -> usize {
+        let mut map = cubes.into_iter().map(|(x, y)| (x, y, 0, 0)).collect();
         let mut support = HashMap::new();
         for _ in 0..6 {
             step(&mut map, &mut support);
@@ -106,20 +106,12 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = ".#.
+#[derive(Solution)]
+#[example(
+    part1 = 112,
+    part2 = 848,
+    input = ".#.
 ..#
 ###"
-    .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2020/day_17_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day17;
@@ -1,5 +1,6 @@
-use std::{str::Chars, time::Instant};
-use std::fs::read_to_string;
+use std::str::Chars;
+
+use utils::Solution;
 
 #[derive(Debug, Clone, Copy)]
 enum Operation {
@@ -65,17 +66,7 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "1 + (2 * 3) + (4 * (5 + 6))".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2020/day_18_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[solution(unparsed)]
+#[example(part1 = 51, part2 = 51, input = "1 + (2 * 3) + (4 * (5 + 6))")]
+pub struct Day18;
@@ -1,10 +1,9 @@
-use std::{cmp::Ordering, time::Instant};
-use std::fs::read_to_string;
+use std::cmp::Ordering;
 
-use crate::LINE_ENDING;
+use utils::Solution;
 
 #[derive(Debug, Clone)]
-struct Rule {
+pub struct Rule {
     number: usize,
     rule: Match,
 }
@@ -36,8 +35,10 @@ enum Match {
     Or(Vec<usize>, Vec<usize>),
 }
 
-fn parse(input: &str) -> (Vec<Rule>, Vec<String>) {
-    let separator = LINE_ENDING.repeat(2);
+type Parsed = (Vec<Rule>, Vec<String>);
+
+fn parse(input: &str) -> Parsed {
+    let separator = "\n".repeat(2);
     let mut parts = input.split(&separator);
     let mut rules: Vec<_> = parts.next().unwrap().lines().map(From::from).collect();
     rules.sort();
@@ -75,7 +76,7 @@ impl From<&str> for Match {
 }
 
 pub mod part1 {
-    use super::{parse, Match, Rule};
+    use super::{Match, Parsed, Rule};
 
     fn matches(message: &str, rule: usize, rules: &Vec<Rule>) -> (bool, usize) {
         if message.is_empty() {
@@ -127,8 +128,7 @@ pub mod part1 {
         }
     }
 
-    pub fn solve(input: &str) -> usize {
-        let (rules, messages) = parse(input);
+    pub fn solve((rules, messages): Parsed) -> usize {
         messages
             .iter()
             .filter(|m| {
@@ -140,7 +140,7 @@ pub mod part1 {
 }
 
 pub mod part2 {
-    use super::{parse, Match, Rule};
+    use super::{Match, Parsed, Rule};
 
     fn matches(message: &str, rule: usize, rules: &Vec<Rule>) -> (bool, Vec<usize>) {
         if message.is_empty() {
@@ -217,8 +217,7 @@ pub mod part2 {
         }
     }
 
-    pub fn solve(input: &str) -> usize {
-        let (mut rules, messages) = parse(input);
+    pub fn solve((mut rules, messages): Parsed) -> usize {
         rules[8] = Rule {
             number: 8,
             rule: Match::Or(vec![42], vec![42, 8]),
@@ -237,8 +236,11 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "0: 8 11
+#[derive(Solution)]
+#[example(
+    part1 = 3,
+    part2 = 12,
+    input = "0: 8 11
 1: \"a\"
 2: 1 24 | 14 4
 3: 5 14 | 16 1
@@ -297,17 +299,5 @@ aaaabbaaaabbaaa
 aaaabbaabbaaaaaaabbbabbbaaabbaabaaa
 babaaabbbaaabaababbaabababaaab
 aabbbbbaabbbaaaaaabbbbbababaaaaabbaaabba"
-        .to_owned()
-        .replace('\n', "\r\n");
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2020/day_19_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day19;
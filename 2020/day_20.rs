@@ -1,6 +1,4 @@
-use std::{fs::read_to_string, time::Instant};
-
-use crate::LINE_ENDING;
+use utils::Solution;
 
 #[derive(Debug, Copy, Clone, Default)]
 enum Rotation {
@@ -71,7 +69,7 @@ const MONSTER: [(usize, usize); 15] = [
 ];
 
 #[derive(Debug, Copy, Clone, Default)]
-struct Tile {
+pub struct Tile {
     id: usize,
     lines: [u16; 10],
     edges: [u16; 8],
@@ -190,8 +188,10 @@ impl From<&str> for Tile {
     }
 }
 
-fn parse(input: &str) -> Vec<Tile> {
-    let separator = LINE_ENDING.repeat(2);
+type Parsed = Vec<Tile>;
+
+fn parse(input: &str) -> Parsed {
+    let separator = "\n".repeat(2);
     input.split(&separator).map(Tile::from).collect()
 }
 
@@ -236,10 +236,9 @@ fn solve_puzzle(tiles: &mut [Tile]) -> Vec<Vec<usize>> {
 }
 
 pub mod part1 {
-    use super::{parse, solve_puzzle};
+    use super::{solve_puzzle, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let mut tiles = parse(input);
+    pub fn solve(mut tiles: Parsed) -> usize {
         let puzzle = solve_puzzle(&mut tiles);
         tiles[puzzle[0][0]].id
             * tiles[puzzle[0][puzzle[0].len() - 1]].id
@@ -249,7 +248,7 @@ pub mod part1 {
 }
 
 pub mod part2 {
-    use super::{parse, solve_puzzle, Tile, Transformation, MONSTER, TRANSFORMATIONS};
+    use super::{solve_puzzle, Parsed, Tile, Transformation, MONSTER, TRANSFORMATIONS};
 
     fn get_transformed_coord<T>(
         matrix: &[Vec<T>],
@@ -312,8 +311,7 @@ pub mod part2 {
         new
     }
 
-    pub fn solve(input: &str) -> usize {
-        let mut tiles = parse(input);
+    pub fn solve(mut tiles: Parsed) -> usize {
         let puzzle = solve_puzzle(&mut tiles);
         let image = create_image(&tiles, puzzle);
         let start: usize = image
@@ -337,8 +335,11 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "Tile 2311:
+#[derive(Solution)]
+#[example(
+    part1 = 20899048083289,
+    part2 = 273,
+    input = "Tile 2311:
 ..##.#..#.
 ##..#.....
 #...##..#.
@@ -445,17 +446,5 @@ Tile 3079:
 ..#.###...
 ..#.......
 ..#.###..."
-        .to_owned()
-        .replace('\n', "\r\n");
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2020/day_20_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day20;
@@ -1,35 +1,40 @@
-use std::{
-    collections::{HashMap, HashSet},
-    fs::read_to_string,
-    time::Instant,
-};
+use std::collections::{HashMap, HashSet};
 
-fn parse(input: &str) -> Vec<(HashSet<&str>, Vec<&str>)> {
+use utils::Solution;
+
+type Parsed = Vec<(HashSet<String>, Vec<String>)>;
+
+fn parse(input: &str) -> Parsed {
     input
         .lines()
         .map(|line| {
             let mut parts = line.split(" (contains ");
-            let ingredients = parts.next().unwrap().split(' ').collect();
+            let ingredients = parts
+                .next()
+                .unwrap()
+                .split(' ')
+                .map(str::to_owned)
+                .collect();
             let allergens = parts
                 .next()
                 .unwrap()
                 .trim_end_matches(')')
                 .split(", ")
+                .map(str::to_owned)
                 .collect();
             (ingredients, allergens)
         })
         .collect()
 }
 
-fn get_possibilities<'a>(
-    recipes: &Vec<(HashSet<&'a str>, Vec<&'a str>)>,
-) -> HashMap<&'a str, &'a str> {
+fn get_possibilities(recipes: &Parsed) -> HashMap<&str, &str> {
     let mut possibilities: HashMap<_, HashSet<_>> = HashMap::new();
     for (ingredients, allergens) in recipes {
+        let ingredients: HashSet<_> = ingredients.iter().map(String::as_str).collect();
         for allergen in allergens {
             possibilities
-                .entry(allergen)
-                .and_modify(|set| *set = set.intersection(ingredients).copied().collect())
+                .entry(allergen.as_str())
+                .and_modify(|set| *set = set.intersection(&ingredients).copied().collect())
                 .or_insert_with(|| ingredients.clone());
         }
     }
@@ -46,14 +51,13 @@ fn get_possibilities<'a>(
     }
     possibilities
         .iter()
-        .map(|(k, v)| (**k, *v.iter().next().unwrap()))
+        .map(|(k, v)| (*k, *v.iter().next().unwrap()))
         .collect()
 }
 pub mod part1 {
-    use super::{get_possibilities, parse};
+    use super::{get_possibilities, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let recipes = parse(input);
+    pub fn solve(recipes: Parsed) -> usize {
         let possibilities = get_possibilities(&recipes);
         let fixed: Vec<_> = possibilities.values().collect();
         recipes
@@ -61,7 +65,7 @@ pub mod part1 {
             .map(|(ingredients, _)| {
                 ingredients
                     .iter()
-                    .filter(|&ingredient| !fixed.contains(&ingredient))
+                    .filter(|ingredient| !fixed.contains(&&ingredient.as_str()))
                     .count()
             })
             .sum()
@@ -69,10 +73,9 @@ pub mod part1 {
 }
 
 pub mod part2 {
-    use super::{get_possibilities, parse};
+    use super::{get_possibilities, Parsed};
 
-    pub fn solve(input: &str) -> String {
-        let recipes = parse(input);
+    pub fn solve(recipes: Parsed) -> String {
         let possibilities = get_possibilities(&recipes);
         let mut pairs: Vec<_> = possibilities.iter().collect();
         pairs.sort_by_key(|(k, _)| *k);
@@ -84,21 +87,13 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "mxmxvkd kfcds sqjhc nhms (contains dairy, fish)
+#[derive(Solution)]
+#[example(
+    part1 = 5,
+    part2 = "mxmxvkd,sqjhc,fvjkl",
+    input = "mxmxvkd kfcds sqjhc nhms (contains dairy, fish)
 trh fvjkl sbzzf mxmxvkd (contains dairy)
 sqjhc fvjkl (contains soy)
 sqjhc mxmxvkd sbzzf (contains fish)"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2020/day_21_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day21;
@@ -1,10 +1,11 @@
-use std::{collections::VecDeque, time::Instant};
-use std::fs::read_to_string;
+use std::collections::VecDeque;
 
-use crate::LINE_ENDING;
+use utils::Solution;
 
-fn parse(input: &str) -> (VecDeque<usize>, VecDeque<usize>) {
-    let separator = LINE_ENDING.repeat(2);
+type Parsed = (VecDeque<usize>, VecDeque<usize>);
+
+fn parse(input: &str) -> Parsed {
+    let separator = "\n".repeat(2);
     let mut players = input.split(&separator);
     let player1 = players
         .next()
@@ -26,7 +27,7 @@ fn parse(input: &str) -> (VecDeque<usize>, VecDeque<usize>) {
 pub mod part1 {
     use std::collections::VecDeque;
 
-    use super::parse;
+    use super::Parsed;
 
     fn turn(player1: &mut VecDeque<usize>, player2: &mut VecDeque<usize>) {
         let card1 = player1.pop_front().unwrap();
@@ -40,8 +41,7 @@ pub mod part1 {
         }
     }
 
-    pub fn solve(input: &str) -> usize {
-        let (mut player1, mut player2) = parse(input);
+    pub fn solve((mut player1, mut player2): Parsed) -> usize {
         while !player1.is_empty() && !player2.is_empty() {
             turn(&mut player1, &mut player2);
         }
@@ -58,7 +58,7 @@ pub mod part1 {
 pub mod part2 {
     use std::collections::VecDeque;
 
-    use super::parse;
+    use super::Parsed;
 
     fn game(player1: &mut VecDeque<usize>, player2: &mut VecDeque<usize>) -> usize {
         let mut previous_rounds = Vec::new();
@@ -99,8 +99,7 @@ pub mod part2 {
         }
     }
 
-    pub fn solve(input: &str) -> usize {
-        let (mut player1, mut player2) = parse(input);
+    pub fn solve((mut player1, mut player2): Parsed) -> usize {
         let winner = game(&mut player1, &mut player2);
         let winner = if winner == 1 { player1 } else { player2 };
         winner
@@ -112,8 +111,11 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "Player 1:
+#[derive(Solution)]
+#[example(
+    part1 = 306,
+    part2 = 291,
+    input = "Player 1:
 9
 2
 6
@@ -126,17 +128,5 @@ Player 2:
 4
 7
 10"
-    .to_owned()
-    .replace('\n', "\r\n");
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2020/day_22_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day22;
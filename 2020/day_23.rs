@@ -1,4 +1,4 @@
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
 #[derive(Copy, Clone)]
 struct Node {
@@ -7,7 +7,16 @@ struct Node {
     next: usize,
 }
 
-fn parse(input: impl Iterator<Item = usize>) -> Vec<Node> {
+type Parsed = Vec<usize>;
+
+fn parse(input: &str) -> Parsed {
+    input
+        .chars()
+        .map(|char| char.to_digit(10).unwrap() as usize)
+        .collect()
+}
+
+fn make_cups(input: impl Iterator<Item = usize>) -> Vec<Node> {
     let mut result: Vec<_> = input
         .map(|n| Node {
             val: n,
@@ -81,7 +90,7 @@ fn do_moves(how_many: usize, cups: &mut [Node]) {
 }
 
 pub mod part1 {
-    use super::{do_moves, parse, Node};
+    use super::{do_moves, make_cups, Node, Parsed};
 
     fn to_string(cups: &Vec<Node>) -> String {
         let mut result = String::with_capacity(cups.len() - 2);
@@ -93,26 +102,19 @@ pub mod part1 {
         result
     }
 
-    pub fn solve(input: &str) -> String {
-        let mut cups = parse(
-            "0".chars()
-                .chain(input.chars())
-                .map(|char| char.to_digit(10).unwrap() as usize),
-        );
+    pub fn solve(labels: Parsed) -> String {
+        let mut cups = make_cups([0].into_iter().chain(labels));
         do_moves(100, &mut cups);
         to_string(&cups)
     }
 }
 
 pub mod part2 {
-    use super::{do_moves, parse};
+    use super::{do_moves, make_cups, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let numbers = "0"
-            .chars()
-            .chain(input.chars())
-            .map(|char| char.to_digit(10).unwrap() as usize);
-        let mut cups = parse(
+    pub fn solve(labels: Parsed) -> usize {
+        let numbers = [0].into_iter().chain(labels);
+        let mut cups = make_cups(
             numbers
                 .clone()
                 .chain((numbers.max().unwrap() + 1)..=1000000),
@@ -124,17 +126,6 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "389125467".to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2020/day_23_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+#[derive(Solution)]
+#[example(part1 = 67384529, part2 = 149245887792, input = "389125467")]
+pub struct Day23;
@@ -1,6 +1,10 @@
-use std::{collections::HashSet, fs::read_to_string, time::Instant};
+use std::collections::HashSet;
 
-fn parse(input: &str) -> Vec<Vec<(i8, i8, i8)>> {
+use utils::Solution;
+
+type Parsed = Vec<Vec<(i8, i8, i8)>>;
+
+fn parse(input: &str) -> Parsed {
     input
         .lines()
         .map(|line| {
@@ -41,10 +45,9 @@ fn get_black_tiles(tiles: Vec<Vec<(i8, i8, i8)>>) -> HashSet<(i8, i8, i8)> {
 }
 
 pub mod part1 {
-    use super::{get_black_tiles, parse};
+    use super::{get_black_tiles, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let tiles = parse(input);
+    pub fn solve(tiles: Parsed) -> usize {
         let black = get_black_tiles(tiles);
         black.len()
     }
@@ -53,7 +56,7 @@ pub mod part1 {
 pub mod part2 {
     use std::collections::{HashMap, HashSet};
 
-    use super::{get_black_tiles, parse};
+    use super::{get_black_tiles, Parsed};
 
     fn day(black: HashSet<(i8, i8, i8)>) -> HashSet<(i8, i8, i8)> {
         let mut neighbors_count = HashMap::new();
@@ -88,8 +91,7 @@ pub mod part2 {
         );
         result
     }
-    pub fn solve(input: &str) -> usize {
-        let tiles = parse(input);
+    pub fn solve(tiles: Parsed) -> usize {
         let mut black = get_black_tiles(tiles);
         for _ in 0..100 {
             black = day(black);
@@ -98,8 +100,11 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "sesenwnenenewseeswwswswwnenewsewsw
+#[derive(Solution)]
+#[example(
+    part1 = 10,
+    part2 = 2208,
+    input = "sesenwnenenewseeswwswswwnenewsewsw
 neeenesenwnwwswnenewnwwsewnenwseswesw
 seswneswswsenwwnwse
 nwnwneseeswswnenewneswwnewseswneseene
@@ -119,16 +124,5 @@ nenewswnwewswnenesenwnesewesw
 eneswnwswnwsenenwnwnwwseeswneewsenese
 neswnwewnwnwseenwseesewsenwsweewe
 wseweeenwnesenwwwswnew"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2020/day_24_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-    let start = Instant::now();
-    println!("{}", part2::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day24;
@@ -1,9 +1,11 @@
-use std::{fs::read_to_string, time::Instant};
+use utils::Solution;
 
 const SUBJECT_NUMBER: usize = 7;
 const MAGIC_NUMBER: usize = 20201227;
 
-fn parse(input: &str) -> (usize, usize) {
+type Parsed = (usize, usize);
+
+fn parse(input: &str) -> Parsed {
     let mut lines = input.lines();
     (
         lines.next().unwrap().parse().unwrap(),
@@ -32,25 +34,19 @@ fn find_private(subject_number: usize, loop_size: usize) -> usize {
 }
 
 pub mod part1 {
-    use super::{find_loop_size, find_private, parse};
+    use super::{find_loop_size, find_private, Parsed};
 
-    pub fn solve(input: &str) -> usize {
-        let (card, door) = parse(input);
+    pub fn solve((card, door): Parsed) -> usize {
         let card_loop = find_loop_size(card);
         find_private(door, card_loop)
     }
 }
 
-pub fn main(test: bool) {
-    let test_input = "5764801
+#[derive(Solution)]
+#[solution(single_part)]
+#[example(
+    part1 = 14897079,
+    input = "5764801
 17807724"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2020/day_25_input.txt").unwrap()
-    };
-    let start = Instant::now();
-    println!("{}", part1::solve(&puzzle_input));
-    println!("Run in {:?}", start.elapsed());
-}
+)]
+pub struct Day25;
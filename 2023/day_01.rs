@@ -1,15 +1,12 @@
 //! https://adventofcode.com/2023/day/1
 //! https://adventofcode.com/2023/day/1/input
 
-use std::{
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
+use utils::Solution;
 
-type Parsed<'a> = Vec<&'a str>;
+type Parsed = Vec<String>;
 
 fn parse(input: &str) -> Parsed {
-    input.lines().collect()
+    input.lines().map(str::to_owned).collect()
 }
 
 pub mod part1 {
@@ -85,44 +82,22 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-    let test_input = "1abc2
+#[derive(Solution)]
+#[example(
+    part1 = 142,
+    input = "1abc2
 pqr3stu8vwx
 a1b2c3d4e5f
 treb7uchet"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2023/day_01_input.txt").unwrap()
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let parsed = parse(&puzzle_input);
-    let elapsed = start.elapsed();
-    if verbose {
-        println!("Parsed in {:?}", elapsed);
-        total += elapsed;
-    }
-
-    let start = Instant::now();
-    let result = part1::solve(parsed.clone());
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part2::solve(parsed);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("Second part in {:?}", elapsed);
-    total += elapsed;
-
-    if verbose {
-        println!("Total {:?}", total);
-    }
-    total
-}
+)]
+#[example(
+    part2 = 281,
+    input = "two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen"
+)]
+pub struct Day01;
@@ -1,10 +1,7 @@
 //! https://adventofcode.com/2023/day/2
 //! https://adventofcode.com/2023/day/2/input
 
-use std::{
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
+use utils::Solution;
 
 #[derive(Copy, Clone, Default, Debug)]
 pub struct Cubes {
@@ -77,45 +74,14 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-    let test_input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+#[derive(Solution)]
+#[example(
+    part1 = 8,
+    part2 = 2286,
+    input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
 Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
 Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
 Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
 Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2023/day_02_input.txt").unwrap()
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let parsed = parse(&puzzle_input);
-    let elapsed = start.elapsed();
-    if verbose {
-        println!("Parsed in {:?}", elapsed);
-        total += elapsed;
-    }
-
-    let start = Instant::now();
-    let result = part1::solve(parsed.clone());
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part2::solve(parsed);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("Second part in {:?}", elapsed);
-    total += elapsed;
-
-    if verbose {
-        println!("Total {:?}", total);
-    }
-    total
-}
+)]
+pub struct Day02;
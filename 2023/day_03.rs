@@ -1,10 +1,7 @@
 //! https://adventofcode.com/2023/day/3
 //! https://adventofcode.com/2023/day/3/input
 
-use std::{
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
+use utils::Solution;
 
 type Parsed = Vec<Vec<char>>;
 
@@ -109,8 +106,11 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-    let test_input = "467..114..
+#[derive(Solution)]
+#[example(
+    part1 = 4361,
+    part2 = 467835,
+    input = "467..114..
 ...*......
 ..35..633.
 ......#...
@@ -120,39 +120,5 @@ pub fn main(test: bool, verbose: bool) -> Duration {
 ......755.
 ...$.*....
 .664.598.."
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2023/day_03_input.txt").unwrap()
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let parsed = parse(&puzzle_input);
-    let elapsed = start.elapsed();
-    if verbose {
-        println!("Parsed in {:?}", elapsed);
-        total += elapsed;
-    }
-
-    let start = Instant::now();
-    let result = part1::solve(parsed.clone());
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part2::solve(parsed);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("Second part in {:?}", elapsed);
-    total += elapsed;
-
-    if verbose {
-        println!("Total {:?}", total);
-    }
-    total
-}
+)]
+pub struct Day03;
@@ -1,12 +1,7 @@
 //! https://adventofcode.com/2023/day/5
 //! https://adventofcode.com/2023/day/5/input
 
-use std::{
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
-
-use crate::LINE_ENDING;
+use utils::Solution;
 
 #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
 pub struct Range {
@@ -42,8 +37,8 @@ type Map = Vec<Range>;
 
 type Parsed = (Vec<usize>, Vec<Map>);
 
-fn parse(input: &str, separator: String) -> Parsed {
-    let mut parts = input.split(&separator);
+fn parse(input: &str) -> Parsed {
+    let mut parts = input.split("\n\n");
     let seeds = parts.next().unwrap()[6..]
         .split_whitespace()
         .map(|s| s.parse().unwrap())
@@ -168,8 +163,11 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-    let test_input = "seeds: 79 14 55 13
+#[derive(Solution)]
+#[example(
+    part1 = 35,
+    part2 = 46,
+    input = "seeds: 79 14 55 13
 
 seed-to-soil map:
 50 98 2
@@ -202,42 +200,5 @@ temperature-to-humidity map:
 humidity-to-location map:
 60 56 37
 56 93 4"
-        .to_owned();
-    let (puzzle_input, separator) = if test {
-        (test_input, "\n".repeat(2))
-    } else {
-        (
-            read_to_string("../inputs/2023/day_05_input.txt").unwrap(),
-            LINE_ENDING.repeat(2),
-        )
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let parsed = parse(&puzzle_input, separator);
-    let elapsed = start.elapsed();
-    if verbose {
-        println!("Parsed in {:?}", elapsed);
-        total += elapsed;
-    }
-
-    let start = Instant::now();
-    let result = part1::solve(parsed.clone());
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part2::solve(parsed);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("Second part in {:?}", elapsed);
-    total += elapsed;
-
-    if verbose {
-        println!("Total {:?}", total);
-    }
-    total
-}
+)]
+pub struct Day05;
@@ -1,10 +1,7 @@
 //! https://adventofcode.com/2023/day/6
 //! https://adventofcode.com/2023/day/6/input
 
-use std::{
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
+use utils::Solution;
 
 #[derive(Copy, Clone, Debug)]
 pub struct Race {
@@ -95,50 +92,17 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-    let test_input = "Time:      7  15   30
+#[derive(Solution)]
+#[solution(
+    parsed = (Vec<Race>, Race),
+    parse = |input| (part1::parse(input), part2::parse(input)),
+    part1 = |(races, _)| part1::solve(races),
+    part2 = |(_, race)| part2::solve(race),
+)]
+#[example(
+    part1 = 288,
+    part2 = 71503,
+    input = "Time:      7  15   30
 Distance:  9  40  200"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2023/day_06_input.txt").unwrap()
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let parsed = part1::parse(&puzzle_input);
-    let elapsed = start.elapsed();
-    if verbose {
-        println!("Parsed in {:?}", elapsed);
-        total += elapsed;
-    }
-
-    let start = Instant::now();
-    let result = part1::solve(parsed.clone());
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let parsed = part2::parse(&puzzle_input);
-    let elapsed = start.elapsed();
-    if verbose {
-        println!("Parsed in {:?}", elapsed);
-        total += elapsed;
-    }
-
-    let start = Instant::now();
-    let result = part2::solve(parsed);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("Second part in {:?}", elapsed);
-    total += elapsed;
-
-    if verbose {
-        println!("Total {:?}", total);
-    }
-    total
-}
+)]
+pub struct Day06;
@@ -1,12 +1,7 @@
 //! https://adventofcode.com/2023/day/7
 //! https://adventofcode.com/2023/day/7/input
 
-use std::{
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
-
-use utils::{parsing::parse_lines, FromStr};
+use utils::{errors::ParseError, parsing::try_parse_lines, FromStr, Solution};
 
 #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
 enum Card {
@@ -126,8 +121,8 @@ pub struct Play {
 
 type Parsed = Vec<Play>;
 
-pub fn parse(input: &str) -> Parsed {
-    parse_lines(input)
+pub fn parse(input: &str) -> Result<Parsed, ParseError> {
+    try_parse_lines(input)
 }
 
 fn solve(mut plays: Parsed) -> usize {
@@ -139,53 +134,21 @@ fn solve(mut plays: Parsed) -> usize {
         .sum()
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-    let test_input = "32T3K 765
+#[derive(Solution)]
+#[solution(
+    parsed = (Parsed, Parsed),
+    parse = |input| parse(input)
+        .and_then(|plays| parse(&input.replace('J', "?")).map(|jokers| (plays, jokers))),
+    part1 = |(plays, _)| solve(plays),
+    part2 = |(_, plays)| solve(plays),
+)]
+#[example(
+    part1 = 6440,
+    part2 = 5905,
+    input = "32T3K 765
 T55J5 684
 KK677 28
 KTJJT 220
 QQQJA 483"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2023/day_07_input.txt").unwrap()
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let parsed = parse(&puzzle_input);
-    let elapsed = start.elapsed();
-    if verbose {
-        println!("Parsed in {:?}", elapsed);
-        total += elapsed;
-    }
-
-    let start = Instant::now();
-    let result = solve(parsed.clone());
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let parsed = parse(&puzzle_input.replace('J', "?"));
-    let elapsed = start.elapsed();
-    if verbose {
-        println!("Parsed in {:?}", elapsed);
-        total += elapsed;
-    }
-
-    let start = Instant::now();
-    let result = solve(parsed);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("Second part in {:?}", elapsed);
-    total += elapsed;
-
-    if verbose {
-        println!("Total {:?}", total);
-    }
-    total
-}
+)]
+pub struct Day07;
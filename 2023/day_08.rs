@@ -1,12 +1,7 @@
 //! https://adventofcode.com/2023/day/8
 //! https://adventofcode.com/2023/day/8/input
 
-use std::{
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
-
-use utils::parsing::parse_alpha;
+use utils::{parsing::parse_alpha, Solution};
 
 type Node = [usize; 2];
 
@@ -74,8 +69,10 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-    let test_input = "RL
+#[derive(Solution)]
+#[example(
+    part1 = 2,
+    input = "RL
 
 AAA = (BBB, CCC)
 BBB = (DDD, EEE)
@@ -84,39 +81,5 @@ DDD = (DDD, DDD)
 EEE = (EEE, EEE)
 GGG = (GGG, GGG)
 ZZZ = (ZZZ, ZZZ)"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2023/day_08_input.txt").unwrap()
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let parsed = parse(&puzzle_input);
-    let elapsed = start.elapsed();
-    if verbose {
-        println!("Parsed in {:?}", elapsed);
-        total += elapsed;
-    }
-
-    let start = Instant::now();
-    let result = part1::solve(parsed.clone());
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part2::solve(parsed);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("Second part in {:?}", elapsed);
-    total += elapsed;
-
-    if verbose {
-        println!("Total {:?}", total);
-    }
-    total
-}
+)]
+pub struct Day08;
@@ -1,12 +1,7 @@
 //! https://adventofcode.com/2023/day/10
 //! https://adventofcode.com/2023/day/10/input
 
-use std::{
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
-
-use utils::from_char;
+use utils::{errors::ParseError, from_char, parsing::try_parse_matrix, Solution};
 
 #[from_char]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -134,19 +129,16 @@ type Coord = (usize, usize);
 
 type Parsed = (Pipes, Coord);
 
-fn parse(input: &str) -> Parsed {
-    let result: Vec<Vec<_>> = input
-        .lines()
-        .map(|line| line.chars().map(Pipe::from).collect())
-        .collect();
+fn parse(input: &str) -> Result<Parsed, ParseError> {
+    let result: Vec<Vec<_>> = try_parse_matrix(input)?;
     for (i, row) in result.iter().enumerate() {
         for (j, pipe) in row.iter().enumerate() {
             if let Pipe::Start = pipe {
-                return (result, (i, j));
+                return Ok((result, (i, j)));
             }
         }
     }
-    unreachable!()
+    Err(ParseError::Invalid("No `S` in the map".to_owned()))
 }
 
 pub mod part1 {
@@ -303,8 +295,10 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-    let test_input = "FF7FSF7F7F7F7F7F---7
+#[derive(Solution)]
+#[example(
+    part2 = 10,
+    input = "FF7FSF7F7F7F7F7F---7
 L|LJ||||||||||||F--J
 FL-7LJLJ||||||LJL-77
 F--JF--7||LJLJ7F7FJ-
@@ -314,39 +308,5 @@ L---JF-JLJ.||-FJLJJ7
 7-L-JL7||F7|L7F-7F7|
 L.L7LFJ|||||FJL7||LJ
 L7JLJL-JLJLJL--JLJ.L"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2023/day_10_input.txt").unwrap()
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let parsed = parse(&puzzle_input);
-    let elapsed = start.elapsed();
-    if verbose {
-        println!("Parsed in {:?}", elapsed);
-        total += elapsed;
-    }
-
-    let start = Instant::now();
-    let result = part1::solve(parsed.clone());
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part2::solve(parsed);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("Second part in {:?}", elapsed);
-    total += elapsed;
-
-    if verbose {
-        println!("Total {:?}", total);
-    }
-    total
-}
+)]
+pub struct Day10;
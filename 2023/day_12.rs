@@ -1,12 +1,14 @@
 //! https://adventofcode.com/2023/day/12
 //! https://adventofcode.com/2023/day/12/input
 
-use std::{
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
+use std::str::FromStr;
 
-use utils::from_char;
+use utils::{
+    errors::ParseError,
+    from_char,
+    parsing::{try_parse_lines, try_parse_matrix},
+    Solution,
+};
 
 #[from_char]
 #[derive(Copy, Clone, Debug)]
@@ -22,24 +24,29 @@ pub struct Group {
     sizes: Vec<u8>,
 }
 
-impl From<&str> for Group {
-    fn from(value: &str) -> Self {
-        let mut parts = value.split_whitespace();
-        let springs = parts.next().unwrap().chars().map(Spring::from).collect();
-        let sizes = parts
-            .next()
-            .unwrap()
+impl FromStr for Group {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (springs, sizes) = value
+            .split_once(' ')
+            .ok_or(ParseError::EndOfInput("sizes"))?;
+        let springs = try_parse_matrix(springs)?.concat();
+        let sizes = sizes
             .split(',')
-            .map(|n| n.parse().unwrap())
-            .collect();
-        Self { springs, sizes }
+            .map(|n| {
+                n.parse()
+                    .map_err(|error| ParseError::new("sizes", error).at(value, n))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { springs, sizes })
     }
 }
 
 type Parsed = Vec<Group>;
 
-fn parse(input: &str) -> Parsed {
-    input.lines().map(Group::from).collect()
+fn parse(input: &str) -> Result<Parsed, ParseError> {
+    try_parse_lines(input)
 }
 
 // https://www.reddit.com/r/adventofcode/comments/18ge41g/comment/kd18cl9
@@ -115,46 +122,15 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-    let test_input = "???.### 1,1,3
+#[derive(Solution)]
+#[example(
+    part1 = 21,
+    part2 = 525152,
+    input = "???.### 1,1,3
 .??..??...?##. 1,1,3
 ?#?#?#?#?#?#?#? 1,3,1,6
 ????.#...#... 4,1,1
 ????.######..#####. 1,6,5
 ?###???????? 3,2,1"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2023/day_12_input.txt").unwrap()
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let parsed = parse(&puzzle_input);
-    let elapsed = start.elapsed();
-    if verbose {
-        println!("Parsed in {:?}", elapsed);
-        total += elapsed;
-    }
-
-    let start = Instant::now();
-    let result = part1::solve(parsed.clone());
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part2::solve(parsed);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("Second part in {:?}", elapsed);
-    total += elapsed;
-
-    if verbose {
-        println!("Total {:?}", total);
-    }
-    total
-}
+)]
+pub struct Day12;
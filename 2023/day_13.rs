@@ -1,12 +1,7 @@
 //! https://adventofcode.com/2023/day/13
 //! https://adventofcode.com/2023/day/13/input
 
-use std::{
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
-
-use utils::matrix::rotate_left;
+use utils::{matrix::rotate_left, Solution};
 
 type Pattern = Vec<Vec<bool>>;
 
@@ -15,7 +10,6 @@ type Parsed = Vec<Pattern>;
 fn parse(input: &str) -> Parsed {
     let separator = "\n".repeat(2);
     input
-        .replace("\r\n", "\n")
         .split(&separator)
         .map(|pattern| {
             pattern
@@ -103,8 +97,11 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-    let test_input = "#.##..##.
+#[derive(Solution)]
+#[example(
+    part1 = 405,
+    part2 = 400,
+    input = "#.##..##.
 ..#.##.#.
 ##......#
 ##......#
@@ -119,39 +116,5 @@ pub fn main(test: bool, verbose: bool) -> Duration {
 #####.##.
 ..##..###
 #....#..#"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2023/day_13_input.txt").unwrap()
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let parsed = parse(&puzzle_input);
-    let elapsed = start.elapsed();
-    if verbose {
-        println!("Parsed in {:?}", elapsed);
-        total += elapsed;
-    }
-
-    let start = Instant::now();
-    let result = part1::solve(parsed.clone());
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part2::solve(parsed);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("Second part in {:?}", elapsed);
-    total += elapsed;
-
-    if verbose {
-        println!("Total {:?}", total);
-    }
-    total
-}
+)]
+pub struct Day13;
@@ -1,12 +1,7 @@
 //! https://adventofcode.com/2023/day/14
 //! https://adventofcode.com/2023/day/14/input
 
-use std::{
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
-
-use utils::from_char;
+use utils::{errors::ParseError, from_char, parsing::try_parse_matrix, Solution};
 
 #[from_char]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
@@ -18,11 +13,8 @@ pub enum Rock {
 
 type Parsed = Vec<Vec<Rock>>;
 
-fn parse(input: &str) -> Parsed {
-    input
-        .lines()
-        .map(|line| line.chars().map(Rock::from).collect())
-        .collect()
+fn parse(input: &str) -> Result<Parsed, ParseError> {
+    try_parse_matrix(input)
 }
 
 pub mod part1 {
@@ -126,8 +118,11 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-    let test_input = "O....#....
+#[derive(Solution)]
+#[example(
+    part1 = 136,
+    part2 = 64,
+    input = "O....#....
 O.OO#....#
 .....##...
 OO.#O....O
@@ -137,39 +132,5 @@ O.#..O.#.#
 .......O..
 #....###..
 #OO..#...."
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2023/day_14_input.txt").unwrap()
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let parsed = parse(&puzzle_input);
-    let elapsed = start.elapsed();
-    if verbose {
-        println!("Parsed in {:?}", elapsed);
-        total += elapsed;
-    }
-
-    let start = Instant::now();
-    let result = part1::solve(parsed.clone());
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part2::solve(parsed);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("Second part in {:?}", elapsed);
-    total += elapsed;
-
-    if verbose {
-        println!("Total {:?}", total);
-    }
-    total
-}
+)]
+pub struct Day14;
@@ -1,13 +1,9 @@
 //! https://adventofcode.com/2023/day/16
 //! https://adventofcode.com/2023/day/16/input
 
-use std::{
-    collections::{HashSet, VecDeque},
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
+use std::collections::{HashSet, VecDeque};
 
-use utils::from_char;
+use utils::{errors::ParseError, from_char, parsing::try_parse_matrix, Solution};
 
 #[from_char]
 #[derive(Copy, Clone, Debug)]
@@ -39,11 +35,8 @@ impl Beam {
 
 type Parsed = Vec<Vec<Tile>>;
 
-fn parse(input: &str) -> Parsed {
-    input
-        .lines()
-        .map(|l| l.chars().map(Tile::from).collect())
-        .collect()
+fn parse(input: &str) -> Result<Parsed, ParseError> {
+    try_parse_matrix(input)
 }
 
 fn find_energized(
@@ -191,8 +184,11 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-    let test_input = r".|...\....
+#[derive(Solution)]
+#[example(
+    part1 = 46,
+    part2 = 51,
+    input = r".|...\....
 |.-.\.....
 .....|-...
 ........|.
@@ -202,39 +198,5 @@ pub fn main(test: bool, verbose: bool) -> Duration {
 .-.-/..|..
 .|....-|.\
 ..//.|...."
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2023/day_16_input.txt").unwrap()
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let parsed = parse(&puzzle_input);
-    let elapsed = start.elapsed();
-    if verbose {
-        println!("Parsed in {:?}", elapsed);
-        total += elapsed;
-    }
-
-    let start = Instant::now();
-    let result = part1::solve(parsed.clone());
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part2::solve(parsed);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("Second part in {:?}", elapsed);
-    total += elapsed;
-
-    if verbose {
-        println!("Total {:?}", total);
-    }
-    total
-}
+)]
+pub struct Day16;
@@ -1,13 +1,9 @@
 //! https://adventofcode.com/2023/day/17
 //! https://adventofcode.com/2023/day/17/input
 
-use std::{
-    collections::BinaryHeap,
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
+use std::collections::BinaryHeap;
 
-use utils::{coords::Direction, new, IntoEnumIterator};
+use utils::{coords::Direction, new, IntoEnumIterator, Solution};
 
 type Parsed = Vec<Vec<usize>>;
 
@@ -140,8 +136,11 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-    let test_input = "2413432311323
+#[derive(Solution)]
+#[example(
+    part1 = 102,
+    part2 = 94,
+    input = "2413432311323
 3215453535623
 3255245654254
 3446585845452
@@ -154,39 +153,5 @@ pub fn main(test: bool, verbose: bool) -> Duration {
 1224686865563
 2546548887735
 4322674655533"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2023/day_17_input.txt").unwrap()
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let parsed = parse(&puzzle_input);
-    let elapsed = start.elapsed();
-    if verbose {
-        println!("Parsed in {:?}", elapsed);
-        total += elapsed;
-    }
-
-    let start = Instant::now();
-    let result = part1::solve(parsed.clone());
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part2::solve(parsed);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("Second part in {:?}", elapsed);
-    total += elapsed;
-
-    if verbose {
-        println!("Total {:?}", total);
-    }
-    total
-}
+)]
+pub struct Day17;
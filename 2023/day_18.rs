@@ -1,12 +1,9 @@
 //! https://adventofcode.com/2023/day/18
 //! https://adventofcode.com/2023/day/18/input
 
-use std::{
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
+use std::str::FromStr;
 
-use utils::from_char;
+use utils::{errors::ParseError, from_char, parsing::try_parse_lines, Solution};
 
 #[from_char]
 #[derive(Copy, Clone)]
@@ -35,24 +32,36 @@ pub struct Dig {
     color: usize,
 }
 
-impl From<&str> for Dig {
-    fn from(value: &str) -> Self {
+impl FromStr for Dig {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
         let mut parts = value.split_whitespace();
-        Self {
-            direction: Direction::from(parts.next().unwrap().chars().next().unwrap()),
-            distance: parts.next().unwrap().parse().unwrap(),
-            color: {
-                let color = parts.next().unwrap();
-                usize::from_str_radix(&color[2..color.len() - 1], 16).unwrap()
-            },
-        }
+        let mut next = |var| parts.next().ok_or(ParseError::EndOfInput(var));
+        let (direction, distance, color) = (next("direction")?, next("distance")?, next("color")?);
+        let hex = color
+            .strip_prefix("(#")
+            .and_then(|color| color.strip_suffix(')'))
+            .ok_or_else(|| {
+                ParseError::Invalid(format!("Expected `(#rrggbb)`, found `{}`", color))
+                    .at(value, color)
+            })?;
+        Ok(Self {
+            direction: Direction::try_from(direction.chars().next().unwrap_or_default())
+                .map_err(|error| ParseError::new("direction", error).at(value, direction))?,
+            distance: distance
+                .parse()
+                .map_err(|error| ParseError::new("distance", error).at(value, distance))?,
+            color: usize::from_str_radix(hex, 16)
+                .map_err(|error| ParseError::new("color", error).at(value, hex))?,
+        })
     }
 }
 
 type Parsed = Vec<Dig>;
 
-fn parse(input: &str) -> Parsed {
-    input.lines().map(Dig::from).collect()
+fn parse(input: &str) -> Result<Parsed, ParseError> {
+    try_parse_lines(input)
 }
 
 pub fn solve(instructions: Parsed) -> usize {
@@ -110,8 +119,11 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-    let test_input = "R 6 (#70c710)
+#[derive(Solution)]
+#[example(
+    part1 = 62,
+    part2 = 952408144115,
+    input = "R 6 (#70c710)
 D 5 (#0dc571)
 L 2 (#5713f0)
 D 2 (#d2c081)
@@ -125,39 +137,5 @@ R 2 (#7807d2)
 U 3 (#a77fa3)
 L 2 (#015232)
 U 2 (#7a21e3)"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2023/day_18_input.txt").unwrap()
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let parsed = parse(&puzzle_input);
-    let elapsed = start.elapsed();
-    if verbose {
-        println!("Parsed in {:?}", elapsed);
-        total += elapsed;
-    }
-
-    let start = Instant::now();
-    let result = part1::solve(parsed.clone());
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part2::solve(parsed);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("Second part in {:?}", elapsed);
-    total += elapsed;
-
-    if verbose {
-        println!("Total {:?}", total);
-    }
-    total
-}
+)]
+pub struct Day18;
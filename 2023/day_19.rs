@@ -1,17 +1,12 @@
 //! https://adventofcode.com/2023/day/19
 //! https://adventofcode.com/2023/day/19/input
 
-use std::{
-    collections::HashMap,
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
+use std::{collections::HashMap, str::FromStr};
 
-use utils::from_char;
+use utils::{errors::ParseError, from_char, parsing::try_parse_lines, FromStr, Solution};
 
-use crate::LINE_ENDING;
-
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, FromStr)]
+#[pattern("{{x={x},m={m},a={a},s={s}}}")]
 pub struct Part {
     x: usize,
     m: usize,
@@ -19,35 +14,14 @@ pub struct Part {
     s: usize,
 }
 
-impl From<&str> for Part {
-    fn from(value: &str) -> Self {
-        let mut parts = value[1..value.len() - 1].split(',');
-        Self {
-            x: parts.next().unwrap()[2..].parse().unwrap(),
-            m: parts.next().unwrap()[2..].parse().unwrap(),
-            a: parts.next().unwrap()[2..].parse().unwrap(),
-            s: parts.next().unwrap()[2..].parse().unwrap(),
-        }
-    }
-}
-
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, FromStr)]
+#[pattern("{name}{{{rules}}}")]
 pub struct Workflow {
     name: String,
+    #[inner_separator(',')]
     rules: Vec<Rule>,
 }
 
-impl From<&str> for Workflow {
-    fn from(value: &str) -> Self {
-        let name = &value[0..value.find('{').unwrap()];
-        let rules = &value[name.len() + 1..value.len() - 1];
-        Self {
-            name: name.to_owned(),
-            rules: rules.split(',').map(Rule::from).collect(),
-        }
-    }
-}
-
 #[derive(Clone, Debug)]
 struct Rule {
     category: Category,
@@ -56,24 +30,39 @@ struct Rule {
     target: String,
 }
 
-impl From<&str> for Rule {
-    fn from(value: &str) -> Self {
-        let parts: Vec<_> = value.split(':').collect();
-        if parts.len() == 1 {
-            Self {
+impl FromStr for Rule {
+    type Err = ParseError;
+
+    fn from_str(rule: &str) -> Result<Self, Self::Err> {
+        let Some((condition, target)) = rule.split_once(':') else {
+            return Ok(Self {
                 category: Category::Last,
                 greater: false,
                 value: 0,
-                target: parts[0].to_owned(),
-            }
-        } else {
-            Self {
-                category: parts[0].chars().next().unwrap().into(),
-                greater: parts[0].chars().nth(1).unwrap() == '>',
-                value: parts[0][2..].parse().unwrap(),
-                target: parts[1].to_owned(),
+                target: rule.to_owned(),
+            });
+        };
+        let mut chars = condition.chars();
+        let category = Category::try_from(chars.next().unwrap_or_default())
+            .map_err(|error| ParseError::new("category", error).at(rule, condition))?;
+        let greater = match chars.next() {
+            Some('>') => true,
+            Some('<') => false,
+            _ => {
+                return Err(
+                    ParseError::Invalid("Expected `<` or `>`".to_owned()).at(rule, &condition[1..])
+                )
             }
-        }
+        };
+        let value = chars.as_str();
+        Ok(Self {
+            category,
+            greater,
+            value: value
+                .parse()
+                .map_err(|error| ParseError::new("value", error).at(rule, value))?,
+            target: target.to_owned(),
+        })
     }
 }
 
@@ -89,17 +78,16 @@ enum Category {
 
 type Parsed = (Vec<Part>, HashMap<String, Workflow>);
 
-fn parse(input: &str, separator: String) -> Parsed {
-    let mut split = input.split(&separator);
-    let workflows = split
-        .next()
-        .unwrap()
-        .lines()
-        .map(Workflow::from)
+fn parse(input: &str) -> Result<Parsed, ParseError> {
+    let (workflows, parts) = input
+        .split_once("\n\n")
+        .ok_or(ParseError::EndOfInput("parts"))?;
+    let workflows = try_parse_lines::<Workflow>(workflows)?
+        .into_iter()
         .map(|w| (w.name.clone(), w))
         .collect();
-    let parts = split.next().unwrap().lines().map(Part::from).collect();
-    (parts, workflows)
+    let parts = try_parse_lines(parts).map_err(|error| error.at(input, parts))?;
+    Ok((parts, workflows))
 }
 
 pub mod part1 {
@@ -224,8 +212,11 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-    let test_input = "px{a<2006:qkq,m>2090:A,rfg}
+#[derive(Solution)]
+#[example(
+    part1 = 19114,
+    part2 = 167409079868000,
+    input = "px{a<2006:qkq,m>2090:A,rfg}
 pv{a>1716:R,A}
 lnx{m>1548:A,A}
 rfg{s<537:gd,x>2440:R,A}
@@ -242,42 +233,5 @@ hdj{m>838:A,pv}
 {x=2036,m=264,a=79,s=2244}
 {x=2461,m=1339,a=466,s=291}
 {x=2127,m=1623,a=2188,s=1013}"
-        .to_owned();
-    let (puzzle_input, separator) = if test {
-        (test_input, "\n".repeat(2))
-    } else {
-        (
-            read_to_string("../inputs/2023/day_19_input.txt").unwrap(),
-            LINE_ENDING.repeat(2),
-        )
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let parsed = parse(&puzzle_input, separator);
-    let elapsed = start.elapsed();
-    if verbose {
-        println!("Parsed in {:?}", elapsed);
-        total += elapsed;
-    }
-
-    let start = Instant::now();
-    let result = part1::solve(parsed.clone());
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part2::solve(parsed);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("Second part in {:?}", elapsed);
-    total += elapsed;
-
-    if verbose {
-        println!("Total {:?}", total);
-    }
-    total
-}
+)]
+pub struct Day19;
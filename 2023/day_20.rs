@@ -1,11 +1,9 @@
 //! https://adventofcode.com/2023/day/20
 //! https://adventofcode.com/2023/day/20/input
 
-use std::{
-    collections::HashMap,
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
+use std::collections::HashMap;
+
+use utils::Solution;
 
 #[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
 pub struct Module {
@@ -209,6 +207,11 @@ pub mod part2 {
                 }]);
                 while let Some(Pulse { high, from, to }) = queue.pop_front() {
                     if from == input && high {
+                        utils::debug!(
+                            "{} sends a high pulse after {} presses",
+                            modules[input].name,
+                            i
+                        );
                         loops.push(i);
                         break 'outer;
                     }
@@ -224,45 +227,13 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-    let test_input = "broadcaster -> a, b, c
+#[derive(Solution)]
+#[example(
+    part1 = 32000000,
+    input = "broadcaster -> a, b, c
 %a -> b
 %b -> c
 %c -> inv
 &inv -> a"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2023/day_20_input.txt").unwrap()
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let parsed = parse(&puzzle_input);
-    let elapsed = start.elapsed();
-    if verbose {
-        println!("Parsed in {:?}", elapsed);
-        total += elapsed;
-    }
-
-    let start = Instant::now();
-    let result = part1::solve(parsed.clone());
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part2::solve(parsed);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("Second part in {:?}", elapsed);
-    total += elapsed;
-
-    if verbose {
-        println!("Total {:?}", total);
-    }
-    total
-}
+)]
+pub struct Day20;
@@ -1,10 +1,7 @@
 //! https://adventofcode.com/2023/day/21
 //! https://adventofcode.com/2023/day/21/input
 
-use std::{
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
+use utils::Solution;
 
 #[derive(Copy, Clone, Debug)]
 pub enum Tile {
@@ -109,8 +106,8 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-    let test_input = "...........
+#[derive(Solution)]
+#[example(input = "...........
 .....###.#.
 .###.##..#.
 ..#.#...#..
@@ -120,40 +117,5 @@ pub fn main(test: bool, verbose: bool) -> Duration {
 .......##..
 .##.#.####.
 .##..##.##.
-..........."
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2023/day_21_input.txt").unwrap()
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let parsed = parse(&puzzle_input);
-    let elapsed = start.elapsed();
-    if verbose {
-        println!("Parsed in {:?}", elapsed);
-        total += elapsed;
-    }
-
-    let start = Instant::now();
-    let result = part1::solve(parsed.clone());
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part2::solve(parsed);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("Second part in {:?}", elapsed);
-    total += elapsed;
-
-    if verbose {
-        println!("Total {:?}", total);
-    }
-    total
-}
+...........")]
+pub struct Day21;
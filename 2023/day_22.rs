@@ -1,14 +1,9 @@
 //! https://adventofcode.com/2023/day/22
 //! https://adventofcode.com/2023/day/22/input
 
-use std::{
-    cmp::Ordering,
-    collections::HashSet,
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
+use std::{cmp::Ordering, collections::HashSet};
 
-use utils::{parsing::parse_lines, FromStr};
+use utils::{errors::ParseError, parsing::try_parse_lines, FromStr, Solution};
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, FromStr)]
 #[separator(',')]
@@ -39,15 +34,15 @@ pub struct Brick {
 
 type Parsed = Vec<Brick>;
 
-fn parse(input: &str) -> Parsed {
-    let mut result = parse_lines::<Brick>(input);
+fn parse(input: &str) -> Result<Parsed, ParseError> {
+    let mut result = try_parse_lines::<Brick>(input)?;
     result.iter_mut().for_each(|b| {
         if b.start > b.end {
             (b.start, b.end) = (b.end, b.start);
         }
     });
     result.sort();
-    result
+    Ok(result)
 }
 
 fn make_space(bricks: &mut Parsed) -> Vec<Vec<Vec<Option<usize>>>> {
@@ -176,47 +171,16 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-    let test_input = "1,0,1~1,2,1
+#[derive(Solution)]
+#[example(
+    part1 = 5,
+    part2 = 7,
+    input = "1,0,1~1,2,1
 0,0,2~2,0,2
 0,2,3~2,2,3
 0,0,4~0,2,4
 2,0,5~2,2,5
 0,1,6~2,1,6
 1,1,8~1,1,9"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2023/day_22_input.txt").unwrap()
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let parsed = parse(&puzzle_input);
-    let elapsed = start.elapsed();
-    if verbose {
-        println!("Parsed in {:?}", elapsed);
-        total += elapsed;
-    }
-
-    let start = Instant::now();
-    let result = part1::solve(parsed.clone());
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part2::solve(parsed);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("Second part in {:?}", elapsed);
-    total += elapsed;
-
-    if verbose {
-        println!("Total {:?}", total);
-    }
-    total
-}
+)]
+pub struct Day22;
@@ -1,12 +1,7 @@
 //! https://adventofcode.com/2023/day/23
 //! https://adventofcode.com/2023/day/23/input
 
-use std::{
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
-
-use utils::{from_char, parsing::parse_matrix};
+use utils::{errors::ParseError, from_char, parsing::try_parse_matrix, Solution};
 
 #[from_char]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -21,8 +16,8 @@ pub enum Cell {
 
 type Parsed = Vec<Vec<Cell>>;
 
-fn parse(input: &str) -> Parsed {
-    parse_matrix(input)
+fn parse(input: &str) -> Result<Parsed, ParseError> {
+    try_parse_matrix(input)
 }
 
 pub mod part1 {
@@ -159,8 +154,11 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-    let test_input = "#.#####################
+#[derive(Solution)]
+#[example(
+    part1 = 94,
+    part2 = 154,
+    input = "#.#####################
 #.......#########...###
 #######.#########.#.###
 ###.....#.>.>.###.#.###
@@ -183,39 +181,5 @@ pub fn main(test: bool, verbose: bool) -> Duration {
 #.###.###.#.###.#.#v###
 #.....###...###...#...#
 #####################.#"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2023/day_23_input.txt").unwrap()
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let parsed = parse(&puzzle_input);
-    let elapsed = start.elapsed();
-    if verbose {
-        println!("Parsed in {:?}", elapsed);
-        total += elapsed;
-    }
-
-    let start = Instant::now();
-    let result = part1::solve(parsed.clone());
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part2::solve(parsed);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("Second part in {:?}", elapsed);
-    total += elapsed;
-
-    if verbose {
-        println!("Total {:?}", total);
-    }
-    total
-}
+)]
+pub struct Day23;
@@ -1,13 +1,9 @@
 //! https://adventofcode.com/2023/day/24
 //! https://adventofcode.com/2023/day/24/input
 
-use std::{
-    fmt::Debug,
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
+use std::fmt::Debug;
 
-use utils::{parsing::parse_lines, FromStr};
+use utils::{errors::ParseError, parsing::try_parse_lines, FromStr, Solution};
 
 #[derive(Copy, Clone, Debug, FromStr)]
 #[separator(',')]
@@ -26,8 +22,8 @@ pub struct Hail {
 
 type Parsed = Vec<Hail>;
 
-fn parse(input: &str) -> Parsed {
-    parse_lines(input)
+fn parse(input: &str) -> Result<Parsed, ParseError> {
+    try_parse_lines(input)
 }
 
 pub mod part1 {
@@ -157,45 +153,10 @@ pub mod part2 {
     }
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-    let test_input = "19, 13, 30 @ -2,  1, -2
+#[derive(Solution)]
+#[example(input = "19, 13, 30 @ -2,  1, -2
 18, 19, 22 @ -1, -1, -2
 20, 25, 34 @ -2, -2, -4
 12, 31, 28 @ -1, -2, -1
-20, 19, 15 @  1, -5, -3"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2023/day_24_input.txt").unwrap()
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let parsed = parse(&puzzle_input);
-    let elapsed = start.elapsed();
-    if verbose {
-        println!("Parsed in {:?}", elapsed);
-        total += elapsed;
-    }
-
-    let start = Instant::now();
-    let result = part1::solve(parsed.clone());
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    let start = Instant::now();
-    let result = part2::solve(parsed);
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("Second part in {:?}", elapsed);
-    total += elapsed;
-
-    if verbose {
-        println!("Total {:?}", total);
-    }
-    total
-}
+20, 19, 15 @  1, -5, -3")]
+pub struct Day24;
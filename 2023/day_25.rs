@@ -1,11 +1,9 @@
 //! https://adventofcode.com/2023/day/25
 //! https://adventofcode.com/2023/day/25/input
 
-use std::{
-    collections::HashMap,
-    fs::read_to_string,
-    time::{Duration, Instant},
-};
+use std::collections::HashMap;
+
+use utils::Solution;
 
 type Parsed = Vec<Vec<usize>>;
 
@@ -57,8 +55,9 @@ pub mod part1 {
     }
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-    let test_input = "jqt: rhn xhk nvd
+#[derive(Solution)]
+#[solution(single_part)]
+#[example(input = "jqt: rhn xhk nvd
 rsh: frs pzl lsr
 xhk: hfx
 cmg: qnr nvd lhk bvb
@@ -70,33 +69,5 @@ ntq: jqt hfx bvb xhk
 nvd: lhk
 lsr: lhk
 rzs: qnr cmg lsr rsh
-frs: qnr lhk lsr"
-        .to_owned();
-    let puzzle_input = if test {
-        test_input
-    } else {
-        read_to_string("../inputs/2023/day_25_input.txt").unwrap()
-    };
-
-    let mut total = Duration::default();
-
-    let start = Instant::now();
-    let parsed = parse(&puzzle_input);
-    let elapsed = start.elapsed();
-    if verbose {
-        println!("Parsed in {:?}", elapsed);
-        total += elapsed;
-    }
-
-    let start = Instant::now();
-    let result = part1::solve(parsed.clone());
-    let elapsed = start.elapsed();
-    println!("{}", result);
-    println!("First part in {:?}", elapsed);
-    total += elapsed;
-
-    if verbose {
-        println!("Total {:?}", total);
-    }
-    total
-}
+frs: qnr lhk lsr")]
+pub struct Day25;
@@ -1,10 +1,7 @@
 //! https://adventofcode.com/2024/day/1
 //! https://adventofcode.com/2024/day/1/input
 
-use std::{
-	fs::read_to_string,
-	time::{Duration, Instant},
-};
+use utils::Solution;
 
 type Parsed = (Vec<usize>, Vec<usize>);
 
@@ -47,40 +44,15 @@ pub mod part2 {
 	}
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-	let test_input = "".to_owned();
-	let puzzle_input = if test {
-		test_input
-	} else {
-		read_to_string("../inputs/2024/day_01_input.txt").unwrap()
-	};
-
-	let mut total = Duration::default();
-
-	let start = Instant::now();
-	let parsed = parse(&puzzle_input);
-	let elapsed = start.elapsed();
-	if verbose {
-		println!("Parsed in {:?}", elapsed);
-		total += elapsed;
-	}
-
-	let start = Instant::now();
-	let result = part1::solve(parsed.clone());
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("First part in {:?}", elapsed);
-	total += elapsed;
-
-	let start = Instant::now();
-	let result = part2::solve(parsed);
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("Second part in {:?}", elapsed);
-	total += elapsed;
-
-	if verbose {
-		println!("Total {:?}", total);
-	}
-	total
-}
+#[derive(Solution)]
+#[example(
+	part1 = 11,
+	part2 = 31,
+	input = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3"
+)]
+pub struct Day01;
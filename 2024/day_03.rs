@@ -1,12 +1,8 @@
 //! https://adventofcode.com/2024/day/3
 //! https://adventofcode.com/2024/day/3/input
 
-use std::{
-	fs::read_to_string,
-	time::{Duration, Instant},
-};
-
 use regex::Regex;
+use utils::Solution;
 
 type Parsed = Vec<Op>;
 
@@ -64,40 +60,13 @@ pub mod part2 {
 	}
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-	let test_input = "".to_owned();
-	let puzzle_input = if test {
-		test_input
-	} else {
-		read_to_string("../inputs/2024/day_03_input.txt").unwrap()
-	};
-
-	let mut total = Duration::default();
-
-	let start = Instant::now();
-	let parsed = parse(&puzzle_input);
-	let elapsed = start.elapsed();
-	if verbose {
-		println!("Parsed in {:?}", elapsed);
-		total += elapsed;
-	}
-
-	let start = Instant::now();
-	let result = part1::solve(parsed.clone());
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("First part in {:?}", elapsed);
-	total += elapsed;
-
-	let start = Instant::now();
-	let result = part2::solve(parsed);
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("Second part in {:?}", elapsed);
-	total += elapsed;
-
-	if verbose {
-		println!("Total {:?}", total);
-	}
-	total
-}
+#[derive(Solution)]
+#[example(
+	part1 = 161,
+	input = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))"
+)]
+#[example(
+	part2 = 48,
+	input = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))"
+)]
+pub struct Day03;
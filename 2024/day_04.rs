@@ -1,12 +1,7 @@
 //! https://adventofcode.com/2024/day/4
 //! https://adventofcode.com/2024/day/4/input
 
-use std::{
-	fs::read_to_string,
-	time::{Duration, Instant},
-};
-
-use utils::parsing::parse_matrix;
+use utils::{parsing::parse_matrix, Solution};
 
 type Parsed = Vec<Vec<char>>;
 
@@ -80,40 +75,19 @@ pub mod part2 {
 	}
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-	let test_input = "".to_owned();
-	let puzzle_input = if test {
-		test_input
-	} else {
-		read_to_string("../inputs/2024/day_04_input.txt").unwrap()
-	};
-
-	let mut total = Duration::default();
-
-	let start = Instant::now();
-	let parsed = parse(&puzzle_input);
-	let elapsed = start.elapsed();
-	if verbose {
-		println!("Parsed in {:?}", elapsed);
-		total += elapsed;
-	}
-
-	let start = Instant::now();
-	let result = part1::solve(parsed.clone());
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("First part in {:?}", elapsed);
-	total += elapsed;
-
-	let start = Instant::now();
-	let result = part2::solve(parsed);
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("Second part in {:?}", elapsed);
-	total += elapsed;
-
-	if verbose {
-		println!("Total {:?}", total);
-	}
-	total
-}
+#[derive(Solution)]
+#[example(
+	part1 = 18,
+	part2 = 9,
+	input = "MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX"
+)]
+pub struct Day04;
@@ -1,18 +1,13 @@
 //! https://adventofcode.com/2024/day/5
 //! https://adventofcode.com/2024/day/5/input
 
-use std::{
-	fs::read_to_string,
-	time::{Duration, Instant},
-};
-
-use crate::LINE_ENDING;
+use utils::Solution;
 
 type Parsed = (Vec<(usize, usize)>, Vec<Vec<usize>>);
 
 fn parse(input: &str) -> Parsed {
 	// let sep = "\n".repeat(2);
-	let sep = LINE_ENDING.repeat(2);
+	let sep = "\n".repeat(2);
 	let mut parts = input.split(&sep);
 	let rules = parts.next().unwrap();
 	let updates = parts.next().unwrap();
@@ -154,8 +149,11 @@ pub mod part2 {
 	}
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-	let test_input = "47|53
+#[derive(Solution)]
+#[example(
+	part1 = 143,
+	part2 = 123,
+	input = "47|53
 97|13
 97|61
 97|47
@@ -184,39 +182,5 @@ pub fn main(test: bool, verbose: bool) -> Duration {
 61,13,29
 97,13,75,29,47
 "
-	.to_owned();
-	let puzzle_input = if test {
-		test_input
-	} else {
-		read_to_string("../inputs/2024/day_05_input.txt").unwrap()
-	};
-
-	let mut total = Duration::default();
-
-	let start = Instant::now();
-	let parsed = parse(&puzzle_input);
-	let elapsed = start.elapsed();
-	if verbose {
-		println!("Parsed in {:?}", elapsed);
-		total += elapsed;
-	}
-
-	let start = Instant::now();
-	let result = part1::solve(parsed.clone());
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("First part in {:?}", elapsed);
-	total += elapsed;
-
-	let start = Instant::now();
-	let result = part2::solve(parsed);
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("Second part in {:?}", elapsed);
-	total += elapsed;
-
-	if verbose {
-		println!("Total {:?}", total);
-	}
-	total
-}
+)]
+pub struct Day05;
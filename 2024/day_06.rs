@@ -1,10 +1,7 @@
 //! https://adventofcode.com/2024/day/6
 //! https://adventofcode.com/2024/day/6/input
 
-use std::{
-	fs::read_to_string,
-	time::{Duration, Instant},
-};
+use utils::Solution;
 
 type Parsed = (Vec<Vec<bool>>, (usize, usize));
 
@@ -93,8 +90,11 @@ pub mod part2 {
 	}
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-	let test_input = "....#.....
+#[derive(Solution)]
+#[example(
+	part1 = 41,
+	part2 = 6,
+	input = "....#.....
 ....+---+#
 ....|...|.
 ..#.|...|.
@@ -105,39 +105,5 @@ pub fn main(test: bool, verbose: bool) -> Duration {
 #+----++..
 ......#O..
 "
-	.to_owned();
-	let puzzle_input = if test {
-		test_input
-	} else {
-		read_to_string("../inputs/2024/day_06_input.txt").unwrap()
-	};
-
-	let mut total = Duration::default();
-
-	let start = Instant::now();
-	let parsed = parse(&puzzle_input);
-	let elapsed = start.elapsed();
-	if verbose {
-		println!("Parsed in {:?}", elapsed);
-		total += elapsed;
-	}
-
-	let start = Instant::now();
-	let result = part1::solve(parsed.clone());
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("First part in {:?}", elapsed);
-	total += elapsed;
-
-	let start = Instant::now();
-	let result = part2::solve(parsed);
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("Second part in {:?}", elapsed);
-	total += elapsed;
-
-	if verbose {
-		println!("Total {:?}", total);
-	}
-	total
-}
+)]
+pub struct Day06;
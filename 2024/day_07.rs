@@ -1,38 +1,53 @@
 //! https://adventofcode.com/2024/day/7
 //! https://adventofcode.com/2024/day/7/input
 
-use std::{
-	fs::read_to_string,
-	str::FromStr,
-	time::{Duration, Instant},
+use utils::{
+	errors::ParseError,
+	generate::{Rng, Seeded},
+	parsing::try_parse_lines,
+	FromStr, Solution,
 };
 
-use utils::parsing::parse_lines;
-
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, FromStr)]
+#[separator(':')]
 pub struct Equation {
 	target: u64,
+	#[inner_separator]
 	factors: Vec<u64>,
 }
 
-impl FromStr for Equation {
-	type Err = ();
+type Parsed = Vec<Equation>;
 
-	fn from_str(line: &str) -> Result<Self, Self::Err> {
-		let mut parts = line.split(':');
-		let target = parts.next().unwrap().parse().unwrap();
-		let factors = parts.next().unwrap()[1..]
-			.split_whitespace()
-			.map(|n| n.parse().unwrap())
-			.collect();
-		Ok(Equation { target, factors })
-	}
+fn parse(input: &str) -> Result<Parsed, ParseError> {
+	try_parse_lines(input)
 }
 
-type Parsed = Vec<Equation>;
-
-fn parse(input: &str) -> Parsed {
-	parse_lines(input)
+/// `size` equations of up to 12 numbers, about half of which can be made true.
+fn generate(rng: &mut Seeded, size: usize) -> String {
+	// keeps the sum of the targets far from overflowing
+	const LIMIT: u64 = 10_u64.pow(13);
+	(0..size)
+		.map(|_| {
+			let factors: Vec<u64> = (0..rng.gen_range(2..=12))
+				.map(|_| rng.gen_range(1..1000))
+				.collect();
+			let mut target = factors[0];
+			for &factor in &factors[1..] {
+				let next = match rng.gen_range(0..3) {
+					0 => target * factor,
+					1 => target + factor,
+					_ => target * 10_u64.pow(factor.ilog10() + 1) + factor,
+				};
+				target = if next <= LIMIT { next } else { target + factor };
+			}
+			if rng.gen() {
+				target += 1;
+			}
+			let factors: Vec<_> = factors.iter().map(u64::to_string).collect();
+			format!("{}: {}", target, factors.join(" "))
+		})
+		.collect::<Vec<_>>()
+		.join("\n")
 }
 
 pub mod part1 {
@@ -108,8 +123,12 @@ pub mod part2 {
 	}
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-	let test_input = "190: 10 19
+#[derive(Solution)]
+#[solution(generator = generate)]
+#[example(
+	part1 = 3749,
+	part2 = 11387,
+	input = "190: 10 19
 3267: 81 40 27
 83: 17 5
 156: 15 6
@@ -119,39 +138,5 @@ pub fn main(test: bool, verbose: bool) -> Duration {
 21037: 9 7 18 13
 292: 11 6 16 20
 "
-	.to_owned();
-	let puzzle_input = if test {
-		test_input
-	} else {
-		read_to_string("../inputs/2024/day_07_input.txt").unwrap()
-	};
-
-	let mut total = Duration::default();
-
-	let start = Instant::now();
-	let parsed = parse(&puzzle_input);
-	let elapsed = start.elapsed();
-	if verbose {
-		println!("Parsed in {:?}", elapsed);
-		total += elapsed;
-	}
-
-	let start = Instant::now();
-	let result = part1::solve(parsed.clone());
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("First part in {:?}", elapsed);
-	total += elapsed;
-
-	let start = Instant::now();
-	let result = part2::solve(parsed);
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("Second part in {:?}", elapsed);
-	total += elapsed;
-
-	if verbose {
-		println!("Total {:?}", total);
-	}
-	total
-}
+)]
+pub struct Day07;
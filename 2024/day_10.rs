@@ -1,10 +1,7 @@
 //! https://adventofcode.com/2024/day/10
 //! https://adventofcode.com/2024/day/10/input
 
-use std::{
-	fs::read_to_string,
-	time::{Duration, Instant},
-};
+use utils::Solution;
 
 type Parsed = Vec<Vec<u8>>;
 
@@ -92,8 +89,11 @@ pub mod part2 {
 	}
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-	let test_input = "89010123
+#[derive(Solution)]
+#[example(
+	part1 = 36,
+	part2 = 81,
+	input = "89010123
 78121874
 87430965
 96549874
@@ -102,39 +102,5 @@ pub fn main(test: bool, verbose: bool) -> Duration {
 01329801
 10456732
 "
-	.to_owned();
-	let puzzle_input = if test {
-		test_input
-	} else {
-		read_to_string("../inputs/2024/day_10_input.txt").unwrap()
-	};
-
-	let mut total = Duration::default();
-
-	let start = Instant::now();
-	let parsed = parse(&puzzle_input);
-	let elapsed = start.elapsed();
-	if verbose {
-		println!("Parsed in {:?}", elapsed);
-		total += elapsed;
-	}
-
-	let start = Instant::now();
-	let result = part1::solve(parsed.clone());
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("First part in {:?}", elapsed);
-	total += elapsed;
-
-	let start = Instant::now();
-	let result = part2::solve(parsed);
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("Second part in {:?}", elapsed);
-	total += elapsed;
-
-	if verbose {
-		println!("Total {:?}", total);
-	}
-	total
-}
+)]
+pub struct Day10;
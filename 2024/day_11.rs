@@ -1,11 +1,9 @@
 //! https://adventofcode.com/2024/day/11
 //! https://adventofcode.com/2024/day/11/input
 
-use std::{
-	collections::{hash_map::Entry, HashMap},
-	fs::read_to_string,
-	time::{Duration, Instant},
-};
+use std::collections::{hash_map::Entry, HashMap};
+
+use utils::Solution;
 
 type Parsed = Vec<usize>;
 
@@ -69,40 +67,6 @@ pub mod part2 {
 	}
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-	let test_input = "125 17".to_owned();
-	let puzzle_input = if test {
-		test_input
-	} else {
-		read_to_string("../inputs/2024/day_11_input.txt").unwrap()
-	};
-
-	let mut total = Duration::default();
-
-	let start = Instant::now();
-	let parsed = parse(&puzzle_input);
-	let elapsed = start.elapsed();
-	if verbose {
-		println!("Parsed in {:?}", elapsed);
-		total += elapsed;
-	}
-
-	let start = Instant::now();
-	let result = part1::solve(parsed.clone());
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("First part in {:?}", elapsed);
-	total += elapsed;
-
-	let start = Instant::now();
-	let result = part2::solve(parsed);
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("Second part in {:?}", elapsed);
-	total += elapsed;
-
-	if verbose {
-		println!("Total {:?}", total);
-	}
-	total
-}
+#[derive(Solution)]
+#[example(part1 = 55312, part2 = 65601038650482, input = "125 17")]
+pub struct Day11;
@@ -1,11 +1,9 @@
 //! https://adventofcode.com/2024/day/12
 //! https://adventofcode.com/2024/day/12/input
 
-use std::{
-	collections::VecDeque,
-	fs::read_to_string,
-	time::{Duration, Instant},
-};
+use std::collections::VecDeque;
+
+use utils::Solution;
 
 type Parsed = Vec<Vec<char>>;
 type Coord = (usize, usize);
@@ -152,8 +150,11 @@ pub mod part2 {
 	}
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-	let test_input = "RRRRIICCFF
+#[derive(Solution)]
+#[example(
+	part1 = 1930,
+	part2 = 1206,
+	input = "RRRRIICCFF
 RRRRIICCCF
 VVRRRCCFFF
 VVRCCCJFFF
@@ -164,39 +165,5 @@ MIIIIIJJEE
 MIIISIJEEE
 MMMISSJEEE
 "
-	.to_owned();
-	let puzzle_input = if test {
-		test_input
-	} else {
-		read_to_string("../inputs/2024/day_12_input.txt").unwrap()
-	};
-
-	let mut total = Duration::default();
-
-	let start = Instant::now();
-	let parsed = parse(&puzzle_input);
-	let elapsed = start.elapsed();
-	if verbose {
-		println!("Parsed in {:?}", elapsed);
-		total += elapsed;
-	}
-
-	let start = Instant::now();
-	let result = part1::solve(parsed.clone());
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("First part in {:?}", elapsed);
-	total += elapsed;
-
-	let start = Instant::now();
-	let result = part2::solve(parsed);
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("Second part in {:?}", elapsed);
-	total += elapsed;
-
-	if verbose {
-		println!("Total {:?}", total);
-	}
-	total
-}
+)]
+pub struct Day12;
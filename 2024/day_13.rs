@@ -1,12 +1,7 @@
 //! https://adventofcode.com/2024/day/13
 //! https://adventofcode.com/2024/day/13/input
 
-use std::{
-	fs::read_to_string,
-	time::{Duration, Instant},
-};
-
-use crate::LINE_ENDING;
+use utils::Solution;
 
 #[derive(Copy, Clone, Debug)]
 pub struct Machine {
@@ -38,7 +33,7 @@ impl From<&str> for Machine {
 type Parsed = Vec<Machine>;
 
 fn parse(input: &str) -> Parsed {
-	let sep = LINE_ENDING.repeat(2);
+	let sep = "\n".repeat(2);
 	input.split(&sep).map(Machine::from).collect()
 }
 
@@ -94,8 +89,11 @@ pub mod part2 {
 	}
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-	let test_input = "Button A: X+94, Y+34
+#[derive(Solution)]
+#[example(
+	part1 = 480,
+	part2 = 875318608908,
+	input = "Button A: X+94, Y+34
 Button B: X+22, Y+67
 Prize: X=8400, Y=5400
 
@@ -111,39 +109,5 @@ Button A: X+69, Y+23
 Button B: X+27, Y+71
 Prize: X=18641, Y=10279
 "
-	.to_owned();
-	let puzzle_input = if test {
-		test_input
-	} else {
-		read_to_string("../inputs/2024/day_13_input.txt").unwrap()
-	};
-
-	let mut total = Duration::default();
-
-	let start = Instant::now();
-	let parsed = parse(&puzzle_input);
-	let elapsed = start.elapsed();
-	if verbose {
-		println!("Parsed in {:?}", elapsed);
-		total += elapsed;
-	}
-
-	let start = Instant::now();
-	let result = part1::solve(parsed.clone());
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("First part in {:?}", elapsed);
-	total += elapsed;
-
-	let start = Instant::now();
-	let result = part2::solve(parsed);
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("Second part in {:?}", elapsed);
-	total += elapsed;
-
-	if verbose {
-		println!("Total {:?}", total);
-	}
-	total
-}
+)]
+pub struct Day13;
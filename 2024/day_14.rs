@@ -1,17 +1,12 @@
 //! https://adventofcode.com/2024/day/14
 //! https://adventofcode.com/2024/day/14/input
 
-use std::{
-	fs::read_to_string,
-	str::FromStr,
-	time::{Duration, Instant},
-};
-
-use utils::parsing::parse_lines;
+use utils::{errors::ParseError, parsing::try_parse_lines, FromStr, Solution};
 
 type Parsed = Vec<Robot>;
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, FromStr)]
+#[pattern("p={px},{py} v={vx},{vy}")]
 pub struct Robot {
 	px: isize,
 	py: isize,
@@ -19,22 +14,8 @@ pub struct Robot {
 	vy: isize,
 }
 
-impl FromStr for Robot {
-	type Err = ();
-
-	fn from_str(value: &str) -> Result<Self, Self::Err> {
-		let mut parts = value.split(['=', ' ', ',']);
-		Ok(Robot {
-			px: parts.nth(1).unwrap().parse().unwrap(),
-			py: parts.next().unwrap().parse().unwrap(),
-			vx: parts.nth(1).unwrap().parse().unwrap(),
-			vy: parts.next().unwrap().parse().unwrap(),
-		})
-	}
-}
-
-fn parse(input: &str) -> Parsed {
-	parse_lines(input)
+fn parse(input: &str) -> Result<Parsed, ParseError> {
+	try_parse_lines(input)
 }
 
 pub mod part1 {
@@ -95,8 +76,8 @@ pub mod part2 {
 	}
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-	let test_input = "p=0,4 v=3,-3
+#[derive(Solution)]
+#[example(input = "p=0,4 v=3,-3
 p=6,3 v=-1,-3
 p=10,3 v=-1,2
 p=2,0 v=2,-1
@@ -108,40 +89,5 @@ p=9,3 v=2,3
 p=7,3 v=-1,2
 p=2,4 v=2,-3
 p=9,5 v=-3,-3
-"
-	.to_owned();
-	let puzzle_input = if test {
-		test_input
-	} else {
-		read_to_string("../inputs/2024/day_14_input.txt").unwrap()
-	};
-
-	let mut total = Duration::default();
-
-	let start = Instant::now();
-	let parsed = parse(&puzzle_input);
-	let elapsed = start.elapsed();
-	if verbose {
-		println!("Parsed in {:?}", elapsed);
-		total += elapsed;
-	}
-
-	let start = Instant::now();
-	let result = part1::solve(parsed.clone());
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("First part in {:?}", elapsed);
-	total += elapsed;
-
-	let start = Instant::now();
-	let result = part2::solve(parsed);
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("Second part in {:?}", elapsed);
-	total += elapsed;
-
-	if verbose {
-		println!("Total {:?}", total);
-	}
-	total
-}
+")]
+pub struct Day14;
@@ -1,14 +1,7 @@
 //! https://adventofcode.com/2024/day/15
 //! https://adventofcode.com/2024/day/15/input
 
-use std::{
-	fs::read_to_string,
-	time::{Duration, Instant},
-};
-
-use utils::{from_char, parsing::parse_matrix};
-
-use crate::LINE_ENDING;
+use utils::{errors::ParseError, from_char, parsing::try_parse_matrix, Solution};
 
 type Coord = (usize, usize);
 type Map = Vec<Vec<Cell>>;
@@ -26,24 +19,23 @@ pub enum Cell {
 	Right = ']',
 }
 
-fn parse(input: &str) -> Parsed {
-	let sep = LINE_ENDING.repeat(2);
-	let mut parts = input.split(&sep);
-	let map = parse_matrix(parts.next().unwrap());
-	let directions = parts
-		.next()
-		.unwrap()
+fn parse(input: &str) -> Result<Parsed, ParseError> {
+	let (map, directions) = input
+		.split_once("\n\n")
+		.ok_or(ParseError::EndOfInput("directions"))?;
+	let map = try_parse_matrix(map)?;
+	let directions = directions
 		.split_whitespace()
 		.flat_map(&str::chars)
 		.collect();
 	for (i, row) in map.iter().enumerate() {
 		for (j, cell) in row.iter().enumerate() {
 			if let Cell::Robot = cell {
-				return (map, directions, (i, j));
+				return Ok((map, directions, (i, j)));
 			}
 		}
 	}
-	unreachable!()
+	Err(ParseError::Invalid("No robot in the map".to_owned()))
 }
 
 pub mod part1 {
@@ -56,11 +48,11 @@ pub mod part1 {
 			'>' => (0, 1),
 			_v => (1, 0),
 		};
-		i += di;
-		j += dj;
+		i = i.wrapping_add(di);
+		j = j.wrapping_add(dj);
 		while map[i][j] == Cell::Box {
-			i += di;
-			j += dj;
+			i = i.wrapping_add(di);
+			j = j.wrapping_add(dj);
 		}
 		(map[i][j] == Cell::Empty).then_some((i, j))
 	}
@@ -150,11 +142,11 @@ pub mod part2 {
 			'>' => (0, 1),
 			_ => unreachable!(),
 		};
-		i += di;
-		j += dj;
+		i = i.wrapping_add(di);
+		j = j.wrapping_add(dj);
 		while map[i][j] == Cell::Left || map[i][j] == Cell::Right {
-			i += di;
-			j += dj;
+			i = i.wrapping_add(di);
+			j = j.wrapping_add(dj);
 		}
 		(map[i][j] == Cell::Empty).then_some((i, j))
 	}
@@ -169,8 +161,8 @@ pub mod part2 {
 			'v' => (1, 0),
 			_ => unreachable!(),
 		};
-		i += di;
-		j += dj;
+		i = i.wrapping_add(di);
+		j = j.wrapping_add(dj);
 		if let Cell::Wall = map[i][j] {
 			return None;
 		}
@@ -187,7 +179,8 @@ pub mod part2 {
 			let mut next_lvl: HashSet<_> = current_lvl
 				.iter()
 				.filter_map(|&(i, j)| {
-					matches!(map[i][j], Cell::Left | Cell::Right).then_some((i + di, j + dj))
+					matches!(map[i][j], Cell::Left | Cell::Right)
+						.then_some((i.wrapping_add(di), j.wrapping_add(dj)))
 				})
 				.collect();
 			for (i, j) in next_lvl.clone() {
@@ -290,8 +283,11 @@ pub mod part2 {
 	}
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-	let test_input = "##########
+#[derive(Solution)]
+#[example(
+	part1 = 10092,
+	part2 = 9021,
+	input = "##########
 #..O..O.O#
 #......O.#
 #.OO..O.O#
@@ -313,62 +309,5 @@ vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
 ^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
 v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
 "
-	.to_owned();
-	// 	let test_input = "##########
-	// #..O..O.O#
-	// #......O.#
-	// #.OO..O.O#
-	// #..O@..O.#
-	// #O#..O...#
-	// #O..O..O.#
-	// #.OO.O.OO#
-	// #....O...#
-	// ##########\r
-	// \r
-	// <vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
-	// vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
-	// ><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
-	// <<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
-	// ^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
-	// ^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
-	// >^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
-	// <><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
-	// ^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
-	// v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
-	// "
-	// 	.to_owned();
-	let puzzle_input = if test {
-		test_input
-	} else {
-		read_to_string("../inputs/2024/day_15_input.txt").unwrap()
-	};
-
-	let mut total = Duration::default();
-
-	let start = Instant::now();
-	let parsed = parse(&puzzle_input);
-	let elapsed = start.elapsed();
-	if verbose {
-		println!("Parsed in {:?}", elapsed);
-		total += elapsed;
-	}
-
-	let start = Instant::now();
-	let result = part1::solve(parsed.clone());
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("First part in {:?}", elapsed);
-	total += elapsed;
-
-	let start = Instant::now();
-	let result = part2::solve(parsed);
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("Second part in {:?}", elapsed);
-	total += elapsed;
-
-	if verbose {
-		println!("Total {:?}", total);
-	}
-	total
-}
+)]
+pub struct Day15;
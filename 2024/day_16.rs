@@ -1,10 +1,7 @@
 //! https://adventofcode.com/2024/day/16
 //! https://adventofcode.com/2024/day/16/input
 
-use std::{
-	fs::read_to_string,
-	time::{Duration, Instant},
-};
+use utils::Solution;
 
 type Coord = (usize, usize);
 type Parsed = Vec<Vec<bool>>;
@@ -188,8 +185,11 @@ pub mod part2 {
 	}
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-	let test_input = "###############
+#[derive(Solution)]
+#[example(
+	part1 = 7036,
+	part2 = 45,
+	input = "###############
 #.......#....O#
 #.#.###.#.###O#
 #.....#.#...#O#
@@ -205,39 +205,5 @@ pub fn main(test: bool, verbose: bool) -> Duration {
 #O..#.....#OOO#
 ###############
 "
-	.to_owned();
-	let puzzle_input = if test {
-		test_input
-	} else {
-		read_to_string("../inputs/2024/day_16_input.txt").unwrap()
-	};
-
-	let mut total = Duration::default();
-
-	let start = Instant::now();
-	let parsed = parse(&puzzle_input);
-	let elapsed = start.elapsed();
-	if verbose {
-		println!("Parsed in {:?}", elapsed);
-		total += elapsed;
-	}
-
-	let start = Instant::now();
-	let result = part1::solve(parsed.clone());
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("First part in {:?}", elapsed);
-	total += elapsed;
-
-	let start = Instant::now();
-	let result = part2::solve(parsed);
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("Second part in {:?}", elapsed);
-	total += elapsed;
-
-	if verbose {
-		println!("Total {:?}", total);
-	}
-	total
-}
+)]
+pub struct Day16;
@@ -1,10 +1,7 @@
 //! https://adventofcode.com/2024/day/17
 //! https://adventofcode.com/2024/day/17/input
 
-use std::{
-	fs::read_to_string,
-	time::{Duration, Instant},
-};
+use utils::Solution;
 
 type Parsed = (u64, u64, u64, Vec<u64>);
 
@@ -160,46 +157,23 @@ pub mod part2 {
 	}
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-	let test_input = "Register A: 2024
+#[derive(Solution)]
+#[example(
+	part1 = "5,7,3,0",
+	part2 = 117440,
+	input = "Register A: 2024
 Register B: 0
 Register C: 0
 
 Program: 0,3,5,4,3,0
 "
-	.to_owned();
-	let puzzle_input = if test {
-		test_input
-	} else {
-		read_to_string("../inputs/2024/day_17_input.txt").unwrap()
-	};
-
-	let mut total = Duration::default();
-
-	let start = Instant::now();
-	let parsed = parse(&puzzle_input);
-	let elapsed = start.elapsed();
-	if verbose {
-		println!("Parsed in {:?}", elapsed);
-		total += elapsed;
-	}
-
-	let start = Instant::now();
-	let result = part1::solve(parsed.clone());
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("First part in {:?}", elapsed);
-	total += elapsed;
-
-	let start = Instant::now();
-	let result = part2::solve(parsed);
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("Second part in {:?}", elapsed);
-	total += elapsed;
+)]
+#[example(
+	part1 = "4,6,3,5,6,3,5,2,1,0",
+	input = "Register A: 729
+Register B: 0
+Register C: 0
 
-	if verbose {
-		println!("Total {:?}", total);
-	}
-	total
-}
+Program: 0,1,5,4,3,0"
+)]
+pub struct Day17;
@@ -1,11 +1,9 @@
 //! https://adventofcode.com/2024/day/18
 //! https://adventofcode.com/2024/day/18/input
 
-use std::{
-	collections::VecDeque,
-	fs::read_to_string,
-	time::{Duration, Instant},
-};
+use std::collections::VecDeque;
+
+use utils::Solution;
 
 type Coord = (usize, usize);
 type Parsed = Vec<Coord>;
@@ -79,8 +77,9 @@ pub mod part2 {
 	}
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-	let test_input = "5,4
+#[derive(Solution)]
+#[solution(part1 = part1::solve::<70>, part2 = part2::solve::<70>)]
+#[example(input = "5,4
 4,2
 4,5
 3,0
@@ -105,40 +104,5 @@ pub fn main(test: bool, verbose: bool) -> Duration {
 0,5
 1,6
 2,0
-"
-	.to_owned();
-	let puzzle_input = if test {
-		test_input
-	} else {
-		read_to_string("../inputs/2024/day_18_input.txt").unwrap()
-	};
-
-	let mut total = Duration::default();
-
-	let start = Instant::now();
-	let parsed = parse(&puzzle_input);
-	let elapsed = start.elapsed();
-	if verbose {
-		println!("Parsed in {:?}", elapsed);
-		total += elapsed;
-	}
-
-	let start = Instant::now();
-	let result = part1::solve::<70>(parsed.clone());
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("First part in {:?}", elapsed);
-	total += elapsed;
-
-	let start = Instant::now();
-	let result = part2::solve::<70>(parsed);
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("Second part in {:?}", elapsed);
-	total += elapsed;
-
-	if verbose {
-		println!("Total {:?}", total);
-	}
-	total
-}
+")]
+pub struct Day18;
@@ -1,17 +1,12 @@
 //! https://adventofcode.com/2024/day/19
 //! https://adventofcode.com/2024/day/19/input
 
-use std::{
-	fs::read_to_string,
-	time::{Duration, Instant},
-};
-
-use crate::LINE_ENDING;
+use utils::Solution;
 
 type Parsed = ([Vec<Vec<usize>>; 26], Vec<Vec<usize>>);
 
 fn parse(input: &str) -> Parsed {
-	let sep = LINE_ENDING.repeat(2);
+	let sep = "\n".repeat(2);
 	let mut parts = input.split(&sep);
 	let patterns = parts.next().unwrap();
 	let designs = parts
@@ -88,8 +83,11 @@ pub mod part2 {
 	}
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-	let test_input = "r, wr, b, g, bwu, rb, gb, br\r
+#[derive(Solution)]
+#[example(
+	part1 = 6,
+	part2 = 16,
+	input = "r, wr, b, g, bwu, rb, gb, br\r
 \r
 brwrr
 bggr
@@ -100,39 +98,5 @@ bwurrg
 brgr
 bbrgwb
 "
-	.to_owned();
-	let puzzle_input = if test {
-		test_input
-	} else {
-		read_to_string("../inputs/2024/day_19_input.txt").unwrap()
-	};
-
-	let mut total = Duration::default();
-
-	let start = Instant::now();
-	let parsed = parse(&puzzle_input);
-	let elapsed = start.elapsed();
-	if verbose {
-		println!("Parsed in {:?}", elapsed);
-		total += elapsed;
-	}
-
-	let start = Instant::now();
-	let result = part1::solve(parsed.clone());
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("First part in {:?}", elapsed);
-	total += elapsed;
-
-	let start = Instant::now();
-	let result = part2::solve(parsed);
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("Second part in {:?}", elapsed);
-	total += elapsed;
-
-	if verbose {
-		println!("Total {:?}", total);
-	}
-	total
-}
+)]
+pub struct Day19;
@@ -1,12 +1,7 @@
 //! https://adventofcode.com/2024/day/20
 //! https://adventofcode.com/2024/day/20/input
 
-use std::{
-	fs::read_to_string,
-	time::{Duration, Instant},
-};
-
-use utils::coords::u_iter_cross_near;
+use utils::{coords::u_iter_cross_near, Solution};
 
 type Coord = (usize, usize);
 type Parsed = Vec<Vec<usize>>;
@@ -137,8 +132,8 @@ pub mod part2 {
 	}
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-	let test_input = "###############
+#[derive(Solution)]
+#[example(input = "###############
 #...#...#.....#
 #.#.#.#.#.###.#
 #S#...#.#.#...#
@@ -153,40 +148,5 @@ pub fn main(test: bool, verbose: bool) -> Duration {
 #.#.#.#.#.#.###
 #...#...#...###
 ###############
-"
-	.to_owned();
-	let puzzle_input = if test {
-		test_input
-	} else {
-		read_to_string("../inputs/2024/day_20_input.txt").unwrap()
-	};
-
-	let mut total = Duration::default();
-
-	let start = Instant::now();
-	let parsed = parse(&puzzle_input);
-	let elapsed = start.elapsed();
-	if verbose {
-		println!("Parsed in {:?}", elapsed);
-		total += elapsed;
-	}
-
-	let start = Instant::now();
-	let result = part1::solve(parsed.clone());
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("First part in {:?}", elapsed);
-	total += elapsed;
-
-	let start = Instant::now();
-	let result = part2::solve(parsed);
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("Second part in {:?}", elapsed);
-	total += elapsed;
-
-	if verbose {
-		println!("Total {:?}", total);
-	}
-	total
-}
+")]
+pub struct Day20;
@@ -1,13 +1,11 @@
 //! https://adventofcode.com/2024/day/21
 //! https://adventofcode.com/2024/day/21/input
 
-use std::{
-	collections::HashMap,
-	fs::read_to_string,
-	time::{Duration, Instant},
-};
+use std::collections::HashMap;
 
-type Parsed<'a> = [[usize; 4]; 5];
+use utils::Solution;
+
+type Parsed = [[usize; 4]; 5];
 
 fn parse(input: &str) -> Parsed {
 	core::array::from_fn(|i| {
@@ -285,46 +283,15 @@ pub mod part2 {
 	}
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-	let test_input = "029A
+#[derive(Solution)]
+#[example(
+	part1 = 126384,
+	part2 = 154115708116294,
+	input = "029A
 980A
 179A
 456A
 379A
 "
-	.to_owned();
-	let puzzle_input = if test {
-		test_input
-	} else {
-		read_to_string("../inputs/2024/day_21_input.txt").unwrap()
-	};
-
-	let mut total = Duration::default();
-
-	let start = Instant::now();
-	let parsed = parse(&puzzle_input);
-	let elapsed = start.elapsed();
-	if verbose {
-		println!("Parsed in {:?}", elapsed);
-		total += elapsed;
-	}
-
-	let start = Instant::now();
-	let result = part1::solve(parsed);
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("First part in {:?}", elapsed);
-	total += elapsed;
-
-	let start = Instant::now();
-	let result = part2::solve(parsed);
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("Second part in {:?}", elapsed);
-	total += elapsed;
-
-	if verbose {
-		println!("Total {:?}", total);
-	}
-	total
-}
+)]
+pub struct Day21;
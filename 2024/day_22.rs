@@ -1,17 +1,12 @@
 //! https://adventofcode.com/2024/day/22
 //! https://adventofcode.com/2024/day/22/input
 
-use std::{
-	fs::read_to_string,
-	time::{Duration, Instant},
-};
-
-use utils::parsing::parse_lines;
+use utils::{errors::ParseError, parsing::try_parse_lines, Solution};
 
 type Parsed = Vec<u64>;
 
-fn parse(input: &str) -> Parsed {
-	parse_lines(input)
+fn parse(input: &str) -> Result<Parsed, ParseError> {
+	try_parse_lines(input)
 }
 
 pub mod part1 {
@@ -108,45 +103,14 @@ pub mod part2 {
 	}
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-	let test_input = "1
+#[derive(Solution)]
+#[example(
+	part1 = 37990510,
+	part2 = 23,
+	input = "1
 2
 3
 2024
 "
-	.to_owned();
-	let puzzle_input = if test {
-		test_input
-	} else {
-		read_to_string("../inputs/2024/day_22_input.txt").unwrap()
-	};
-
-	let mut total = Duration::default();
-
-	let start = Instant::now();
-	let parsed = parse(&puzzle_input);
-	let elapsed = start.elapsed();
-	if verbose {
-		println!("Parsed in {:?}", elapsed);
-		total += elapsed;
-	}
-
-	let start = Instant::now();
-	let result = part1::solve(parsed.clone());
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("First part in {:?}", elapsed);
-	total += elapsed;
-
-	let start = Instant::now();
-	let result = part2::solve(parsed);
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("Second part in {:?}", elapsed);
-	total += elapsed;
-
-	if verbose {
-		println!("Total {:?}", total);
-	}
-	total
-}
+)]
+pub struct Day22;
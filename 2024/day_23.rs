@@ -1,12 +1,7 @@
 //! https://adventofcode.com/2024/day/23
 //! https://adventofcode.com/2024/day/23/input
 
-use std::{
-	fs::read_to_string,
-	time::{Duration, Instant},
-};
-
-use utils::parsing::parse_alpha;
+use utils::{parsing::parse_alpha, Solution};
 
 type Parsed = Vec<Vec<usize>>;
 
@@ -128,8 +123,11 @@ pub mod part2 {
 	}
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-	let test_input = "kh-tc
+#[derive(Solution)]
+#[example(
+	part1 = 7,
+	part2 = "co,de,ka,ta",
+	input = "kh-tc
 qp-kh
 de-cg
 ka-co
@@ -162,39 +160,5 @@ wh-qp
 tb-vc
 td-yn
 "
-	.to_owned();
-	let puzzle_input = if test {
-		test_input
-	} else {
-		read_to_string("../inputs/2024/day_23_input.txt").unwrap()
-	};
-
-	let mut total = Duration::default();
-
-	let start = Instant::now();
-	let parsed = parse(&puzzle_input);
-	let elapsed = start.elapsed();
-	if verbose {
-		println!("Parsed in {:?}", elapsed);
-		total += elapsed;
-	}
-
-	let start = Instant::now();
-	let result = part1::solve(parsed.clone());
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("First part in {:?}", elapsed);
-	total += elapsed;
-
-	let start = Instant::now();
-	let result = part2::solve(parsed);
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("Second part in {:?}", elapsed);
-	total += elapsed;
-
-	if verbose {
-		println!("Total {:?}", total);
-	}
-	total
-}
+)]
+pub struct Day23;
@@ -1,13 +1,9 @@
 //! https://adventofcode.com/2024/day/24
 //! https://adventofcode.com/2024/day/24/input
 
-use std::{
-	collections::{hash_map::Entry, HashMap},
-	fs::read_to_string,
-	time::{Duration, Instant},
-};
+use std::collections::{hash_map::Entry, HashMap};
 
-use crate::LINE_ENDING;
+use utils::Solution;
 
 #[derive(Copy, Clone, Debug)]
 pub enum Value {
@@ -18,16 +14,16 @@ pub enum Value {
 	Invalid,
 }
 
-#[derive(Copy, Clone, Debug)]
-pub struct Gate<'a> {
-	name: &'a str,
+#[derive(Clone, Debug)]
+pub struct Gate {
+	name: String,
 	value: Value,
 }
 
-type Parsed<'a> = (Vec<Gate<'a>>, HashMap<&'a str, usize>);
+type Parsed = (Vec<Gate>, HashMap<String, usize>);
 
 fn parse(input: &str) -> Parsed {
-	let sep = LINE_ENDING.repeat(2);
+	let sep = "\n".repeat(2);
 	let mut parts = input.split(&sep);
 	let [values, connections] = core::array::from_fn(|_| parts.next().unwrap());
 	let mut gates = Vec::with_capacity(values.len());
@@ -36,9 +32,9 @@ fn parse(input: &str) -> Parsed {
 		let mut parts = gate.split(": ");
 		let [name, value] = core::array::from_fn(|_| parts.next().unwrap());
 		let index = gates.len();
-		name_to_index.insert(name, index);
+		name_to_index.insert(name.to_owned(), index);
 		gates.push(Gate {
-			name,
+			name: name.to_owned(),
 			value: Value::Val(value.starts_with('1')),
 		});
 		// gates.push(Gate::Val(value.starts_with('1')));
@@ -46,25 +42,25 @@ fn parse(input: &str) -> Parsed {
 	for gate in connections.lines() {
 		let mut parts = gate.split_whitespace();
 		let [left, op, right, _, name] = core::array::from_fn(|_| parts.next().unwrap());
-		let left_index = match name_to_index.entry(left) {
+		let left_index = match name_to_index.entry(left.to_owned()) {
 			Entry::Occupied(index) => *index.get(),
 			Entry::Vacant(entry) => {
 				let index = gates.len();
 				entry.insert(index);
 				gates.push(Gate {
-					name,
+					name: name.to_owned(),
 					value: Value::Invalid,
 				});
 				index
 			}
 		};
-		let right_index = match name_to_index.entry(right) {
+		let right_index = match name_to_index.entry(right.to_owned()) {
 			Entry::Occupied(index) => *index.get(),
 			Entry::Vacant(entry) => {
 				let index = gates.len();
 				entry.insert(index);
 				gates.push(Gate {
-					name,
+					name: name.to_owned(),
 					value: Value::Invalid,
 				});
 				index
@@ -72,19 +68,19 @@ fn parse(input: &str) -> Parsed {
 		};
 		let gate = match op.chars().next().unwrap() {
 			'A' => Gate {
-				name,
+				name: name.to_owned(),
 				value: Value::And(left_index, right_index),
 			},
 			'O' => Gate {
-				name,
+				name: name.to_owned(),
 				value: Value::Or(left_index, right_index),
 			},
 			_x => Gate {
-				name,
+				name: name.to_owned(),
 				value: Value::Xor(left_index, right_index),
 			},
 		};
-		match name_to_index.entry(name) {
+		match name_to_index.entry(name.to_owned()) {
 			Entry::Occupied(index) => {
 				let &index = index.get();
 				gates[index] = gate;
@@ -101,7 +97,7 @@ fn parse(input: &str) -> Parsed {
 	(gates, name_to_index)
 }
 
-fn extract(name_to_index: &HashMap<&str, usize>, start: char) -> Vec<usize> {
+fn extract(name_to_index: &HashMap<String, usize>, start: char) -> Vec<usize> {
 	let mut gates: Vec<_> = name_to_index
 		.iter()
 		.filter(|(name, _)| name.starts_with(start))
@@ -200,7 +196,7 @@ pub mod part1 {
 }
 
 pub mod part2 {
-	use std::fs;
+	use std::{env, fs};
 
 	use super::{extract, Parsed, Value};
 
@@ -215,14 +211,14 @@ pub mod part2 {
 		for gate in extract(&name_to_index, 'x')
 			.into_iter()
 			.chain(extract(&name_to_index, 'y'))
-			.map(|n| gates[n])
+			.map(|n| &gates[n])
 		{
 			graph.push_str(&format!("{} [color=gold];\n", gate.name));
 		}
 		graph.push_str("};\n");
 		graph.push_str("{\n");
 		graph.push_str("rank=sink;\n");
-		for gate in extract(&name_to_index, 'z').into_iter().map(|n| gates[n]) {
+		for gate in extract(&name_to_index, 'z').into_iter().map(|n| &gates[n]) {
 			graph.push_str(&format!("{} [color=lightblue];\n", gate.name));
 		}
 		graph.push_str("};\n");
@@ -246,14 +242,36 @@ pub mod part2 {
 			graph.push_str(&format!("{} -> {};\n", gates[right].name, gate.name));
 		}
 		graph.push_str("}\n");
-		fs::write("graph.dot", graph).unwrap();
-		"dot -Tpdf graph.dot -o graph.pdf".to_owned()
+		// in the temporary directory, since the runner may be started from anywhere
+		let path = env::temp_dir().join("2024_day_24.dot");
+		fs::write(&path, graph).unwrap();
+		format!("dot -Tpdf {} -o graph.pdf", path.display())
 	}
 }
 
 #[allow(unused)]
-pub fn main(test: bool, verbose: bool) -> Duration {
-	let test_input = "x00: 1
+#[derive(Solution)]
+#[example(input = "x00: 0
+x01: 1
+x02: 0
+x03: 1
+x04: 0
+x05: 1
+y00: 0
+y01: 0
+y02: 1
+y03: 1
+y04: 0
+y05: 1\r
+\r
+x00 AND y00 -> z05
+x01 AND y01 -> z02
+x02 AND y02 -> z01
+x03 AND y03 -> z03
+x04 AND y04 -> z04
+x05 AND y05 -> z00
+")]
+#[example(input = "x00: 1
 x01: 1
 x02: 1
 y00: 0
@@ -263,9 +281,10 @@ y02: 0\r
 x00 AND y00 -> z00
 x01 XOR y01 -> z01
 x02 OR y02 -> z02
-"
-	.to_owned();
-	let test_input = "x00: 1
+")]
+#[example(
+	part1 = 2024,
+	input = "x00: 1
 x01: 0
 x02: 1
 x03: 1
@@ -313,60 +332,5 @@ hwm AND bqk -> z03
 tgd XOR rvg -> z12
 tnw OR pbm -> gnj
 "
-	.to_owned();
-	let test_input = "x00: 0
-x01: 1
-x02: 0
-x03: 1
-x04: 0
-x05: 1
-y00: 0
-y01: 0
-y02: 1
-y03: 1
-y04: 0
-y05: 1\r
-\r
-x00 AND y00 -> z05
-x01 AND y01 -> z02
-x02 AND y02 -> z01
-x03 AND y03 -> z03
-x04 AND y04 -> z04
-x05 AND y05 -> z00
-"
-	.to_owned();
-	let puzzle_input = if test {
-		test_input
-	} else {
-		read_to_string("../inputs/2024/day_24_input.txt").unwrap()
-	};
-
-	let mut total = Duration::default();
-
-	let start = Instant::now();
-	let parsed = parse(&puzzle_input);
-	let elapsed = start.elapsed();
-	if verbose {
-		println!("Parsed in {:?}", elapsed);
-		total += elapsed;
-	}
-
-	let start = Instant::now();
-	let result = part1::solve(parsed.clone());
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("First part in {:?}", elapsed);
-	total += elapsed;
-
-	let start = Instant::now();
-	let result = part2::solve(parsed);
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("Second part in {:?}", elapsed);
-	total += elapsed;
-
-	if verbose {
-		println!("Total {:?}", total);
-	}
-	total
-}
+)]
+pub struct Day24;
@@ -1,12 +1,7 @@
 //! https://adventofcode.com/2024/day/25
 //! https://adventofcode.com/2024/day/25/input
 
-use std::{
-	fs::read_to_string,
-	time::{Duration, Instant},
-};
-
-use crate::LINE_ENDING;
+use utils::Solution;
 
 type Parsed = (Vec<u64>, Vec<u64>);
 
@@ -15,7 +10,7 @@ fn parse(input: &str) -> Parsed {
 		Vec::with_capacity(input.lines().count() / 2),
 		Vec::with_capacity(input.lines().count() / 2),
 	);
-	let sep = LINE_ENDING.repeat(2);
+	let sep = "\n".repeat(2);
 	for schematic in input.split(&sep) {
 		let mut bits = 0;
 		for char in schematic.lines().flat_map(|l| l.chars()) {
@@ -49,8 +44,11 @@ pub mod part1 {
 	}
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-	let test_input = "#####
+#[derive(Solution)]
+#[solution(single_part)]
+#[example(
+	part1 = 3,
+	input = "#####
 .####
 .####
 .####
@@ -90,32 +88,5 @@ pub fn main(test: bool, verbose: bool) -> Duration {
 #.#.#
 #####
 "
-	.to_owned();
-	let puzzle_input = if test {
-		test_input
-	} else {
-		read_to_string("../inputs/2024/day_25_input.txt").unwrap()
-	};
-
-	let mut total = Duration::default();
-
-	let start = Instant::now();
-	let parsed = parse(&puzzle_input);
-	let elapsed = start.elapsed();
-	if verbose {
-		println!("Parsed in {:?}", elapsed);
-		total += elapsed;
-	}
-
-	let start = Instant::now();
-	let result = part1::solve(parsed.clone());
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("First part in {:?}", elapsed);
-	total += elapsed;
-
-	if verbose {
-		println!("Total {:?}", total);
-	}
-	total
-}
+)]
+pub struct Day25;
@@ -1,34 +1,5 @@
-use std::{env, time::Duration};
-
 use utils::run_days;
 
 fn main() {
-	run_days!(
-		advent_of_code_2024,
-		day_01, /**/
-		day_02, /**/
-		day_03, /**/
-		day_04, /**/
-		day_05, /**/
-		day_06, /**/
-		day_07, /**/
-		day_08, /**/
-		day_09, /**/
-		day_10, /**/
-		day_11, /**/
-		day_12, /**/
-		day_13, /**/
-		day_14, /**/
-		day_15, /**/
-		day_16, /**/
-		day_17, /**/
-		day_18, /**/
-		day_19, /**/
-		day_20, /**/
-		day_21, /**/
-		day_22, /**/
-		day_23, /**/
-		day_24, /**/
-		day_25, /**/
-	);
+	run_days!(advent_of_code_2024);
 }
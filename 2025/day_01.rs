@@ -1,36 +1,20 @@
 //! https://adventofcode.com/2025/day/1
 //! https://adventofcode.com/2025/day/1/input
 
-use std::{
-	fs::read_to_string,
-	str::FromStr,
-	time::{Duration, Instant},
-};
+use utils::{errors::ParseError, parsing::try_parse_lines, FromStr, Solution};
 
-use utils::parsing::parse_lines;
-
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, FromStr)]
 pub enum Rotation {
+	#[pattern("R{0}")]
 	R(usize),
+	#[pattern("L{0}")]
 	L(usize),
 }
 
-impl FromStr for Rotation {
-	type Err = ();
-
-	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		match s.chars().next().unwrap() {
-			'R' => Ok(Self::R(s[1..].parse().unwrap())),
-			'L' => Ok(Self::L(s[1..].parse().unwrap())),
-			_ => Err(()),
-		}
-	}
-}
-
 type Parsed = Vec<Rotation>;
 
-fn parse(input: &str) -> Parsed {
-	parse_lines(input)
+fn parse(input: &str) -> Result<Parsed, ParseError> {
+	try_parse_lines(input)
 }
 
 pub mod part1 {
@@ -80,40 +64,19 @@ pub mod part2 {
 	}
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-	let test_input = "".to_owned();
-	let puzzle_input = if test {
-		test_input
-	} else {
-		read_to_string("../inputs/2025/day_01_input.txt").unwrap()
-	};
-
-	let mut total = Duration::default();
-
-	let start = Instant::now();
-	let parsed = parse(&puzzle_input);
-	let elapsed = start.elapsed();
-	if verbose {
-		println!("Parsed in {:?}", elapsed);
-		total += elapsed;
-	}
-
-	let start = Instant::now();
-	let result = part1::solve(parsed.clone());
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("First part in {:?}", elapsed);
-	total += elapsed;
-
-	let start = Instant::now();
-	let result = part2::solve(parsed);
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("Second part in {:?}", elapsed);
-	total += elapsed;
-
-	if verbose {
-		println!("Total {:?}", total);
-	}
-	total
-}
+#[derive(Solution)]
+#[example(
+	part1 = 3,
+	part2 = 6,
+	input = "L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82"
+)]
+pub struct Day01;
@@ -1,17 +1,15 @@
 //! https://adventofcode.com/2025/day/5
 //! https://adventofcode.com/2025/day/5/input
 
-use std::{
-	fs::read_to_string,
-	time::{Duration, Instant},
+use utils::{
+	generate::{Rng, Seeded},
+	Solution,
 };
 
-use crate::LINE_ENDING;
-
 type Parsed = (Vec<(usize, usize)>, Vec<usize>);
 
 fn parse(input: &str) -> Parsed {
-	let separator = &LINE_ENDING.repeat(2);
+	let separator = &"\n".repeat(2);
 	let (ranges, ids) = input.split_once(separator).unwrap();
 	let mut ranges: Vec<(usize, usize)> = ranges
 		.lines()
@@ -31,6 +29,34 @@ fn parse(input: &str) -> Parsed {
 	(ranges, ids.lines().map(|l| l.parse().unwrap()).collect())
 }
 
+/// `size` ingredients, about half of them fresh, and a fifth as many ranges.
+fn generate(rng: &mut Seeded, size: usize) -> String {
+	const MAX: usize = 10_usize.pow(15);
+	let ranges: Vec<(usize, usize)> = (0..(size / 5).max(1))
+		.map(|_| {
+			let left = rng.gen_range(1..MAX);
+			(left, left + rng.gen_range(0..MAX / 1000))
+		})
+		.collect();
+	let ids = (0..size).map(|_| {
+		if rng.gen() {
+			let (left, right) = ranges[rng.gen_range(0..ranges.len())];
+			rng.gen_range(left..=right)
+		} else {
+			rng.gen_range(1..MAX)
+		}
+	});
+	let ranges = ranges
+		.iter()
+		.map(|(left, right)| format!("{}-{}", left, right));
+	let ids = ids.map(|id| id.to_string());
+	format!(
+		"{}\n\n{}",
+		ranges.collect::<Vec<_>>().join("\n"),
+		ids.collect::<Vec<_>>().join("\n")
+	)
+}
+
 pub mod part1 {
 	use super::Parsed;
 
@@ -60,8 +86,12 @@ pub mod part2 {
 	}
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-	let test_input = "3-5\r
+#[derive(Solution)]
+#[solution(generator = generate)]
+#[example(
+	part1 = 3,
+	part2 = 14,
+	input = "3-5\r
 10-14\r
 16-20\r
 12-18\r
@@ -72,39 +102,5 @@ pub fn main(test: bool, verbose: bool) -> Duration {
 11\r
 17\r
 32"
-	.to_owned();
-	let puzzle_input = if test {
-		test_input
-	} else {
-		read_to_string("../inputs/2025/day_05_input.txt").unwrap()
-	};
-
-	let mut total = Duration::default();
-
-	let start = Instant::now();
-	let parsed = parse(&puzzle_input);
-	let elapsed = start.elapsed();
-	if verbose {
-		println!("Parsed in {:?}", elapsed);
-		total += elapsed;
-	}
-
-	let start = Instant::now();
-	let result = part1::solve(parsed.clone());
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("First part in {:?}", elapsed);
-	total += elapsed;
-
-	let start = Instant::now();
-	let result = part2::solve(parsed);
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("Second part in {:?}", elapsed);
-	total += elapsed;
-
-	if verbose {
-		println!("Total {:?}", total);
-	}
-	total
-}
+)]
+pub struct Day05;
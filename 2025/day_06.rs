@@ -1,10 +1,7 @@
 //! https://adventofcode.com/2025/day/6
 //! https://adventofcode.com/2025/day/6/input
 
-use std::{
-	fs::read_to_string,
-	time::{Duration, Instant},
-};
+use utils::Solution;
 
 type Parsed = (Vec<Vec<usize>>, Vec<bool>);
 
@@ -91,45 +88,20 @@ pub mod part2 {
 	}
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-	let test_input = "123 328  51 64
+#[derive(Solution)]
+#[solution(
+	parsed = (Parsed, Parsed),
+	parse = |input| (parse(input), part2::parse(input)),
+	part1 = |(parsed, _)| part1::solve(parsed),
+	part2 = |(_, parsed)| part2::solve(parsed),
+)]
+#[example(
+	part1 = 4277556,
+	part2 = 3263827,
+	input = "123 328  51 64
  45 64  387 23
   6 98  215 314
 *   +   *   +
 "
-	.to_owned();
-	let puzzle_input = if test {
-		test_input
-	} else {
-		read_to_string("../inputs/2025/day_06_input.txt").unwrap()
-	};
-
-	let mut total = Duration::default();
-
-	let start = Instant::now();
-	let parsed = parse(&puzzle_input);
-	let elapsed = start.elapsed();
-	if verbose {
-		println!("Parsed in {:?}", elapsed);
-		total += elapsed;
-	}
-
-	let start = Instant::now();
-	let result = part1::solve(parsed.clone());
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("First part in {:?}", elapsed);
-	total += elapsed;
-
-	let start = Instant::now();
-	let result = part2::solve(part2::parse(&puzzle_input));
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("Second part in {:?}", elapsed);
-	total += elapsed;
-
-	if verbose {
-		println!("Total {:?}", total);
-	}
-	total
-}
+)]
+pub struct Day06;
@@ -1,12 +1,7 @@
 //! https://adventofcode.com/2025/day/7
 //! https://adventofcode.com/2025/day/7/input
 
-use std::{
-	fs::read_to_string,
-	time::{Duration, Instant},
-};
-
-use utils::parsing::parse_matrix;
+use utils::{parsing::parse_matrix, Solution};
 
 type Parsed = (Vec<Vec<Tile>>, usize);
 
@@ -88,8 +83,11 @@ pub mod part2 {
 	}
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-	let test_input = ".......S.......
+#[derive(Solution)]
+#[example(
+	part1 = 21,
+	part2 = 40,
+	input = ".......S.......
 ...............
 .......^.......
 ...............
@@ -106,39 +104,5 @@ pub fn main(test: bool, verbose: bool) -> Duration {
 .^.^.^.^.^...^.
 ...............
 "
-	.to_owned();
-	let puzzle_input = if test {
-		test_input
-	} else {
-		read_to_string("../inputs/2025/day_07_input.txt").unwrap()
-	};
-
-	let mut total = Duration::default();
-
-	let start = Instant::now();
-	let parsed = parse(&puzzle_input);
-	let elapsed = start.elapsed();
-	if verbose {
-		println!("Parsed in {:?}", elapsed);
-		total += elapsed;
-	}
-
-	let start = Instant::now();
-	let result = part1::solve(parsed.clone());
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("First part in {:?}", elapsed);
-	total += elapsed;
-
-	let start = Instant::now();
-	let result = part2::solve(parsed);
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("Second part in {:?}", elapsed);
-	total += elapsed;
-
-	if verbose {
-		println!("Total {:?}", total);
-	}
-	total
-}
+)]
+pub struct Day07;
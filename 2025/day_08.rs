@@ -1,38 +1,20 @@
 //! https://adventofcode.com/2025/day/8
 //! https://adventofcode.com/2025/day/8/input
 
-use std::{
-	fs::read_to_string,
-	str::FromStr,
-	time::{Duration, Instant},
-};
-
-use utils::parsing::parse_lines;
+use utils::{errors::ParseError, parsing::try_parse_lines, FromStr, Solution};
 
 type Parsed = Vec<Box>;
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, FromStr)]
+#[separator(',')]
 pub struct Box {
 	x: usize,
 	y: usize,
 	z: usize,
 }
 
-impl FromStr for Box {
-	type Err = ();
-
-	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		let mut parts = s.splitn(3, ',');
-		Ok(Self {
-			x: parts.next().unwrap().parse().unwrap(),
-			y: parts.next().unwrap().parse().unwrap(),
-			z: parts.next().unwrap().parse().unwrap(),
-		})
-	}
-}
-
-fn parse(input: &str) -> Parsed {
-	parse_lines(input)
+fn parse(input: &str) -> Result<Parsed, ParseError> {
+	try_parse_lines(input)
 }
 
 pub mod part1 {
@@ -107,8 +89,17 @@ pub mod part2 {
 	}
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-	let test_input = "162,817,812
+#[derive(Solution)]
+#[solution(
+	params = 1000,
+	parse = |input, _| parse(input),
+	part2 = |boxes, _| part2::solve(boxes)
+)]
+#[example(
+	params = 10,
+	part1 = 40,
+	part2 = 25272,
+	input = "162,817,812
 57,618,57
 906,360,560
 592,479,940
@@ -129,42 +120,5 @@ pub fn main(test: bool, verbose: bool) -> Duration {
 984,92,344
 425,690,689
 "
-	.to_owned();
-	let (puzzle_input, iterations) = if test {
-		(test_input, 10)
-	} else {
-		(
-			read_to_string("../inputs/2025/day_08_input.txt").unwrap(),
-			1000,
-		)
-	};
-
-	let mut total = Duration::default();
-
-	let start = Instant::now();
-	let parsed = parse(&puzzle_input);
-	let elapsed = start.elapsed();
-	if verbose {
-		println!("Parsed in {:?}", elapsed);
-		total += elapsed;
-	}
-
-	let start = Instant::now();
-	let result = part1::solve(parsed.clone(), iterations);
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("First part in {:?}", elapsed);
-	total += elapsed;
-
-	let start = Instant::now();
-	let result = part2::solve(parsed);
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("Second part in {:?}", elapsed);
-	total += elapsed;
-
-	if verbose {
-		println!("Total {:?}", total);
-	}
-	total
-}
+)]
+pub struct Day08;
@@ -1,36 +1,19 @@
 //! https://adventofcode.com/2025/day/9
 //! https://adventofcode.com/2025/day/9/input
 
-use std::{
-	fs::read_to_string,
-	str::FromStr,
-	time::{Duration, Instant},
-};
-
-use utils::parsing::parse_lines;
+use utils::{errors::ParseError, parsing::try_parse_lines, FromStr, Solution};
 
 type Parsed = Vec<Point>;
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, FromStr)]
+#[separator(',')]
 pub struct Point {
 	x: isize,
 	y: isize,
 }
 
-impl FromStr for Point {
-	type Err = ();
-
-	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		let (x, y) = s.split_once(',').unwrap();
-		Ok(Self {
-			x: x.parse().unwrap(),
-			y: y.parse().unwrap(),
-		})
-	}
-}
-
-fn parse(input: &str) -> Parsed {
-	parse_lines(input)
+fn parse(input: &str) -> Result<Parsed, ParseError> {
+	try_parse_lines(input)
 }
 
 pub mod part1 {
@@ -128,8 +111,10 @@ pub mod part2 {
 	}
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-	let test_input = "7,1
+#[derive(Solution)]
+#[example(
+	part1 = 50,
+	input = "7,1
 11,1
 11,7
 9,7
@@ -138,39 +123,5 @@ pub fn main(test: bool, verbose: bool) -> Duration {
 2,3
 7,3
 "
-	.to_owned();
-	let puzzle_input = if test {
-		test_input
-	} else {
-		read_to_string("../inputs/2025/day_09_input.txt").unwrap()
-	};
-
-	let mut total = Duration::default();
-
-	let start = Instant::now();
-	let parsed = parse(&puzzle_input);
-	let elapsed = start.elapsed();
-	if verbose {
-		println!("Parsed in {:?}", elapsed);
-		total += elapsed;
-	}
-
-	let start = Instant::now();
-	let result = part1::solve(parsed.clone());
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("First part in {:?}", elapsed);
-	total += elapsed;
-
-	let start = Instant::now();
-	let result = part2::solve(parsed);
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("Second part in {:?}", elapsed);
-	total += elapsed;
-
-	if verbose {
-		println!("Total {:?}", total);
-	}
-	total
-}
+)]
+pub struct Day09;
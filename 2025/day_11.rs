@@ -1,11 +1,9 @@
 //! https://adventofcode.com/2025/day/11
 //! https://adventofcode.com/2025/day/11/input
 
-use std::{
-	collections::{hash_map::Entry, HashMap},
-	fs::read_to_string,
-	time::{Duration, Instant},
-};
+use std::collections::{hash_map::Entry, HashMap};
+
+use utils::Solution;
 
 type Parsed = (Vec<Vec<usize>>, usize, usize, usize, usize, usize);
 
@@ -141,8 +139,10 @@ pub mod part2 {
 	}
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-	let test_input = "svr: aaa bbb
+#[derive(Solution)]
+#[example(
+	part2 = 2,
+	input = "svr: aaa bbb
 aaa: fft
 fft: ccc
 bbb: tty
@@ -156,39 +156,5 @@ fff: ggg hhh
 ggg: out
 hhh: out
 "
-	.to_owned();
-	let puzzle_input = if test {
-		test_input
-	} else {
-		read_to_string("../inputs/2025/day_11_input.txt").unwrap()
-	};
-
-	let mut total = Duration::default();
-
-	let start = Instant::now();
-	let parsed = parse(&puzzle_input);
-	let elapsed = start.elapsed();
-	if verbose {
-		println!("Parsed in {:?}", elapsed);
-		total += elapsed;
-	}
-
-	let start = Instant::now();
-	let result = part1::solve(parsed.clone());
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("First part in {:?}", elapsed);
-	total += elapsed;
-
-	let start = Instant::now();
-	let result = part2::solve(parsed);
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("Second part in {:?}", elapsed);
-	total += elapsed;
-
-	if verbose {
-		println!("Total {:?}", total);
-	}
-	total
-}
+)]
+pub struct Day11;
@@ -1,12 +1,7 @@
 //! https://adventofcode.com/2025/day/12
 //! https://adventofcode.com/2025/day/12/input
 
-use std::{
-	fs::read_to_string,
-	time::{Duration, Instant},
-};
-
-use crate::LINE_ENDING;
+use utils::Solution;
 
 type Parsed = (Vec<Vec<Piece>>, Vec<Tree>);
 
@@ -56,7 +51,7 @@ impl Piece {
 fn parse(input: &str) -> Parsed {
 	let mut pieces = vec![];
 	let mut trees = vec![];
-	let separator = &LINE_ENDING.repeat(2);
+	let separator = &"\n".repeat(2);
 	let parts: Vec<_> = input.split(separator).collect();
 	for piece in &parts[..6] {
 		let mut rows = [0; 3];
@@ -160,8 +155,9 @@ pub mod part1 {
 	}
 }
 
-pub fn main(test: bool, verbose: bool) -> Duration {
-	let test_input = "0:\r
+#[derive(Solution)]
+#[solution(single_part)]
+#[example(input = "0:\r
 ###\r
 ##.\r
 ##.\r
@@ -194,33 +190,5 @@ pub fn main(test: bool, verbose: bool) -> Duration {
 4x4: 0 0 0 0 2 0\r
 12x5: 1 0 1 0 2 2\r
 12x5: 1 0 1 0 3 2\r
-"
-	.to_owned();
-	let puzzle_input = if test {
-		test_input
-	} else {
-		read_to_string("../inputs/2025/day_12_input.txt").unwrap()
-	};
-
-	let mut total = Duration::default();
-
-	let start = Instant::now();
-	let parsed = parse(&puzzle_input);
-	let elapsed = start.elapsed();
-	if verbose {
-		println!("Parsed in {:?}", elapsed);
-		total += elapsed;
-	}
-
-	let start = Instant::now();
-	let result = part1::solve(parsed.clone());
-	let elapsed = start.elapsed();
-	println!("{}", result);
-	println!("First part in {:?}", elapsed);
-	total += elapsed;
-
-	if verbose {
-		println!("Total {:?}", total);
-	}
-	total
-}
+")]
+pub struct Day12;
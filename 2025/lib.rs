@@ -1,17 +1 @@
-pub mod day_01;
-pub mod day_02;
-pub mod day_03;
-pub mod day_04;
-pub mod day_05;
-pub mod day_06;
-pub mod day_07;
-pub mod day_08;
-pub mod day_09;
-pub mod day_10;
-pub mod day_11;
-pub mod day_12;
-
-#[cfg(windows)]
-pub const LINE_ENDING: &str = "\r\n";
-#[cfg(not(windows))]
-pub const LINE_ENDING: &str = "\n";
+utils::days!();
@@ -1,21 +1,5 @@
-use std::{env, time::Duration};
-
 use utils::run_days;
 
 fn main() {
-	run_days!(
-		advent_of_code_2025,
-		day_01, /**/
-		day_02, /**/
-		day_03, /**/
-		day_04, /**/
-		day_05, /**/
-		day_06, /**/
-		day_07, /**/
-		day_08, /**/
-		day_09, /**/
-		day_10, /**/
-		day_11, /**/
-		day_12, /**/
-	);
+	run_days!(advent_of_code_2025);
 }
@@ -0,0 +1,43 @@
+use std::{
+	fs::read_dir,
+	path::{Path, PathBuf},
+};
+
+use utils::input;
+
+use crate::check::Manifest;
+
+/// The inputs of another account, used to check that the solutions do not depend on one input.
+/// They are laid out like the default ones, in `DIR/NAME/YYYY/day_XX_input.txt`,
+/// with the expected answers in `DIR/NAME/YYYY/answers.toml` like the ones of each year.
+pub struct Account {
+	pub name: String,
+	root: PathBuf,
+}
+
+impl Account {
+	/// Every subdirectory of `dir` is an account, sorted by name.
+	pub fn all(dir: &Path) -> Result<Vec<Self>, String> {
+		let mut accounts: Vec<_> = read_dir(dir)
+			.map_err(|error| format!("Cannot read {}: {}", dir.display(), error))?
+			.filter_map(|entry| {
+				let entry = entry.ok()?;
+				entry.file_type().ok()?.is_dir().then(|| Self {
+					name: entry.file_name().to_string_lossy().into_owned(),
+					root: entry.path(),
+				})
+			})
+			.collect();
+		accounts.sort_by(|a, b| a.name.cmp(&b.name));
+		Ok(accounts)
+	}
+
+	/// Returns `None` if the account has no input for the day.
+	pub fn input(&self, year: u16, day: u8) -> Option<PathBuf> {
+		Some(input::path_in(&self.root, year, day)).filter(|path| path.is_file())
+	}
+
+	pub fn manifest(&self, year: u16) -> Result<Manifest, String> {
+		Manifest::load_file(&self.root.join(year.to_string()).join("answers.toml"))
+	}
+}
@@ -0,0 +1,289 @@
+use std::{
+	fs::{read_to_string, write},
+	path::Path,
+	time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+use utils::{
+	bench::{Phase, Samples},
+	memory::Usage,
+};
+
+/// The statistics of a phase of a day, as saved by `--output` and read by `--baseline`.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+	pub year: u16,
+	pub day: u8,
+	pub phase: String,
+	pub min_ns: u128,
+	pub median_ns: u128,
+	pub p95_ns: u128,
+	/// The heap usage of the last run, only with the `memory` feature and never in CSV.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub allocations: Option<usize>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub bytes: Option<usize>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub peak_bytes: Option<usize>,
+}
+
+impl Record {
+	pub fn new(year: u16, day: u8, samples: &Samples) -> Self {
+		let stats = samples.stats();
+		Self {
+			year,
+			day,
+			phase: samples.phase.to_string(),
+			min_ns: stats.min.as_nanos(),
+			median_ns: stats.median.as_nanos(),
+			p95_ns: stats.p95.as_nanos(),
+			allocations: samples.usage.map(|usage| usage.allocations),
+			bytes: samples.usage.map(|usage| usage.bytes),
+			peak_bytes: samples.usage.map(|usage| usage.peak),
+		}
+	}
+
+	fn duration(nanos: u128) -> Duration {
+		Duration::from_nanos(nanos as u64)
+	}
+
+	fn usage(&self) -> Option<Usage> {
+		Some(Usage {
+			allocations: self.allocations?,
+			bytes: self.bytes?,
+			peak: self.peak_bytes?,
+		})
+	}
+
+	pub fn print(&self) {
+		let usage = self
+			.usage()
+			.map_or_else(String::new, |usage| format!("  ({})", usage));
+		println!(
+			"{:<5} min {:>12?}  median {:>12?}  p95 {:>12?}{}",
+			self.phase,
+			Self::duration(self.min_ns),
+			Self::duration(self.median_ns),
+			Self::duration(self.p95_ns),
+			usage,
+		);
+	}
+}
+
+const CSV_HEADER: &str = "year,day,phase,min_ns,median_ns,p95_ns";
+
+fn is_csv(path: &Path) -> bool {
+	path.extension().is_some_and(|extension| extension == "csv")
+}
+
+/// Writes the records as CSV if the path ends with `.csv`, as JSON otherwise.
+pub fn save(path: &Path, records: &[Record]) -> Result<(), String> {
+	let content = if is_csv(path) {
+		let mut content = String::from(CSV_HEADER);
+		for r in records {
+			content.push_str(&format!(
+				"\n{},{},{},{},{},{}",
+				r.year, r.day, r.phase, r.min_ns, r.median_ns, r.p95_ns
+			));
+		}
+		content + "\n"
+	} else {
+		serde_json::to_string_pretty(records).map_err(|error| error.to_string())? + "\n"
+	};
+	write(path, content).map_err(|error| format!("Cannot write {}: {}", path.display(), error))
+}
+
+pub fn load(path: &Path) -> Result<Vec<Record>, String> {
+	let content = read_to_string(path)
+		.map_err(|error| format!("Cannot read {}: {}", path.display(), error))?;
+	let invalid = |line: &str| format!("Invalid line `{}` in {}", line, path.display());
+	if !is_csv(path) {
+		return serde_json::from_str(&content)
+			.map_err(|error| format!("Cannot parse {}: {}", path.display(), error));
+	}
+	content
+		.lines()
+		.skip(1)
+		.filter(|line| !line.is_empty())
+		.map(|line| {
+			let fields: Vec<_> = line.split(',').collect();
+			let [year, day, phase, min_ns, median_ns, p95_ns] = fields[..] else {
+				return Err(invalid(line));
+			};
+			let number = |field: &str| field.parse().map_err(|_| invalid(line));
+			Ok(Record {
+				year: year.parse().map_err(|_| invalid(line))?,
+				day: day.parse().map_err(|_| invalid(line))?,
+				phase: phase.to_owned(),
+				min_ns: number(min_ns)?,
+				median_ns: number(median_ns)?,
+				p95_ns: number(p95_ns)?,
+				allocations: None,
+				bytes: None,
+				peak_bytes: None,
+			})
+		})
+		.collect()
+}
+
+/// A phase whose median got slower than the baseline allows.
+pub struct Regression {
+	pub year: u16,
+	pub day: u8,
+	pub phase: Phase,
+	pub baseline: Duration,
+	pub current: Duration,
+}
+
+impl Regression {
+	pub fn slowdown(&self) -> f64 {
+		(self.current.as_secs_f64() / self.baseline.as_secs_f64() - 1.0) * 100.0
+	}
+}
+
+/// Compares the medians of the phases present in both runs.
+pub fn compare(baseline: &[Record], current: &[Record], threshold: f64) -> Vec<Regression> {
+	current
+		.iter()
+		.filter_map(|record| {
+			let old = baseline.iter().find(|old| {
+				old.year == record.year && old.day == record.day && old.phase == record.phase
+			})?;
+			let regression = Regression {
+				year: record.year,
+				day: record.day,
+				phase: Phase::from_name(&record.phase)?,
+				baseline: Record::duration(old.median_ns),
+				current: Record::duration(record.median_ns),
+			};
+			(regression.current > regression.baseline && regression.slowdown() > threshold)
+				.then_some(regression)
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{env, fs::remove_file};
+
+	use utils::runner::Part;
+
+	use super::*;
+
+	fn record(day: u8, phase: &str, median_ms: u64) -> Record {
+		Record {
+			year: 2024,
+			day,
+			phase: phase.to_owned(),
+			min_ns: Duration::from_millis(median_ms / 2).as_nanos(),
+			median_ns: Duration::from_millis(median_ms).as_nanos(),
+			p95_ns: Duration::from_millis(median_ms * 2).as_nanos(),
+			allocations: None,
+			bytes: None,
+			peak_bytes: None,
+		}
+	}
+
+	#[test]
+	fn records() {
+		let mut samples = Samples::new(Phase::Part(Part::Second), 3);
+		samples.times = [3, 1, 2].map(Duration::from_millis).to_vec();
+		samples.usage = Some(Usage {
+			allocations: 4,
+			bytes: 64,
+			peak: 128,
+		});
+		let with_usage = Record::new(2024, 5, &samples);
+		assert_eq!(
+			(
+				with_usage.phase.as_str(),
+				with_usage.min_ns,
+				with_usage.median_ns,
+				with_usage.p95_ns
+			),
+			("part2", 1_000_000, 2_000_000, 3_000_000)
+		);
+		assert_eq!(with_usage.usage(), samples.usage);
+		let records = vec![with_usage.clone(), record(6, "parse", 10)];
+
+		let path = env::temp_dir().join(format!("aoc_bench_{}.json", std::process::id()));
+		save(&path, &records).unwrap();
+		assert_eq!(load(&path).unwrap(), records);
+		remove_file(&path).unwrap();
+
+		// CSV has no columns for the heap usage
+		let path = path.with_extension("csv");
+		save(&path, &records).unwrap();
+		assert_eq!(
+			read_to_string(&path).unwrap(),
+			"year,day,phase,min_ns,median_ns,p95_ns\n\
+			2024,5,part2,1000000,2000000,3000000\n\
+			2024,6,parse,5000000,10000000,20000000\n"
+		);
+		let without_usage = Record {
+			allocations: None,
+			bytes: None,
+			peak_bytes: None,
+			..with_usage
+		};
+		assert_eq!(
+			load(&path).unwrap(),
+			vec![without_usage, records[1].clone()]
+		);
+
+		write(
+			&path,
+			"year,day,phase,min_ns,median_ns,p95_ns\n2024,5,part2,1\n",
+		)
+		.unwrap();
+		assert!(load(&path)
+			.unwrap_err()
+			.starts_with("Invalid line `2024,5,part2,1`"));
+		remove_file(&path).unwrap();
+	}
+
+	#[test]
+	fn regressions() {
+		let baseline = [
+			record(1, "parse", 10),
+			record(1, "part1", 10),
+			record(1, "part2", 10),
+			record(2, "part1", 10),
+		];
+		let current = [
+			// 50% slower
+			record(1, "parse", 15),
+			// 5% slower, within the threshold
+			Record {
+				median_ns: Duration::from_micros(10_500).as_nanos(),
+				..record(1, "part1", 10)
+			},
+			// faster
+			record(1, "part2", 5),
+			// not in the baseline
+			record(3, "part1", 100),
+		];
+		let regressions = compare(&baseline, &current, 10.0);
+		assert_eq!(regressions.len(), 1);
+		let regression = &regressions[0];
+		assert_eq!(
+			(
+				regression.day,
+				regression.phase,
+				regression.baseline,
+				regression.current
+			),
+			(
+				1,
+				Phase::Parse,
+				Duration::from_millis(10),
+				Duration::from_millis(15)
+			)
+		);
+		assert!((regression.slowdown() - 50.0).abs() < 1e-9);
+
+		assert_eq!(compare(&baseline, &current, 4.0).len(), 2);
+		assert!(compare(&baseline, &current, 60.0).is_empty());
+	}
+}
@@ -0,0 +1,239 @@
+use std::{
+	collections::HashMap,
+	fmt::Display,
+	fs::{read_to_string, write},
+	io::ErrorKind,
+	path::Path,
+};
+
+use toml::{Table, Value};
+use utils::{bench::Phase, runner::Part, Answer};
+
+/// Expected answers of a year on its default inputs, read from `YYYY/answers.toml`:
+/// ```toml
+/// [day_01]
+/// part1 = 1234
+/// part2 = "abc"
+/// ```
+#[derive(Default)]
+pub struct Manifest {
+	answers: HashMap<(u8, Part), String>,
+}
+
+impl Manifest {
+	pub fn path(year: u16) -> String {
+		format!("../{}/answers.toml", year)
+	}
+
+	/// Loads the manifest of the given year, which is empty if the file does not exist.
+	pub fn load(year: u16) -> Result<Self, String> {
+		Self::load_file(Path::new(&Self::path(year)))
+	}
+
+	/// Loads a manifest from anywhere, like the ones next to the inputs of other accounts.
+	pub fn load_file(path: &Path) -> Result<Self, String> {
+		let content = match read_to_string(path) {
+			Ok(content) => content,
+			Err(error) if error.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+			Err(error) => return Err(format!("Cannot read {}: {}", path.display(), error)),
+		};
+		let path = path.display();
+		let table: Table = content
+			.parse()
+			.map_err(|error| format!("Cannot parse {}: {}", path, error))?;
+		let mut answers = HashMap::new();
+		for (name, parts) in table {
+			let day = name
+				.strip_prefix("day_")
+				.and_then(|day| day.parse().ok())
+				.ok_or_else(|| format!("Invalid day `{}` in {}", name, path))?;
+			let Value::Table(parts) = parts else {
+				return Err(format!("`{}` in {} is not a table", name, path));
+			};
+			for (key, value) in parts {
+				let part = match key.as_str() {
+					"part1" => Part::First,
+					"part2" => Part::Second,
+					_ => return Err(format!("Invalid part `{}.{}` in {}", name, key, path)),
+				};
+				let value = match value {
+					Value::String(value) => value,
+					value => value.to_string(),
+				};
+				answers.insert((day, part), value);
+			}
+		}
+		Ok(Self { answers })
+	}
+
+	/// Writes a correct answer into the manifest of the given year,
+	/// leaving the rest of the file, comments included, as it is.
+	pub fn record(year: u16, day: u8, part: Part, answer: &Answer) -> Result<(), String> {
+		let path = Self::path(year);
+		let content = match read_to_string(&path) {
+			Ok(content) => content,
+			Err(error) if error.kind() == ErrorKind::NotFound => String::new(),
+			Err(error) => return Err(format!("Cannot read {}: {}", path, error)),
+		};
+		let mut lines: Vec<_> = content.lines().map(str::to_owned).collect();
+		record_line(&mut lines, day, part, answer);
+		write(&path, lines.join("\n") + "\n")
+			.map_err(|error| format!("Cannot write {}: {}", path, error))
+	}
+
+	/// Whether the answer of the part is known, which means it was checked once.
+	pub fn recorded(&self, day: u8, part: Part) -> bool {
+		self.answers.contains_key(&(day, part))
+	}
+
+	pub fn check(&self, day: u8, part: Part, answer: &Answer) -> Status {
+		match self.answers.get(&(day, part)) {
+			None => Status::Missing,
+			Some(expected) if answer == expected => Status::Pass,
+			Some(expected) => Status::Fail(expected.clone()),
+		}
+	}
+}
+
+fn section_day(line: &str) -> Option<u8> {
+	line.trim()
+		.strip_prefix("[day_")?
+		.strip_suffix(']')?
+		.parse()
+		.ok()
+}
+
+fn record_line(lines: &mut Vec<String>, day: u8, part: Part, answer: &Answer) {
+	let key = Phase::Part(part).to_string();
+	let value = match answer {
+		// TOML integers are 64 bits
+		Answer::Number(number) if i64::try_from(*number).is_ok() => number.to_string(),
+		answer => Value::String(answer.to_string()).to_string(),
+	};
+	let line = format!("{} = {}", key, value);
+	let Some(start) = lines.iter().position(|l| section_day(l) == Some(day)) else {
+		// sections are kept sorted by day
+		let section = [format!("[day_{:0>2}]", day), line];
+		match lines
+			.iter()
+			.position(|l| section_day(l).is_some_and(|d| d > day))
+		{
+			Some(next) => {
+				lines.splice(next..next, section.into_iter().chain([String::new()]));
+			}
+			None => {
+				if lines.last().is_some_and(|l| !l.trim().is_empty()) {
+					lines.push(String::new());
+				}
+				lines.extend(section);
+			}
+		}
+		return;
+	};
+	let end = lines[start + 1..]
+		.iter()
+		.position(|l| l.trim_start().starts_with('['))
+		.map_or(lines.len(), |i| start + 1 + i);
+	let existing = (start + 1..end).find(|&i| {
+		lines[i]
+			.split_once('=')
+			.is_some_and(|(k, _)| k.trim() == key)
+	});
+	match existing {
+		Some(i) => lines[i] = line,
+		None => {
+			// right after the last key, before the blank lines separating the sections
+			let last = (start + 1..end)
+				.rev()
+				.find(|&i| !lines[i].trim().is_empty())
+				.unwrap_or(start);
+			lines.insert(last + 1, line);
+		}
+	}
+}
+
+pub enum Status {
+	Pass,
+	/// Holds the expected answer.
+	Fail(String),
+	Missing,
+}
+
+impl Display for Status {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Status::Pass => write!(f, "pass"),
+			Status::Fail(expected) => write!(f, "FAIL (expected {})", expected),
+			Status::Missing => write!(f, "missing"),
+		}
+	}
+}
+
+/// Counts of the checked answers, printed at the end of `run --check`.
+#[derive(Default)]
+pub struct Summary {
+	pub passed: usize,
+	pub failed: usize,
+	pub missing: usize,
+}
+
+impl Summary {
+	pub fn add(&mut self, status: &Status) {
+		match status {
+			Status::Pass => self.passed += 1,
+			Status::Fail(_) => self.failed += 1,
+			Status::Missing => self.missing += 1,
+		}
+	}
+}
+
+impl Display for Summary {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(
+			f,
+			"{} passed, {} failed, {} missing",
+			self.passed, self.failed, self.missing
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn record() {
+		let mut lines: Vec<_> = [
+			"# comment",
+			"",
+			"[day_03]",
+			"part1 = 5",
+			"",
+			"[day_07]",
+			"part1 = 7",
+		]
+		.map(String::from)
+		.to_vec();
+		record_line(&mut lines, 3, Part::Second, &Answer::from("abc"));
+		record_line(&mut lines, 1, Part::First, &Answer::from(u64::MAX));
+		record_line(&mut lines, 9, Part::First, &Answer::from(9));
+		record_line(&mut lines, 7, Part::First, &Answer::from(8));
+		assert_eq!(
+			lines.join("\n"),
+			"# comment
+
+[day_01]
+part1 = \"18446744073709551615\"
+
+[day_03]
+part1 = 5
+part2 = \"abc\"
+
+[day_07]
+part1 = 8
+
+[day_09]
+part1 = 9"
+		);
+	}
+}
@@ -0,0 +1,228 @@
+use std::{
+	env,
+	fs::{create_dir_all, read_to_string, rename, write},
+	io::ErrorKind,
+	path::Path,
+	thread::sleep,
+	time::{Duration, Instant},
+};
+
+use ureq::Agent;
+use utils::{input, runner::Part, Answer};
+
+use crate::submit::{level, Verdict};
+
+pub const DEFAULT_URL: &str = "https://adventofcode.com";
+/// Environment variable holding the session cookie, used instead of `local_config.ini` when set.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+const CONFIG_PATH: &str = "../local_config.ini";
+const USER_AGENT: &str =
+	"https://github.com/Princic-1837592/advent_of_code by andreapri.99@gmail.com";
+
+/// Reads the session cookie from `$AOC_SESSION`,
+/// or from the `[auto_download]` section of `local_config.ini` like the Python scripts do.
+pub fn session() -> Result<String, String> {
+	if let Ok(session) = env::var(SESSION_VAR) {
+		return Ok(session);
+	}
+	let missing = || {
+		format!(
+			"No session cookie, set {} or `session` in the [auto_download] section of {}",
+			SESSION_VAR, CONFIG_PATH
+		)
+	};
+	let config = match read_to_string(CONFIG_PATH) {
+		Ok(config) => config,
+		Err(error) if error.kind() == ErrorKind::NotFound => return Err(missing()),
+		Err(error) => return Err(format!("Cannot read {}: {}", CONFIG_PATH, error)),
+	};
+	let mut section = "";
+	for line in config.lines().map(str::trim) {
+		if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+			section = name;
+		} else if let Some((key, value)) = line.split_once('=') {
+			if section == "auto_download" && key.trim() == "session" {
+				return Ok(value.trim().to_owned());
+			}
+		}
+	}
+	Err(missing())
+}
+
+pub enum Fetched {
+	Downloaded,
+	/// The input was already there, so nothing was requested.
+	Cached,
+}
+
+/// Talks to the Advent of Code website, waiting at least `delay` between two requests.
+pub struct Client {
+	agent: Agent,
+	url: String,
+	session: String,
+	delay: Duration,
+	last_request: Option<Instant>,
+}
+
+impl Client {
+	pub fn new(url: &str, session: String, delay: Duration) -> Self {
+		Self {
+			agent: Agent::new(),
+			url: url.trim_end_matches('/').to_owned(),
+			session,
+			delay,
+			last_request: None,
+		}
+	}
+
+	/// Requests the given path, posting `form` if there is one, after waiting for the delay.
+	fn send(&mut self, path: &str, form: Option<&[(&str, &str)]>) -> Result<String, String> {
+		if let Some(last) = self.last_request {
+			sleep(self.delay.saturating_sub(last.elapsed()));
+		}
+		self.last_request = Some(Instant::now());
+		let url = format!("{}{}", self.url, path);
+		let request = self
+			.agent
+			.request(if form.is_some() { "POST" } else { "GET" }, &url)
+			.set("Cookie", &format!("session={}", self.session))
+			.set("User-Agent", USER_AGENT);
+		let response = match form {
+			Some(form) => request.send_form(form),
+			None => request.call(),
+		}
+		.map_err(|error| match error {
+			ureq::Error::Status(code, response) => format!(
+				"{} returned {}: {}",
+				url,
+				code,
+				response.into_string().unwrap_or_default().trim()
+			),
+			error => format!("Cannot request {}: {}", url, error),
+		})?;
+		response
+			.into_string()
+			.map_err(|error| format!("Cannot read the response of {}: {}", url, error))
+	}
+
+	/// Posts the answer of a part and reads the verdict from the response page.
+	pub fn submit(
+		&mut self,
+		year: u16,
+		day: u8,
+		part: Part,
+		answer: &Answer,
+	) -> Result<Verdict, String> {
+		let form = [("level", level(part)), ("answer", &answer.to_string())];
+		let page = self.send(&format!("/{}/day/{}/answer", year, day), Some(&form))?;
+		Ok(Verdict::parse(&page))
+	}
+
+	/// Downloads the input of a day into the inputs directory `root`, unless it is already there.
+	pub fn fetch(&mut self, root: &Path, year: u16, day: u8) -> Result<Fetched, String> {
+		let path = input::path_in(root, year, day);
+		if path.exists() {
+			return Ok(Fetched::Cached);
+		}
+		let content = self.send(&format!("/{}/day/{}/input", year, day), None)?;
+		let directory = path.parent().unwrap();
+		create_dir_all(directory)
+			.map_err(|error| format!("Cannot create {}: {}", directory.display(), error))?;
+		// written aside and then moved, so that an interrupted download is not mistaken for an input
+		let partial = path.with_extension("txt.part");
+		write(&partial, content.trim_end_matches('\n'))
+			.and_then(|_| rename(&partial, &path))
+			.map_err(|error| format!("Cannot write {}: {}", path.display(), error))?;
+		Ok(Fetched::Downloaded)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{
+		io::{BufRead, BufReader, Read, Write},
+		net::TcpListener,
+		sync::mpsc::{channel, Receiver},
+		thread,
+	};
+
+	use super::*;
+	use crate::submit::Hint;
+
+	/// Answers a single request with `body`, returning its URL and the received request.
+	fn stub(body: &'static str) -> (String, Receiver<(Vec<String>, String)>) {
+		let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+		let url = format!("http://{}", listener.local_addr().unwrap());
+		let (sender, receiver) = channel();
+		thread::spawn(move || {
+			let (stream, _) = listener.accept().unwrap();
+			let mut reader = BufReader::new(&stream);
+			let mut head = Vec::new();
+			loop {
+				let mut line = String::new();
+				reader.read_line(&mut line).unwrap();
+				if line.trim().is_empty() {
+					break;
+				}
+				head.push(line.trim().to_owned());
+			}
+			let length = head
+				.iter()
+				.find_map(|line| line.strip_prefix("Content-Length: "))
+				.map_or(0, |length| length.parse().unwrap());
+			let mut content = vec![0; length];
+			reader.read_exact(&mut content).unwrap();
+			sender
+				.send((head, String::from_utf8(content).unwrap()))
+				.unwrap();
+			write!(
+				&stream,
+				"HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+				body.len(),
+				body
+			)
+			.unwrap();
+		});
+		(url, receiver)
+	}
+
+	#[test]
+	fn fetch() {
+		let (url, receiver) = stub("1 2\n3 4\n");
+		let inputs = env::temp_dir().join(format!("aoc_fetch_{}", std::process::id()));
+		let mut client = Client::new(&url, "secret".to_owned(), Duration::ZERO);
+		assert!(matches!(
+			client.fetch(&inputs, 2023, 1),
+			Ok(Fetched::Downloaded)
+		));
+		let (head, _) = receiver.recv().unwrap();
+		assert_eq!(head[0], "GET /2023/day/1/input HTTP/1.1");
+		assert!(head.contains(&"Cookie: session=secret".to_owned()));
+		assert_eq!(
+			read_to_string(inputs.join("2023/day_01_input.txt")).unwrap(),
+			"1 2\n3 4"
+		);
+		// the stub only answers once
+		assert!(matches!(
+			client.fetch(&inputs, 2023, 1),
+			Ok(Fetched::Cached)
+		));
+		std::fs::remove_dir_all(inputs).unwrap();
+	}
+
+	#[test]
+	fn submit() {
+		let (url, receiver) = stub(
+			"<main><article><p>That's not the right answer; your answer is too high. \
+			Please wait one minute before trying again.</p></article></main>",
+		);
+		let mut client = Client::new(&url, "secret".to_owned(), Duration::ZERO);
+		assert_eq!(
+			client.submit(2024, 7, Part::Second, &Answer::from(1234)),
+			Ok(Verdict::Wrong(Some(Hint::TooHigh)))
+		);
+		let (head, content) = receiver.recv().unwrap();
+		assert_eq!(head[0], "POST /2024/day/7/answer HTTP/1.1");
+		assert_eq!(content, "level=2&answer=1234");
+	}
+}
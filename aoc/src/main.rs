@@ -0,0 +1,823 @@
+use std::{
+	collections::{BTreeMap, HashMap},
+	fs::{read_to_string, write},
+	num::NonZeroUsize,
+	path::{Path, PathBuf},
+	process::exit,
+	thread::{self, sleep},
+	time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use accounts::Account;
+use bench::Record;
+use check::{Manifest, Status, Summary};
+use clap::{error::ErrorKind, CommandFactory, Parser, Subcommand};
+use client::{Client, Fetched};
+use new::Scaffold;
+use report::{Row, Table};
+use submit::{Guesses, Verdict};
+use utils::{
+	bench::{Phase, Repetitions},
+	generate, memory,
+	runner::{self, Args, Part},
+	trace::Level,
+};
+use watch::{Snapshot, Watcher};
+use workers::Report;
+use years::{Year, YEARS};
+
+mod accounts;
+mod bench;
+mod check;
+mod client;
+mod new;
+mod report;
+mod submit;
+mod watch;
+mod workers;
+mod years;
+
+/// Reports the heap usage of every phase next to its time.
+#[cfg(feature = "memory")]
+#[global_allocator]
+static ALLOCATOR: memory::CountingAllocator = memory::CountingAllocator;
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code solutions")]
+struct Cli {
+	#[command(subcommand)]
+	command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+	/// Lists the available solutions
+	List,
+	/// Runs the selected days, or all of them if nothing is selected
+	Run(RunArgs),
+	/// Downloads the missing inputs of the selected days, or of all of them if nothing is selected
+	Fetch(FetchArgs),
+	/// Submits the answer of a part, recording it in `YYYY/answers.toml` if it is correct
+	Submit(SubmitArgs),
+	/// Creates the crate of a year and the files of its days, without overwriting existing ones
+	New(NewArgs),
+	/// Rebuilds and re-runs a day whenever its source or its input changes
+	Watch(WatchArgs),
+	/// Writes the times and the check of every day into the tables of the README
+	Report(ReportArgs),
+	/// Writes a random input for a day with a generator, or runs the day on bigger and bigger ones
+	Generate(GenerateArgs),
+}
+
+#[derive(clap::Args)]
+struct FetchArgs {
+	/// Also accepts years and days without a Rust solution, if both are given
+	#[arg(long)]
+	year: Option<u16>,
+	#[arg(long)]
+	day: Option<u8>,
+	/// Server to download from, useful to test against a local one
+	#[arg(long, default_value = client::DEFAULT_URL)]
+	url: String,
+	/// Minimum time between two requests
+	#[arg(long, value_name = "MILLISECONDS", default_value_t = 1000)]
+	delay: u64,
+}
+
+#[derive(clap::Args)]
+struct RunArgs {
+	#[arg(long)]
+	year: Option<u16>,
+	#[arg(long)]
+	day: Option<u8>,
+	/// Runs only the given part (1 or 2)
+	#[arg(long)]
+	part: Option<Part>,
+	/// Reads the puzzle input from this file instead of the default one, or from stdin if `-`
+	#[arg(long)]
+	input: Option<PathBuf>,
+	/// Runs on the test input
+	#[arg(long)]
+	test: bool,
+	/// Prints parse and total times for every day, along with their info events
+	#[arg(long)]
+	verbose: bool,
+	/// Prints the events of the days up to this level (info, debug or trace)
+	#[arg(long, value_name = "LEVEL", conflicts_with = "bench")]
+	trace: Option<Level>,
+	/// Prints the events of --trace only for these days
+	#[arg(long, value_name = "DAY", requires = "trace")]
+	trace_day: Vec<u8>,
+	/// Seed of the randomized searches, printed along with the failures of the days that use it
+	#[arg(long, default_value_t = 0)]
+	seed: u64,
+	/// Compares every answer with the ones in `YYYY/answers.toml`
+	#[arg(long, conflicts_with_all = ["test", "input"])]
+	check: bool,
+	/// Repeats every phase N times and reports min, median and p95 instead of the answers
+	#[arg(long, value_name = "N", conflicts_with = "check")]
+	#[arg(value_parser = clap::value_parser!(u32).range(1..))]
+	bench: Option<u32>,
+	/// Runs discarded before measuring with --bench
+	#[arg(long, value_name = "N", default_value_t = 1, requires = "bench")]
+	warmup: usize,
+	/// Saves the --bench results, as CSV if the path ends with .csv and as JSON otherwise
+	#[arg(long, requires = "bench")]
+	output: Option<PathBuf>,
+	/// Compares the --bench results with a file previously saved by --output
+	#[arg(long, requires = "bench")]
+	baseline: Option<PathBuf>,
+	/// Slowdown, in percent of the baseline median, reported as a regression
+	#[arg(
+		long,
+		value_name = "PERCENT",
+		default_value_t = 10.0,
+		requires = "baseline"
+	)]
+	threshold: f64,
+	/// Number of days run at the same time, by default one per available core
+	#[arg(long, short, value_name = "N", conflicts_with = "bench")]
+	#[arg(value_parser = clap::value_parser!(u32).range(1..))]
+	jobs: Option<u32>,
+	/// Gives up on the days still running after this many seconds.
+	/// They cannot be stopped though, so they keep running in the background,
+	/// and more than `--jobs` threads can be busy. When counting allocations,
+	/// no other day starts until they are done
+	#[arg(long, value_name = "SECONDS", conflicts_with = "bench")]
+	#[arg(value_parser = clap::value_parser!(u64).range(1..))]
+	timeout: Option<u64>,
+	/// Saves the answers and the time of the day, for `watch`
+	#[arg(long, hide = true)]
+	snapshot: Option<PathBuf>,
+	/// Also runs every day on the inputs of other accounts, in `DIR/NAME/YYYY/day_XX_input.txt`,
+	/// checking the answers in `DIR/NAME/YYYY/answers.toml`, to find solutions fitted to one input
+	#[arg(long, value_name = "DIR", conflicts_with_all = ["input", "test", "bench", "snapshot"])]
+	inputs: Option<PathBuf>,
+}
+
+#[derive(clap::Args)]
+struct ReportArgs {
+	/// Reports only this year, leaving the tables of the others as they are
+	#[arg(long)]
+	year: Option<u16>,
+	/// Reads the median times from a file saved by `run --bench --output` instead of running the days,
+	/// in which case an answer passes the check if it is recorded in `YYYY/answers.toml`
+	#[arg(long, value_name = "PATH")]
+	cached: Option<PathBuf>,
+	/// Gives up on the days still running after this many seconds,
+	/// which keep running in the background like with `run --timeout`
+	#[arg(long, value_name = "SECONDS", conflicts_with = "cached")]
+	#[arg(value_parser = clap::value_parser!(u64).range(1..))]
+	timeout: Option<u64>,
+	#[arg(long, default_value = "../README.md")]
+	readme: PathBuf,
+}
+
+#[derive(clap::Args)]
+struct GenerateArgs {
+	#[arg(long)]
+	year: u16,
+	#[arg(long)]
+	day: u8,
+	/// Number of items, like lines or moves, of the input
+	#[arg(long, default_value_t = 1000)]
+	#[arg(value_parser = clap::value_parser!(u64).range(1..))]
+	size: u64,
+	/// Seed of the input, a random one by default, printed so that the input can be generated again
+	#[arg(long)]
+	seed: Option<u64>,
+	/// Writes the input to this file instead of printing it
+	#[arg(long, conflicts_with = "scale")]
+	output: Option<PathBuf>,
+	/// Runs the day on this many inputs instead, starting from --size and doubling it every time
+	#[arg(long, value_name = "STEPS")]
+	#[arg(value_parser = clap::value_parser!(u32).range(1..=32))]
+	scale: Option<u32>,
+}
+
+fn fail(kind: ErrorKind, message: String) -> ! {
+	Cli::command().error(kind, message).exit()
+}
+
+fn list() {
+	for year in YEARS {
+		let days: Vec<_> = year.numbers().iter().map(u8::to_string).collect();
+		println!("{}: {}", year.year, days.join(", "));
+	}
+}
+
+fn select(year: Option<u16>, day: Option<u8>) -> Vec<(&'static Year, u8)> {
+	let selected: Vec<_> = YEARS
+		.iter()
+		.filter(|y| year.is_none_or(|year| y.year == year))
+		.flat_map(|y| {
+			y.numbers()
+				.into_iter()
+				.filter(move |&d| day.is_none_or(|day| d == day))
+				.map(move |d| (y, d))
+		})
+		.collect();
+	if selected.is_empty() {
+		fail(
+			ErrorKind::InvalidValue,
+			"No solution matches the selected year and day".to_owned(),
+		);
+	}
+	selected
+}
+
+fn run(run_args: RunArgs) {
+	let selected = select(run_args.year, run_args.day);
+	let args = Args {
+		test: run_args.test,
+		verbose: run_args.verbose,
+		part: run_args.part,
+		input: run_args.input.clone(),
+		trace: run_args.verbose.then_some(Level::Info),
+		seed: run_args.seed,
+	};
+	if (args.input.is_some() || run_args.snapshot.is_some()) && selected.len() > 1 {
+		fail(
+			ErrorKind::ArgumentConflict,
+			"--input and --snapshot require a single day to be selected".to_owned(),
+		);
+	}
+
+	if let Some(runs) = run_args.bench {
+		let repetitions = Repetitions {
+			warmup: run_args.warmup,
+			runs: runs as usize,
+		};
+		bench(selected, &args, repetitions, &run_args);
+		return;
+	}
+
+	let accounts = run_args.inputs.as_ref().map_or_else(Vec::new, |dir| {
+		Account::all(dir).unwrap_or_else(|error| fail(ErrorKind::Io, error))
+	});
+	// every day runs on the default input, then on the ones of the accounts that have it
+	let mut tasks = Vec::new();
+	let mut owners = Vec::new();
+	for &(year, day) in &selected {
+		let mut args = args.clone();
+		if run_args.trace_day.is_empty() || run_args.trace_day.contains(&day) {
+			args.trace = run_args.trace.or(args.trace);
+		}
+		tasks.push((year, day, args.clone()));
+		owners.push(None);
+		for account in &accounts {
+			if let Some(input) = account.input(year.year, day) {
+				let args = Args {
+					input: Some(input),
+					..args.clone()
+				};
+				tasks.push((year, day, args));
+				owners.push(Some(account));
+			}
+		}
+	}
+
+	let mut manifests = HashMap::new();
+	let mut summary = Summary::default();
+	let mut total = Duration::default();
+	let mut failed = Vec::new();
+	// the allocations of days running together would be counted together
+	let jobs = if memory::is_counting() {
+		if run_args.jobs.is_some_and(|jobs| jobs > 1) {
+			fail(
+				ErrorKind::ArgumentConflict,
+				"--jobs cannot be greater than 1 when counting allocations".to_owned(),
+			);
+		}
+		1
+	} else {
+		run_args.jobs.map_or_else(
+			|| thread::available_parallelism().map_or(1, NonZeroUsize::get),
+			|jobs| jobs as usize,
+		)
+	};
+	let timeout = run_args.timeout.map(Duration::from_secs);
+	let start = Instant::now();
+	workers::run(&tasks, jobs, timeout, |index, report| {
+		let (year, day, _) = tasks[index];
+		let account = owners[index];
+		let name = match account {
+			Some(account) => format!("{} day_{:0>2} ({})", year.year, day, account.name),
+			None => format!("{} day_{:0>2}", year.year, day),
+		};
+		println!("Running {}", name);
+		let outcome = match report {
+			Report::Finished(outcome) => outcome,
+			Report::TimedOut(timeout) => {
+				println!("Timed out after {:?}", timeout);
+				println!();
+				failed.push(format!("{} (timed out)", name));
+				return;
+			}
+		};
+		print!("{}", outcome.output);
+		total += outcome.elapsed;
+		if let Some(path) = &run_args.snapshot {
+			Snapshot::new(&outcome)
+				.save(path)
+				.unwrap_or_else(|error| fail(ErrorKind::Io, error));
+		}
+		if !outcome.failures.is_empty() {
+			failed.push(name);
+		}
+		if run_args.check || account.is_some() {
+			let key = (account.map(|account| &account.name), year.year);
+			let manifest = manifests.entry(key).or_insert_with(|| {
+				match account {
+					Some(account) => account.manifest(year.year),
+					None => Manifest::load(year.year),
+				}
+				.unwrap_or_else(|error| fail(ErrorKind::Io, error))
+			});
+			let statuses: Vec<_> = outcome
+				.answers
+				.iter()
+				.map(|(part, answer)| {
+					let status = manifest.check(day, *part, answer);
+					summary.add(&status);
+					format!("{} {}", part, status)
+				})
+				.collect();
+			println!("Check: {}", statuses.join(", "));
+		}
+		println!();
+	});
+	println!("Total: {:?}", total);
+	if jobs > 1 {
+		println!("Wall clock: {:?}", start.elapsed());
+	}
+	report_failures(&failed);
+	if run_args.check || !accounts.is_empty() {
+		println!("Check: {}", summary);
+	}
+	if summary.failed > 0 || !failed.is_empty() {
+		exit(1);
+	}
+}
+
+fn bench(
+	selected: Vec<(&'static Year, u8)>,
+	args: &Args,
+	repetitions: Repetitions,
+	run_args: &RunArgs,
+) {
+	let baseline = run_args
+		.baseline
+		.as_ref()
+		.map(|path| bench::load(path).unwrap_or_else(|error| fail(ErrorKind::Io, error)));
+	let mut records = Vec::new();
+	let mut failed = Vec::new();
+	for (year, day) in selected {
+		println!("Benchmarking {} day_{:0>2}", year.year, day);
+		match year.bench(day, args, repetitions) {
+			Err(failure) => {
+				eprintln!("{}", failure);
+				failed.push(format!("{} day_{:0>2}", year.year, day));
+			}
+			Ok(samples) => {
+				for samples in samples {
+					let record = Record::new(year.year, day, &samples);
+					record.print();
+					records.push(record);
+				}
+			}
+		}
+		println!();
+	}
+	if let Some(path) = &run_args.output {
+		bench::save(path, &records).unwrap_or_else(|error| fail(ErrorKind::Io, error));
+	}
+	report_failures(&failed);
+	if let Some(baseline) = baseline {
+		let regressions = bench::compare(&baseline, &records, run_args.threshold);
+		for r in &regressions {
+			println!(
+				"{} day_{:0>2} {} is {:.1}% slower: {:?} -> {:?}",
+				r.year,
+				r.day,
+				r.phase,
+				r.slowdown(),
+				r.baseline,
+				r.current
+			);
+		}
+		if regressions.is_empty() {
+			println!("No regressions over {}%", run_args.threshold);
+		} else {
+			exit(1);
+		}
+	}
+	if !failed.is_empty() {
+		exit(1);
+	}
+}
+
+fn report_failures(failed: &[String]) {
+	if !failed.is_empty() {
+		println!("Failed: {}", failed.join(", "));
+	}
+}
+
+#[derive(clap::Args)]
+struct SubmitArgs {
+	#[arg(long)]
+	year: u16,
+	#[arg(long)]
+	day: u8,
+	/// Part to submit (1 or 2)
+	#[arg(long)]
+	part: Part,
+	/// Answer to submit, computed by running the part on the default input if missing
+	answer: Option<String>,
+	/// Waits and tries again when answering too soon, instead of giving up
+	#[arg(long)]
+	wait: bool,
+	/// Server to submit to, useful to test against a local one
+	#[arg(long, default_value = client::DEFAULT_URL)]
+	url: String,
+}
+
+#[derive(clap::Args)]
+struct NewArgs {
+	#[arg(long)]
+	year: u16,
+	/// Creates only this day instead of all of them
+	#[arg(long)]
+	#[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+	day: Option<u8>,
+}
+
+#[derive(clap::Args)]
+struct WatchArgs {
+	#[arg(long)]
+	year: u16,
+	#[arg(long)]
+	day: u8,
+	/// Runs only the given part (1 or 2)
+	#[arg(long)]
+	part: Option<Part>,
+	/// Reads the puzzle input from this file instead of the default one
+	#[arg(long)]
+	input: Option<PathBuf>,
+	/// Runs on the test input
+	#[arg(long, conflicts_with = "input")]
+	test: bool,
+	/// Prints parse and total times, along with the info events
+	#[arg(long)]
+	verbose: bool,
+	/// Prints the events up to this level (info, debug or trace)
+	#[arg(long, value_name = "LEVEL")]
+	trace: Option<Level>,
+	/// Time between two checks for changes
+	#[arg(long, value_name = "MILLISECONDS", default_value_t = 500)]
+	interval: u64,
+}
+
+fn fetch(fetch_args: FetchArgs) {
+	let selected = match (fetch_args.year, fetch_args.day) {
+		(Some(year), Some(day)) => vec![(year, day)],
+		(year, day) => select(year, day)
+			.into_iter()
+			.map(|(year, day)| (year.year, day))
+			.collect(),
+	};
+	let session = client::session().unwrap_or_else(|error| fail(ErrorKind::Io, error));
+	let mut client = Client::new(
+		&fetch_args.url,
+		session,
+		Duration::from_millis(fetch_args.delay),
+	);
+	let inputs = utils::input::root();
+	for (year, day) in selected {
+		match client.fetch(&inputs, year, day) {
+			Ok(Fetched::Downloaded) => println!("Downloaded {} day_{:0>2}", year, day),
+			Ok(Fetched::Cached) => {}
+			// most likely a day that is not out yet, or an expired session
+			Err(error) => fail(ErrorKind::Io, error),
+		}
+	}
+}
+
+fn submit(submit_args: SubmitArgs) {
+	let SubmitArgs {
+		year, day, part, ..
+	} = submit_args;
+	let answer = match &submit_args.answer {
+		Some(answer) => answer.parse().unwrap(),
+		None => {
+			let (year, day) = select(Some(year), Some(day))[0];
+			let args = Args {
+				part: Some(part),
+				..Default::default()
+			};
+			let outcome = year.run(day, &args);
+			print!("{}", outcome.output);
+			match outcome.answers.into_iter().next() {
+				Some((_, answer)) => answer,
+				None => exit(1),
+			}
+		}
+	};
+
+	let manifest = Manifest::load(year).unwrap_or_else(|error| fail(ErrorKind::Io, error));
+	match manifest.check(day, part, &answer) {
+		Status::Pass => {
+			println!("{} is already known to be correct", answer);
+			return;
+		}
+		Status::Fail(expected) => fail(
+			ErrorKind::InvalidValue,
+			format!("{} is wrong, the correct answer is {}", answer, expected),
+		),
+		Status::Missing => {}
+	}
+	let mut guesses = Guesses::load(year, day).unwrap_or_else(|error| fail(ErrorKind::Io, error));
+	if let Some(reason) = guesses.rejects(part, &answer) {
+		fail(
+			ErrorKind::InvalidValue,
+			format!("Not submitted, {}", reason),
+		);
+	}
+
+	let session = client::session().unwrap_or_else(|error| fail(ErrorKind::Io, error));
+	let mut client = Client::new(&submit_args.url, session, Duration::ZERO);
+	println!(
+		"Submitting {} for {} day_{:0>2} part {}",
+		answer,
+		year,
+		day,
+		submit::level(part)
+	);
+	loop {
+		let verdict = client
+			.submit(year, day, part, &answer)
+			.unwrap_or_else(|error| fail(ErrorKind::Io, error));
+		println!("{}", verdict);
+		match verdict {
+			Verdict::Correct => Manifest::record(year, day, part, &answer)
+				.unwrap_or_else(|error| fail(ErrorKind::Io, error)),
+			Verdict::Wrong(hint) => {
+				guesses
+					.add(part, hint, answer)
+					.unwrap_or_else(|error| fail(ErrorKind::Io, error));
+				exit(1);
+			}
+			Verdict::TooSoon(wait) if submit_args.wait => {
+				sleep(wait);
+				continue;
+			}
+			Verdict::TooSoon(_) | Verdict::AlreadySolved | Verdict::Unknown(_) => exit(1),
+		}
+		break;
+	}
+}
+
+fn new(new_args: NewArgs) {
+	let year = new_args.year;
+	let mut scaffold = Scaffold::new(Path::new(".."), year);
+	let days = match new_args.day {
+		Some(day) => day..=day,
+		None => 1..=new::days(year),
+	};
+	let result = scaffold
+		.year()
+		.and_then(|_| days.into_iter().try_for_each(|day| scaffold.day(day)));
+	for path in &scaffold.changed {
+		println!("Wrote {}", path.display());
+	}
+	if let Err(error) = result {
+		fail(ErrorKind::Io, error);
+	}
+}
+
+fn watch(watch_args: WatchArgs) {
+	let WatchArgs { year, day, .. } = watch_args;
+	let source = PathBuf::from(format!("../{}/day_{:0>2}.rs", year, day));
+	if !source.exists() {
+		fail(
+			ErrorKind::InvalidValue,
+			format!("{} does not exist", source.display()),
+		);
+	}
+	let mut files = vec![source];
+	let mut args = vec![
+		"run".to_owned(),
+		format!("--year={}", year),
+		format!("--day={}", day),
+	];
+	if let Some(part) = watch_args.part {
+		args.push(format!("--part={}", submit::level(part)));
+	}
+	if watch_args.test {
+		args.push("--test".to_owned());
+	} else {
+		let input = watch_args
+			.input
+			.unwrap_or_else(|| utils::input::path(year, day));
+		args.push(format!("--input={}", input.display()));
+		files.push(input);
+	}
+	if watch_args.verbose {
+		args.push("--verbose".to_owned());
+	}
+	if let Some(level) = watch_args.trace {
+		args.push(format!("--trace={}", level));
+	}
+	let snapshot = Path::new(watch::TARGET_DIR).join("snapshot.toml");
+	let mut watcher = Watcher::new(files, Duration::from_millis(watch_args.interval));
+	let mut previous: Option<Snapshot> = None;
+	loop {
+		println!("Building {} day_{:0>2}", year, day);
+		if !watch::build() {
+			println!("Build failed");
+		} else {
+			match watch::run(&args, &snapshot) {
+				Some(current) => {
+					if let Some(previous) = &previous {
+						println!("Changes:");
+						for line in current.diff(previous) {
+							println!("  {}", line);
+						}
+					}
+					previous = Some(current);
+				}
+				None => println!("The day did not finish"),
+			}
+		}
+		println!();
+		println!("Waiting for changes");
+		for path in watcher.wait() {
+			println!("Changed {}", path.display());
+		}
+	}
+}
+
+fn report(report_args: ReportArgs) {
+	let root = Path::new("..");
+	let mut years = report::years(root).unwrap_or_else(|error| fail(ErrorKind::Io, error));
+	years.extend(YEARS.iter().map(|year| year.year));
+	if let Some(year) = report_args.year {
+		years.retain(|&y| y == year);
+	}
+	let manifest = |year| Manifest::load(year).unwrap_or_else(|error| fail(ErrorKind::Io, error));
+	let mut tables: BTreeMap<_, _> = years.iter().map(|&year| (year, Table::new(year))).collect();
+	let selected: Vec<_> = YEARS
+		.iter()
+		.filter(|year| years.contains(&year.year))
+		.flat_map(|year| year.numbers().into_iter().map(move |day| (year, day)))
+		.collect();
+	for (year, day) in &selected {
+		tables.get_mut(&year.year).unwrap().add_day(*day);
+	}
+
+	if let Some(path) = &report_args.cached {
+		let records = bench::load(path).unwrap_or_else(|error| fail(ErrorKind::Io, error));
+		let mut manifests = HashMap::new();
+		for record in records {
+			let (Some(table), Some(Phase::Part(part))) = (
+				tables.get_mut(&record.year),
+				Phase::from_name(&record.phase),
+			) else {
+				continue;
+			};
+			let status = if manifests
+				.entry(record.year)
+				.or_insert_with(|| manifest(record.year))
+				.recorded(record.day, part)
+			{
+				Status::Pass
+			} else {
+				Status::Missing
+			};
+			let time = Duration::from_nanos(record.median_ns as u64);
+			table.add(record.day, part, time, status);
+		}
+	} else {
+		let timeout = report_args.timeout.map(Duration::from_secs);
+		let mut manifests = HashMap::new();
+		// one day at a time, so that the times are not disturbed by the other days
+		let tasks: Vec<_> = selected
+			.iter()
+			.map(|&(year, day)| (year, day, Args::default()))
+			.collect();
+		workers::run(&tasks, 1, timeout, |index, report| {
+			let (year, day, _) = tasks[index];
+			println!("Running {} day_{:0>2}", year.year, day);
+			let Report::Finished(outcome) = report else {
+				println!("Timed out");
+				return;
+			};
+			for failure in &outcome.failures {
+				println!("{}", failure);
+			}
+			let manifest = manifests
+				.entry(year.year)
+				.or_insert_with(|| manifest(year.year));
+			for (part, answer) in &outcome.answers {
+				let time = outcome
+					.times
+					.iter()
+					.find(|(phase, _)| *phase == Phase::Part(*part))
+					.map_or_else(Duration::default, |(_, time)| *time);
+				let status = manifest.check(day, *part, answer);
+				tables
+					.get_mut(&year.year)
+					.unwrap()
+					.add(day, *part, time, status);
+			}
+		});
+	}
+
+	for (&year, table) in &mut tables {
+		for day in report::python_days(root, year) {
+			table.rows.entry(day).or_insert(Row::Python);
+		}
+	}
+	let tables: Vec<_> = tables
+		.into_values()
+		.filter(|table| !table.rows.is_empty())
+		.collect();
+	let path = &report_args.readme;
+	let readme = read_to_string(path).unwrap_or_else(|error| {
+		fail(
+			ErrorKind::Io,
+			format!("Cannot read {}: {}", path.display(), error),
+		)
+	});
+	write(path, report::update(&readme, &tables)).unwrap_or_else(|error| {
+		fail(
+			ErrorKind::Io,
+			format!("Cannot write {}: {}", path.display(), error),
+		)
+	});
+	println!("Wrote {}", path.display());
+}
+
+fn generate(generate_args: GenerateArgs) {
+	let (year, day) = select(Some(generate_args.year), Some(generate_args.day))[0];
+	let Some(generator) = year.generator(day) else {
+		fail(
+			ErrorKind::InvalidValue,
+			format!("{} day_{:0>2} has no generator", year.year, day),
+		);
+	};
+	let seed = generate_args.seed.unwrap_or_else(|| {
+		SystemTime::now()
+			.duration_since(UNIX_EPOCH)
+			.map_or(0, |time| time.as_nanos() as u64)
+	});
+	eprintln!("Seed {}", seed);
+	let mut rng = generate::seeded(seed);
+	let size = generate_args.size as usize;
+	let Some(steps) = generate_args.scale else {
+		let input = generator(&mut rng, size);
+		match &generate_args.output {
+			Some(path) => write(path, input).unwrap_or_else(|error| {
+				fail(
+					ErrorKind::Io,
+					format!("Cannot write {}: {}", path.display(), error),
+				)
+			}),
+			None => println!("{}", input),
+		}
+		return;
+	};
+	let mut failed = false;
+	for step in 0..steps {
+		let size = size << step;
+		let input = generator(&mut rng, size);
+		let outcome = year.run_on(day, &Args::default(), &input);
+		let times: Vec<_> = outcome
+			.times
+			.iter()
+			.map(|(phase, time)| format!("{} {:?}", phase, time))
+			.collect();
+		println!("Size {}: {}", size, times.join(", "));
+		for failure in &outcome.failures {
+			println!("{}", failure);
+			failed = true;
+		}
+	}
+	if failed {
+		exit(1);
+	}
+}
+
+fn main() {
+	// the panics of the days are reported along with their outcome
+	match Cli::parse().command {
+		Command::List => list(),
+		Command::Run(run_args) => runner::silence_panics(|| run(run_args)),
+		Command::Fetch(fetch_args) => fetch(fetch_args),
+		Command::Submit(submit_args) => runner::silence_panics(|| submit(submit_args)),
+		Command::New(new_args) => new(new_args),
+		Command::Watch(watch_args) => watch(watch_args),
+		Command::Report(report_args) => runner::silence_panics(|| report(report_args)),
+		Command::Generate(generate_args) => runner::silence_panics(|| generate(generate_args)),
+	}
+}
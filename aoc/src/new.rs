@@ -0,0 +1,334 @@
+use std::{
+	fs::{create_dir_all, read_to_string, write, File},
+	ops::Range,
+	path::{Path, PathBuf},
+	time::SystemTime,
+};
+
+const CARGO_TOML: &str = r#"[package]
+name = "advent_of_code_{year}"
+version = "0.1.0"
+authors = ["Andrea Princic <andreapri.99@gmail.com>"]
+edition = "2021"
+
+[lib]
+name = "advent_of_code_{year}"
+path = "lib.rs"
+
+[[bin]]
+name = "main"
+path = "main.rs"
+
+[dependencies]
+utils = { path = "../utils" }
+"#;
+
+const ANSWERS_TOML: &str = r#"# Expected answers on the default inputs, checked by `aoc run --check`.
+# Every day is a table like:
+#
+# [day_01]
+# part1 = 1234
+# part2 = "abc"
+"#;
+
+const LIB: &str = "utils::days!();\n";
+
+const MAIN: &str = r#"use utils::run_days;
+
+fn main() {
+	run_days!(advent_of_code_{year});
+}
+"#;
+
+const DAY: &str = r#"//! https://adventofcode.com/{year}/day/{day}
+//! https://adventofcode.com/{year}/day/{day}/input
+
+use utils::Solution;
+
+type Parsed = Vec<usize>;
+
+fn parse(_input: &str) -> Parsed {
+	vec![]
+}
+
+pub mod part1 {
+	use super::Parsed;
+
+	pub fn solve(_parsed: Parsed) -> usize {
+		0
+	}
+}
+
+pub mod part2 {
+	use super::Parsed;
+
+	pub fn solve(_parsed: Parsed) -> usize {
+		0
+	}
+}
+
+#[derive(Solution)]
+pub struct Day{dd};
+"#;
+
+fn fill(template: &str, year: u16, day: u8) -> String {
+	template
+		.replace("{year}", &year.to_string())
+		.replace("{dd}", &format!("{:0>2}", day))
+		.replace("{day}", &day.to_string())
+}
+
+/// Advent of Code has 12 days since 2025.
+pub fn days(year: u16) -> u8 {
+	if year >= 2025 {
+		12
+	} else {
+		25
+	}
+}
+
+/// The number at the start of what follows `prefix` in `line`.
+fn number_after(line: &str, prefix: &str) -> Option<u32> {
+	let (_, rest) = line.split_once(prefix)?;
+	let digits: String = rest.chars().take_while(char::is_ascii_digit).collect();
+	digits.parse().ok()
+}
+
+enum Slot {
+	Present,
+	/// Before the first line with a greater key.
+	Before(usize),
+	/// After the last line with a key, all of them being smaller.
+	After(usize),
+	/// No line in the range has a key.
+	Nowhere,
+}
+
+fn slot(
+	lines: &[String],
+	range: Range<usize>,
+	key: u32,
+	line_key: impl Fn(&str) -> Option<u32>,
+) -> Slot {
+	let mut slot = Slot::Nowhere;
+	for i in range {
+		match line_key(&lines[i]) {
+			Some(k) if k == key => return Slot::Present,
+			Some(k) if k > key => return Slot::Before(i),
+			Some(_) => slot = Slot::After(i + 1),
+			None => {}
+		}
+	}
+	slot
+}
+
+/// A file edited as lines, written back only if something changed.
+struct Lines {
+	path: PathBuf,
+	lines: Vec<String>,
+	trailing_newline: bool,
+	changed: bool,
+}
+
+impl Lines {
+	fn read(path: PathBuf) -> Result<Self, String> {
+		let content = read_to_string(&path)
+			.map_err(|error| format!("Cannot read {}: {}", path.display(), error))?;
+		Ok(Self {
+			path,
+			lines: content.lines().map(str::to_owned).collect(),
+			trailing_newline: content.ends_with('\n'),
+			changed: false,
+		})
+	}
+
+	fn insert(&mut self, i: usize, text: &str) {
+		self.lines.splice(i..i, text.lines().map(str::to_owned));
+		self.changed = true;
+	}
+
+	fn find(&self, from: usize, matches: impl Fn(&str) -> bool) -> Option<usize> {
+		(from..self.lines.len()).find(|&i| matches(&self.lines[i]))
+	}
+
+	/// Returns whether the file was written.
+	fn save(self) -> Result<bool, String> {
+		if !self.changed {
+			return Ok(false);
+		}
+		let mut content = self.lines.join("\n");
+		if self.trailing_newline {
+			content.push('\n');
+		}
+		write(&self.path, content)
+			.map_err(|error| format!("Cannot write {}: {}", self.path.display(), error))?;
+		Ok(true)
+	}
+}
+
+/// Creates the crate of a year and the files of its days, from the root of the repository.
+/// Existing files are never overwritten, only extended with the entries of the new year.
+pub struct Scaffold {
+	root: PathBuf,
+	year: u16,
+	/// Files created or modified so far.
+	pub changed: Vec<PathBuf>,
+}
+
+impl Scaffold {
+	pub fn new(root: &Path, year: u16) -> Self {
+		Self {
+			root: root.to_owned(),
+			year,
+			changed: Vec::new(),
+		}
+	}
+
+	fn dir(&self) -> PathBuf {
+		self.root.join(self.year.to_string())
+	}
+
+	fn create(&mut self, path: PathBuf, content: &str) -> Result<(), String> {
+		if !path.exists() {
+			write(&path, content)
+				.map_err(|error| format!("Cannot write {}: {}", path.display(), error))?;
+			self.changed.push(path);
+		}
+		Ok(())
+	}
+
+	fn save(&mut self, lines: Lines) -> Result<(), String> {
+		let path = lines.path.clone();
+		if lines.save()? {
+			self.changed.push(path);
+		}
+		Ok(())
+	}
+
+	/// Creates the missing files of the year and registers it in the `aoc` crate.
+	pub fn year(&mut self) -> Result<(), String> {
+		let dir = self.dir();
+		create_dir_all(&dir)
+			.map_err(|error| format!("Cannot create {}: {}", dir.display(), error))?;
+		let year = self.year;
+		self.create(dir.join("Cargo.toml"), &fill(CARGO_TOML, year, 0))?;
+		self.create(dir.join("answers.toml"), ANSWERS_TOML)?;
+		self.create(dir.join("lib.rs"), LIB)?;
+		self.create(dir.join("main.rs"), &fill(MAIN, year, 0))?;
+
+		let mut cargo = Lines::read(self.root.join("aoc").join("Cargo.toml"))?;
+		let dependency = fill("advent_of_code_{year} = { path = \"../{year}\" }", year, 0);
+		let range = 0..cargo.lines.len();
+		match slot(&cargo.lines, range, year as u32, |line| {
+			number_after(line.strip_prefix("advent_of_code_")?, "")
+		}) {
+			Slot::Present => {}
+			Slot::Before(i) | Slot::After(i) => cargo.insert(i, &dependency),
+			Slot::Nowhere => {
+				let i = cargo.lines.len();
+				cargo.insert(i, &dependency);
+			}
+		}
+		self.save(cargo)?;
+
+		let mut years = Lines::read(self.root.join("aoc").join("src").join("years.rs"))?;
+		let start = years
+			.find(0, |line| line.starts_with("pub const YEARS"))
+			.ok_or("No YEARS in aoc/src/years.rs")?;
+		let end = years
+			.find(start, |line| line == "];")
+			.ok_or("No end of YEARS in aoc/src/years.rs")?;
+		let entry = fill("\tyear!(advent_of_code_{year}),", year, 0);
+		match slot(&years.lines, start..end, year as u32, |line| {
+			number_after(line.strip_prefix("\tyear!(advent_of_code_")?, "")
+		}) {
+			Slot::Present => {}
+			Slot::Before(i) | Slot::After(i) => years.insert(i, &entry),
+			Slot::Nowhere => years.insert(end, &entry),
+		}
+		self.save(years)
+	}
+
+	/// Creates the file of a day, which `days!` finds by itself once `lib.rs` is rebuilt.
+	pub fn day(&mut self, day: u8) -> Result<(), String> {
+		let dir = self.dir();
+		let lib = dir.join("lib.rs");
+		if !read_to_string(&lib)
+			.map_err(|error| format!("Cannot read {}: {}", lib.display(), error))?
+			.contains("days!")
+		{
+			return Err(format!(
+				"{} does not register its days with days!",
+				lib.display()
+			));
+		}
+		let path = dir.join(format!("day_{:0>2}.rs", day));
+		if path.exists() {
+			return Ok(());
+		}
+		self.create(path, &fill(DAY, self.year, day))?;
+		// Cargo only tracks the files it already knows of
+		File::options()
+			.append(true)
+			.open(&lib)
+			.and_then(|file| file.set_modified(SystemTime::now()))
+			.map_err(|error| format!("Cannot touch {}: {}", lib.display(), error))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{env, fs::remove_dir_all};
+
+	use super::*;
+
+	#[test]
+	fn scaffold() {
+		let root = env::temp_dir().join(format!("aoc_new_{}", std::process::id()));
+		create_dir_all(root.join("aoc").join("src")).unwrap();
+		write(
+			root.join("aoc").join("Cargo.toml"),
+			"[dependencies]\nadvent_of_code_2016 = { path = \"../2016\" }\nadvent_of_code_2024 = { path = \"../2024\" }\n",
+		)
+		.unwrap();
+		write(
+			root.join("aoc").join("src").join("years.rs"),
+			"pub const YEARS: &[Year] = &[\n\tyear!(advent_of_code_2024),\n];\n",
+		)
+		.unwrap();
+		let read = |path: &[&str]| {
+			read_to_string(path.iter().fold(root.clone(), |p, c| p.join(c))).unwrap()
+		};
+
+		let mut scaffold = Scaffold::new(&root, 2025);
+		scaffold.year().unwrap();
+		scaffold.day(3).unwrap();
+		write(root.join("2025").join("day_03.rs"), "solved").unwrap();
+		let mut scaffold = Scaffold::new(&root, 2025);
+		scaffold.year().unwrap();
+		scaffold.day(3).unwrap();
+		assert!(scaffold.changed.is_empty());
+		assert_eq!(read(&["2025", "day_03.rs"]), "solved");
+
+		let mut scaffold = Scaffold::new(&root, 2017);
+		scaffold.year().unwrap();
+		assert_eq!(read(&["2017", "lib.rs"]), "utils::days!();\n");
+		assert_eq!(
+			read(&["aoc", "Cargo.toml"]),
+			"[dependencies]\nadvent_of_code_2016 = { path = \"../2016\" }\nadvent_of_code_2017 = { path = \"../2017\" }\nadvent_of_code_2024 = { path = \"../2024\" }\nadvent_of_code_2025 = { path = \"../2025\" }\n"
+		);
+		assert_eq!(
+			read(&["aoc", "src", "years.rs"]),
+			"pub const YEARS: &[Year] = &[\n\tyear!(advent_of_code_2017),\n\tyear!(advent_of_code_2024),\n\tyear!(advent_of_code_2025),\n];\n"
+		);
+
+		scaffold.day(2).unwrap();
+		assert!(read(&["2017", "day_02.rs"]).contains("pub struct Day02;"));
+		// a crate listing its days
+		write(root.join("2017").join("lib.rs"), "pub mod day_01;\n").unwrap();
+		assert!(scaffold.day(4).is_err());
+		assert!(!root.join("2017").join("day_04.rs").exists());
+
+		remove_dir_all(root).unwrap();
+	}
+}
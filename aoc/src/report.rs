@@ -0,0 +1,250 @@
+use std::{
+	collections::{BTreeMap, BTreeSet},
+	fs::read_dir,
+	path::Path,
+	time::Duration,
+};
+
+use utils::runner::Part;
+
+use crate::check::Status;
+
+/// What the report says about a day.
+pub enum Row {
+	/// The time and the check of both parts, `None` if the part gave no answer.
+	Rust([Option<(Duration, Status)>; 2]),
+	/// The day has only a Python solution, which is never run.
+	Python,
+}
+
+/// The generated table of a year, kept in the README between two markers.
+pub struct Table {
+	pub year: u16,
+	pub rows: BTreeMap<u8, Row>,
+}
+
+fn index(part: Part) -> usize {
+	match part {
+		Part::First => 0,
+		Part::Second => 1,
+	}
+}
+
+fn mark(status: &Status) -> &'static str {
+	match status {
+		Status::Pass => "✓",
+		Status::Fail(_) => "✗",
+		Status::Missing => "?",
+	}
+}
+
+impl Table {
+	pub fn new(year: u16) -> Self {
+		Self {
+			year,
+			rows: BTreeMap::new(),
+		}
+	}
+
+	pub fn add(&mut self, day: u8, part: Part, time: Duration, status: Status) {
+		let row = self
+			.rows
+			.entry(day)
+			.or_insert_with(|| Row::Rust([None, None]));
+		if let Row::Rust(parts) = row {
+			parts[index(part)] = Some((time, status));
+		}
+	}
+
+	/// Adds a day that gave no answer, if it is not there already.
+	pub fn add_day(&mut self, day: u8) {
+		self.rows.entry(day).or_insert(Row::Rust([None, None]));
+	}
+
+	fn is_python(&self) -> bool {
+		self.rows.values().all(|row| matches!(row, Row::Python))
+	}
+
+	fn markers(year: u16) -> (String, String) {
+		(
+			format!("<!-- report {} -->", year),
+			format!("<!-- /report {} -->", year),
+		)
+	}
+
+	pub fn markdown(&self) -> String {
+		let (start, end) = Self::markers(self.year);
+		let mut lines = vec![
+			start,
+			"| Day | Part 1 | Part 2 |".to_owned(),
+			"|---:|---:|---:|".to_owned(),
+		];
+		let mut total = Duration::default();
+		for (day, row) in &self.rows {
+			let (extension, cells) = match row {
+				Row::Python => ("py", ["Python only".to_owned(), String::new()]),
+				Row::Rust(parts) => (
+					"rs",
+					parts.each_ref().map(|part| match part {
+						Some((time, status)) => {
+							total += *time;
+							format!("{:.1?} {}", time, mark(status))
+						}
+						None => "-".to_owned(),
+					}),
+				),
+			};
+			lines.push(format!(
+				"| [{}]({}/day_{:0>2}.{}) | {} | {} |",
+				day, self.year, day, extension, cells[0], cells[1]
+			));
+		}
+		if !self.is_python() {
+			lines.push(format!("| Total | | {:.1?} |", total));
+		}
+		lines.push(end);
+		lines.join("\n")
+	}
+}
+
+/// The days of a year with a Python solution, found as `YYYY/day_XX.py` from the root of the repository.
+pub fn python_days(root: &Path, year: u16) -> BTreeSet<u8> {
+	let Ok(entries) = read_dir(root.join(year.to_string())) else {
+		return BTreeSet::new();
+	};
+	entries
+		.filter_map(|entry| {
+			let name = entry.ok()?.file_name().into_string().ok()?;
+			name.strip_prefix("day_")?.strip_suffix(".py")?.parse().ok()
+		})
+		.collect()
+}
+
+/// The years with a directory in the root of the repository.
+pub fn years(root: &Path) -> Result<BTreeSet<u16>, String> {
+	let entries =
+		read_dir(root).map_err(|error| format!("Cannot read {}: {}", root.display(), error))?;
+	Ok(entries
+		.filter_map(|entry| {
+			let entry = entry.ok()?;
+			entry.file_type().ok()?.is_dir().then_some(())?;
+			entry.file_name().into_string().ok()?.parse().ok()
+		})
+		.collect())
+}
+
+fn heading_year(line: &str) -> Option<u16> {
+	line.strip_prefix("## [")?.get(..4)?.parse().ok()
+}
+
+/// Replaces the table of every year in the README, or adds it at the end of the section of the year.
+/// Years without a section get a new one, keeping the sections sorted from the most recent.
+pub fn update(readme: &str, tables: &[Table]) -> String {
+	let mut lines: Vec<String> = readme.lines().map(str::to_owned).collect();
+	for table in tables {
+		let markdown = table.markdown();
+		let (start, end) = Table::markers(table.year);
+		let existing = lines.iter().position(|line| *line == start).and_then(|s| {
+			let e = lines[s..].iter().position(|line| *line == end)?;
+			Some(s..s + e + 1)
+		});
+		if let Some(range) = existing {
+			lines.splice(range, markdown.lines().map(str::to_owned));
+			continue;
+		}
+		let heading = lines
+			.iter()
+			.position(|line| heading_year(line) == Some(table.year));
+		let (at, text) = match heading {
+			Some(heading) => {
+				let next = (heading + 1..lines.len())
+					.find(|&i| lines[i].starts_with("## "))
+					.unwrap_or(lines.len());
+				let last = (heading..next)
+					.rev()
+					.find(|&i| !lines[i].trim().is_empty())
+					.unwrap();
+				(last + 1, format!("\n{}", markdown))
+			}
+			None => {
+				let language = if table.is_python() { "Python" } else { "Rust" };
+				let at = lines
+					.iter()
+					.position(|line| heading_year(line).is_some_and(|year| year < table.year))
+					.unwrap_or(lines.len());
+				let section = format!(
+					"## [{year}]({year}) ({})\n\n{}\n\n",
+					language,
+					markdown,
+					year = table.year
+				);
+				if at == lines.len() {
+					(at, format!("\n{}", section.trim_end()))
+				} else {
+					(at, section)
+				}
+			}
+		};
+		lines.splice(at..at, text.lines().map(str::to_owned));
+	}
+	let mut readme = lines.join("\n");
+	readme.push('\n');
+	readme
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn tables() {
+		let readme = "# Advent of Code\n\n## [2022](2022) (Python)\n\nFirst.\n\n## [2015](2015) (Rust)\n\nLast.\n";
+		let mut rust = Table::new(2023);
+		rust.add(1, Part::First, Duration::from_micros(1500), Status::Pass);
+		rust.add(1, Part::Second, Duration::from_micros(500), Status::Missing);
+		rust.add_day(2);
+		let mut python = Table::new(2022);
+		python.rows.insert(1, Row::Python);
+		let updated = update(readme, &[rust, python]);
+		assert_eq!(
+			updated,
+			"# Advent of Code
+
+## [2023](2023) (Rust)
+
+<!-- report 2023 -->
+| Day | Part 1 | Part 2 |
+|---:|---:|---:|
+| [1](2023/day_01.rs) | 1.5ms ✓ | 500.0µs ? |
+| [2](2023/day_02.rs) | - | - |
+| Total | | 2.0ms |
+<!-- /report 2023 -->
+
+## [2022](2022) (Python)
+
+First.
+
+<!-- report 2022 -->
+| Day | Part 1 | Part 2 |
+|---:|---:|---:|
+| [1](2022/day_01.py) | Python only |  |
+<!-- /report 2022 -->
+
+## [2015](2015) (Rust)
+
+Last.
+"
+		);
+
+		let mut rust = Table::new(2023);
+		rust.add(
+			1,
+			Part::First,
+			Duration::from_millis(3),
+			Status::Fail("1".to_owned()),
+		);
+		let updated = update(&updated, &[rust]);
+		assert!(updated.contains("| [1](2023/day_01.rs) | 3.0ms ✗ | - |\n| Total | | 3.0ms |\n"));
+		assert!(!updated.contains("day_02.rs"));
+	}
+}
@@ -0,0 +1,256 @@
+use std::{
+	fmt::Display,
+	fs::{create_dir_all, read_to_string, OpenOptions},
+	io::{ErrorKind, Write},
+	path::PathBuf,
+	time::Duration,
+};
+
+use utils::{input, runner::Part, Answer};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Hint {
+	TooHigh,
+	TooLow,
+}
+
+/// What the website said about a submitted answer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Verdict {
+	Correct,
+	Wrong(Option<Hint>),
+	/// Answers are rate-limited, so this is how long to wait before trying again.
+	TooSoon(Duration),
+	AlreadySolved,
+	/// The text of a page that could not be understood.
+	Unknown(String),
+}
+
+impl Verdict {
+	/// Reads the verdict from the `<article>` of the response page.
+	pub fn parse(page: &str) -> Self {
+		let article = page
+			.split_once("<article>")
+			.and_then(|(_, article)| article.split_once("</article>"))
+			.map_or(page, |(article, _)| article);
+		let text = strip_tags(article);
+		if text.contains("That's the right answer") {
+			Verdict::Correct
+		} else if text.contains("That's not the right answer") {
+			Verdict::Wrong(if text.contains("too high") {
+				Some(Hint::TooHigh)
+			} else if text.contains("too low") {
+				Some(Hint::TooLow)
+			} else {
+				None
+			})
+		} else if let Some((before, _)) = text.split_once(" left to wait") {
+			Verdict::TooSoon(parse_wait(before))
+		} else if text.contains("Did you already complete it?") {
+			Verdict::AlreadySolved
+		} else {
+			Verdict::Unknown(text)
+		}
+	}
+}
+
+impl Display for Verdict {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Verdict::Correct => write!(f, "Correct!"),
+			Verdict::Wrong(None) => write!(f, "Wrong answer"),
+			Verdict::Wrong(Some(Hint::TooHigh)) => write!(f, "Wrong answer, too high"),
+			Verdict::Wrong(Some(Hint::TooLow)) => write!(f, "Wrong answer, too low"),
+			Verdict::TooSoon(wait) => write!(f, "Answered too recently, wait {:?}", wait),
+			Verdict::AlreadySolved => write!(f, "This part is already solved"),
+			Verdict::Unknown(text) => write!(f, "Unexpected response: {}", text),
+		}
+	}
+}
+
+fn strip_tags(html: &str) -> String {
+	let mut text = String::new();
+	let mut in_tag = false;
+	for char in html.chars() {
+		match char {
+			'<' => in_tag = true,
+			'>' => in_tag = false,
+			_ if !in_tag => text.push(char),
+			_ => {}
+		}
+	}
+	text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Reads the end of `You have 1m 5s`.
+fn parse_wait(text: &str) -> Duration {
+	let seconds = text
+		.split_whitespace()
+		.rev()
+		.map_while(|token| {
+			let (number, unit) = token.split_at(token.len().checked_sub(1)?);
+			let number: u64 = number.parse().ok()?;
+			match unit {
+				"s" => Some(number),
+				"m" => Some(number * 60),
+				"h" => Some(number * 3600),
+				_ => None,
+			}
+		})
+		.sum();
+	Duration::from_secs(seconds)
+}
+
+/// The wrong answers already submitted for a day, kept next to its input
+/// in `day_XX_guesses.txt` as lines like `2 high 1234`.
+pub struct Guesses {
+	path: PathBuf,
+	guesses: Vec<(Part, Option<Hint>, Answer)>,
+}
+
+/// How the website calls a part.
+pub fn level(part: Part) -> &'static str {
+	match part {
+		Part::First => "1",
+		Part::Second => "2",
+	}
+}
+
+impl Guesses {
+	pub fn load(year: u16, day: u8) -> Result<Self, String> {
+		let path = input::path(year, day).with_file_name(format!("day_{:0>2}_guesses.txt", day));
+		let content = match read_to_string(&path) {
+			Ok(content) => content,
+			Err(error) if error.kind() == ErrorKind::NotFound => String::new(),
+			Err(error) => return Err(format!("Cannot read {}: {}", path.display(), error)),
+		};
+		let guesses = content
+			.lines()
+			.filter(|line| !line.is_empty())
+			.map(|line| {
+				let invalid = || format!("Invalid line `{}` in {}", line, path.display());
+				let mut fields = line.splitn(3, ' ');
+				let (Some(part), Some(hint), Some(answer)) =
+					(fields.next(), fields.next(), fields.next())
+				else {
+					return Err(invalid());
+				};
+				let hint = match hint {
+					"wrong" => None,
+					"high" => Some(Hint::TooHigh),
+					"low" => Some(Hint::TooLow),
+					_ => return Err(invalid()),
+				};
+				Ok((
+					part.parse().map_err(|_| invalid())?,
+					hint,
+					answer.parse().unwrap(),
+				))
+			})
+			.collect::<Result<_, _>>()?;
+		Ok(Self { path, guesses })
+	}
+
+	/// Tells why the answer is known to be wrong, if it is,
+	/// either because it was already submitted or because it is beyond a previous hint.
+	pub fn rejects(&self, part: Part, answer: &Answer) -> Option<String> {
+		self.guesses
+			.iter()
+			.filter(|(p, ..)| *p == part)
+			.find_map(|(_, hint, guess)| {
+				if guess == answer {
+					return Some(format!("{} was already submitted", answer));
+				}
+				let (Answer::Number(guess), Answer::Number(number)) = (guess, answer) else {
+					return None;
+				};
+				match hint {
+					Some(Hint::TooHigh) if number >= guess => {
+						Some(format!("{} was too high, so {} is too", guess, number))
+					}
+					Some(Hint::TooLow) if number <= guess => {
+						Some(format!("{} was too low, so {} is too", guess, number))
+					}
+					_ => None,
+				}
+			})
+	}
+
+	pub fn add(&mut self, part: Part, hint: Option<Hint>, answer: Answer) -> Result<(), String> {
+		let hint_name = match hint {
+			None => "wrong",
+			Some(Hint::TooHigh) => "high",
+			Some(Hint::TooLow) => "low",
+		};
+		create_dir_all(self.path.parent().unwrap())
+			.and_then(|_| {
+				OpenOptions::new()
+					.create(true)
+					.append(true)
+					.open(&self.path)
+			})
+			.and_then(|mut file| writeln!(file, "{} {} {}", level(part), hint_name, answer))
+			.map_err(|error| format!("Cannot write {}: {}", self.path.display(), error))?;
+		self.guesses.push((part, hint, answer));
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn verdicts() {
+		let page = |text: &str| format!("<main>\n<article><p>{}</p></article>\n</main>", text);
+		assert_eq!(
+			Verdict::parse(&page(
+				"That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer."
+			)),
+			Verdict::Correct
+		);
+		assert_eq!(
+			Verdict::parse(&page(
+				"That's not the right answer; your answer is too low.  If you're stuck, ..."
+			)),
+			Verdict::Wrong(Some(Hint::TooLow))
+		);
+		assert_eq!(
+			Verdict::parse(&page(
+				"That's not the right answer.  If you're stuck, make sure you're using the full input data"
+			)),
+			Verdict::Wrong(None)
+		);
+		assert_eq!(
+			Verdict::parse(&page(
+				"You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait. <a href=\"/2024/day/7\">[Return to Day 7]</a>"
+			)),
+			Verdict::TooSoon(Duration::from_secs(65))
+		);
+		assert_eq!(
+			Verdict::parse(&page(
+				"You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2024/day/7\">[Return to Day 7]</a>"
+			)),
+			Verdict::AlreadySolved
+		);
+	}
+
+	#[test]
+	fn rejected_guesses() {
+		let guesses = Guesses {
+			path: PathBuf::new(),
+			guesses: vec![
+				(Part::First, Some(Hint::TooHigh), Answer::from(100)),
+				(Part::First, Some(Hint::TooLow), Answer::from(10)),
+				(Part::Second, None, Answer::from("abc")),
+			],
+		};
+		assert!(guesses.rejects(Part::First, &Answer::from(150)).is_some());
+		assert!(guesses.rejects(Part::First, &Answer::from(10)).is_some());
+		assert!(guesses.rejects(Part::First, &Answer::from(50)).is_none());
+		assert!(guesses
+			.rejects(Part::Second, &Answer::from("abc"))
+			.is_some());
+		assert!(guesses.rejects(Part::Second, &Answer::from(150)).is_none());
+	}
+}
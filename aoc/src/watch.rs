@@ -0,0 +1,190 @@
+use std::{
+	collections::BTreeMap,
+	env,
+	fs::{metadata, read_to_string, remove_file, write},
+	path::{Path, PathBuf},
+	process::Command,
+	thread::sleep,
+	time::{Duration, SystemTime},
+};
+
+use serde::{Deserialize, Serialize};
+use utils::{bench::Phase, runner::Outcome};
+
+/// Where the watched binary is built, apart from the one running the watch,
+/// which could not be replaced while running on some platforms.
+pub const TARGET_DIR: &str = "target/watch";
+
+/// What a run of the watched day produced, written by `run --snapshot`.
+#[derive(Default, Serialize, Deserialize)]
+pub struct Snapshot {
+	pub elapsed: Duration,
+	/// Answers by part, like `part1`.
+	pub answers: BTreeMap<String, String>,
+}
+
+impl Snapshot {
+	pub fn new(outcome: &Outcome) -> Self {
+		Self {
+			elapsed: outcome.elapsed,
+			answers: outcome
+				.answers
+				.iter()
+				.map(|(part, answer)| (Phase::Part(*part).to_string(), answer.to_string()))
+				.collect(),
+		}
+	}
+
+	pub fn save(&self, path: &Path) -> Result<(), String> {
+		let content = toml::to_string(self).map_err(|error| error.to_string())?;
+		write(path, content).map_err(|error| format!("Cannot write {}: {}", path.display(), error))
+	}
+
+	/// Returns `None` if the run did not get to write it.
+	pub fn load(path: &Path) -> Option<Self> {
+		toml::from_str(&read_to_string(path).ok()?).ok()
+	}
+
+	/// Describes how the answers and the time changed since the `previous` run.
+	pub fn diff(&self, previous: &Snapshot) -> Vec<String> {
+		let mut lines = Vec::new();
+		for (part, answer) in &self.answers {
+			match previous.answers.get(part) {
+				Some(old) if old == answer => lines.push(format!("{}: unchanged", part)),
+				Some(old) => lines.push(format!("{}: {} -> {}", part, old, answer)),
+				None => lines.push(format!("{}: {} (new)", part, answer)),
+			}
+		}
+		for (part, old) in &previous.answers {
+			if !self.answers.contains_key(part) {
+				lines.push(format!("{}: {} -> none", part, old));
+			}
+		}
+		let (old, new) = (previous.elapsed.as_secs_f64(), self.elapsed.as_secs_f64());
+		let change = if old > 0.0 {
+			format!(", {:+.1}%", (new - old) / old * 100.0)
+		} else {
+			String::new()
+		};
+		lines.push(format!(
+			"time: {:?} -> {:?}{}",
+			previous.elapsed, self.elapsed, change
+		));
+		lines
+	}
+}
+
+/// Polls the modification times of some files, missing files included.
+pub struct Watcher {
+	files: Vec<PathBuf>,
+	stamps: Vec<Option<SystemTime>>,
+	interval: Duration,
+}
+
+impl Watcher {
+	pub fn new(files: Vec<PathBuf>, interval: Duration) -> Self {
+		let stamps = stamps(&files);
+		Self {
+			files,
+			stamps,
+			interval,
+		}
+	}
+
+	/// Blocks until one of the files is created, modified or deleted,
+	/// and returns the ones that changed.
+	pub fn wait(&mut self) -> Vec<&Path> {
+		loop {
+			sleep(self.interval);
+			let stamps = stamps(&self.files);
+			if stamps != self.stamps {
+				let changed = self
+					.files
+					.iter()
+					.zip(stamps.iter().zip(&self.stamps))
+					.filter(|(_, (new, old))| new != old)
+					.map(|(file, _)| file.as_path())
+					.collect();
+				self.stamps = stamps;
+				return changed;
+			}
+		}
+	}
+}
+
+fn stamps(files: &[PathBuf]) -> Vec<Option<SystemTime>> {
+	files
+		.iter()
+		.map(|file| metadata(file).and_then(|m| m.modified()).ok())
+		.collect()
+}
+
+/// Builds the runner in [`TARGET_DIR`], with the same profile and features as the current one.
+pub fn build() -> bool {
+	let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_owned());
+	let mut command = Command::new(cargo);
+	command.args(["build", "--quiet", "--target-dir", TARGET_DIR]);
+	if !cfg!(debug_assertions) {
+		command.arg("--release");
+	}
+	if cfg!(feature = "memory") {
+		command.args(["--features", "memory"]);
+	}
+	command.status().is_ok_and(|status| status.success())
+}
+
+/// Runs the freshly built runner with `args`, returning what it saved in `snapshot`.
+pub fn run(args: &[String], snapshot: &Path) -> Option<Snapshot> {
+	let profile = if cfg!(debug_assertions) {
+		"debug"
+	} else {
+		"release"
+	};
+	let binary = Path::new(TARGET_DIR)
+		.join(profile)
+		.join(format!("aoc{}", env::consts::EXE_SUFFIX));
+	let _ = remove_file(snapshot);
+	// the output of the day goes straight to the terminal
+	Command::new(binary)
+		.args(args)
+		.arg("--snapshot")
+		.arg(snapshot)
+		.status()
+		.ok()?;
+	Snapshot::load(snapshot)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn diff() {
+		let snapshot = |elapsed, answers: &[(&str, &str)]| Snapshot {
+			elapsed: Duration::from_millis(elapsed),
+			answers: answers
+				.iter()
+				.map(|&(part, answer)| (part.to_owned(), answer.to_owned()))
+				.collect(),
+		};
+		let previous = snapshot(200, &[("part1", "12"), ("part2", "abc")]);
+		let current = snapshot(150, &[("part1", "12"), ("part2", "abd")]);
+		assert_eq!(
+			current.diff(&previous),
+			[
+				"part1: unchanged",
+				"part2: abc -> abd",
+				"time: 200ms -> 150ms, -25.0%"
+			]
+		);
+		let current = snapshot(200, &[("part1", "13")]);
+		assert_eq!(
+			current.diff(&previous),
+			[
+				"part1: 12 -> 13",
+				"part2: abc -> none",
+				"time: 200ms -> 200ms, +0.0%"
+			]
+		);
+	}
+}
@@ -0,0 +1,207 @@
+use std::{
+	sync::mpsc::{channel, RecvTimeoutError},
+	thread,
+	time::{Duration, Instant},
+};
+
+use utils::{
+	memory,
+	runner::{Args, Outcome},
+};
+
+use crate::years::Year;
+
+/// Some solutions recurse deeply, and spawned threads only get 2 MiB of stack by default.
+const STACK_SIZE: usize = 256 * 1024 * 1024;
+
+/// What became of a job started by [`run`].
+pub enum Report<T = Outcome> {
+	Finished(T),
+	/// The job was still running after the timeout, and has been left behind.
+	TimedOut(Duration),
+}
+
+/// Runs the selected days, each with its own arguments, on up to `jobs` threads,
+/// calling `report` with the index of every day in `selected`, in order,
+/// as soon as the day and all the previous ones are done.
+/// A thread cannot be stopped, so a day that timed out keeps running in the background:
+/// while counting allocations no other day starts until it is done, since they would be counted together.
+pub fn run(
+	selected: &[(&'static Year, u8, Args)],
+	jobs: usize,
+	timeout: Option<Duration>,
+	report: impl FnMut(usize, Report),
+) {
+	let tasks = selected
+		.iter()
+		.map(|&(year, day, ref args)| {
+			let args = args.clone();
+			move || year.run(day, &args)
+		})
+		.collect();
+	run_jobs(tasks, jobs, timeout, memory::is_counting(), report);
+}
+
+/// Runs the jobs like [`run`] runs the days, waiting for the ones that timed out
+/// before starting any other if `wait_abandoned`.
+fn run_jobs<T: Send + 'static>(
+	tasks: Vec<impl FnOnce() -> T + Send + 'static>,
+	jobs: usize,
+	timeout: Option<Duration>,
+	wait_abandoned: bool,
+	mut report: impl FnMut(usize, Report<T>),
+) {
+	let (sender, receiver) = channel();
+	let total = tasks.len();
+	let mut tasks = tasks.into_iter().enumerate();
+	let mut reports: Vec<Option<Report<T>>> = (0..total).map(|_| None).collect();
+	// indexes of the jobs being run, along with their start
+	let mut running: Vec<(usize, Instant)> = Vec::new();
+	// indexes of the jobs that timed out but are still running
+	let mut abandoned: Vec<usize> = Vec::new();
+	let mut reported = 0;
+	while reported < total {
+		while running.len() < jobs && (!wait_abandoned || abandoned.is_empty()) {
+			let Some((index, task)) = tasks.next() else {
+				break;
+			};
+			let sender = sender.clone();
+			thread::Builder::new()
+				.stack_size(STACK_SIZE)
+				.spawn(move || {
+					// the receiver is gone only if the job timed out
+					let _ = sender.send((index, task()));
+				})
+				.unwrap();
+			running.push((index, Instant::now()));
+		}
+
+		if let Some(ready) = reports[reported].take() {
+			report(reported, ready);
+			reported += 1;
+			continue;
+		}
+
+		let deadline =
+			timeout.and_then(|timeout| running.iter().map(|&(_, start)| start + timeout).min());
+		let received = match deadline {
+			Some(deadline) => {
+				receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+			}
+			None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+		};
+		match received {
+			Ok((index, result)) => {
+				// jobs that timed out are not running anymore, and their result is dropped
+				if let Some(position) = running.iter().position(|&(i, _)| i == index) {
+					running.remove(position);
+					reports[index] = Some(Report::Finished(result));
+				} else {
+					abandoned.retain(|&i| i != index);
+				}
+			}
+			Err(RecvTimeoutError::Timeout) => {
+				let timeout = timeout.unwrap();
+				running.retain(|&(index, start)| {
+					let timed_out = start.elapsed() >= timeout;
+					if timed_out {
+						reports[index] = Some(Report::TimedOut(timeout));
+						abandoned.push(index);
+					}
+					!timed_out
+				});
+			}
+			Err(RecvTimeoutError::Disconnected) => unreachable!("the sender is still alive"),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::sync::{
+		atomic::{AtomicBool, Ordering::SeqCst},
+		Arc,
+	};
+
+	use super::*;
+
+	fn finished(reports: Vec<(usize, Report<u64>)>) -> Vec<(usize, Option<u64>)> {
+		reports
+			.into_iter()
+			.map(|(index, report)| match report {
+				Report::Finished(result) => (index, Some(result)),
+				Report::TimedOut(_) => (index, None),
+			})
+			.collect()
+	}
+
+	#[test]
+	fn in_order() {
+		// the later jobs are done first
+		let tasks: Vec<_> = (0..6)
+			.map(|i| {
+				move || {
+					thread::sleep(Duration::from_millis(60 - i * 10));
+					i
+				}
+			})
+			.collect();
+		let mut reports = Vec::new();
+		run_jobs(tasks, 3, None, false, |index, report| {
+			reports.push((index, report))
+		});
+		assert_eq!(
+			finished(reports),
+			(0..6).map(|i| (i as usize, Some(i))).collect::<Vec<_>>()
+		);
+	}
+
+	#[test]
+	fn timed_out() {
+		let tasks: Vec<_> = [2000, 0, 10]
+			.into_iter()
+			.map(|millis| {
+				move || {
+					thread::sleep(Duration::from_millis(millis));
+					millis
+				}
+			})
+			.collect();
+		let mut reports = Vec::new();
+		let timeout = Duration::from_millis(200);
+		run_jobs(tasks, 2, Some(timeout), false, |index, report| {
+			reports.push((index, report))
+		});
+		assert!(matches!(reports[0], (0, Report::TimedOut(t)) if t == timeout));
+		assert_eq!(
+			finished(reports),
+			vec![(0, None), (1, Some(0)), (2, Some(10))]
+		);
+	}
+
+	#[test]
+	fn wait_abandoned() {
+		let done = Arc::new(AtomicBool::new(false));
+		let first = {
+			let done = done.clone();
+			move || {
+				thread::sleep(Duration::from_millis(300));
+				done.store(true, SeqCst);
+				false
+			}
+		};
+		let second = move || done.load(SeqCst);
+		let tasks: Vec<Box<dyn FnOnce() -> bool + Send>> = vec![Box::new(first), Box::new(second)];
+		let mut reports = Vec::new();
+		run_jobs(
+			tasks,
+			1,
+			Some(Duration::from_millis(50)),
+			true,
+			|index, report| reports.push((index, report)),
+		);
+		assert!(matches!(reports[0], (0, Report::TimedOut(_))));
+		// the second job started only after the first was done
+		assert!(matches!(reports[1], (1, Report::Finished(true))));
+	}
+}
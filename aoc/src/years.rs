@@ -0,0 +1,94 @@
+use utils::{
+	bench::{Repetitions, Samples},
+	generate::Generator,
+	runner::{Args, Failure, Outcome},
+	solution::Day,
+};
+
+pub struct Year {
+	pub year: u16,
+	pub days: &'static [Day],
+}
+
+impl Year {
+	pub fn numbers(&self) -> Vec<u8> {
+		self.days.iter().map(|day| day.number).collect()
+	}
+
+	fn day(&self, day: u8) -> &Day {
+		self.days.iter().find(|d| d.number == day).unwrap()
+	}
+
+	/// Runs the given day, which must be one of [`Year::numbers`].
+	pub fn run(&self, day: u8, args: &Args) -> Outcome {
+		self.day(day).run(self.year, args)
+	}
+
+	/// Returns `None` if the given day has no generator.
+	pub fn generator(&self, day: u8) -> Option<Generator> {
+		self.day(day).generator
+	}
+
+	/// Runs the given day on `input` instead of its usual input.
+	pub fn run_on(&self, day: u8, args: &Args, input: &str) -> Outcome {
+		self.day(day).run_on(args, input)
+	}
+
+	/// Benchmarks the given day, which must be one of [`Year::numbers`].
+	pub fn bench(
+		&self,
+		day: u8,
+		args: &Args,
+		repetitions: Repetitions,
+	) -> Result<Vec<Samples>, Failure> {
+		self.day(day).bench(self.year, args, repetitions)
+	}
+}
+
+macro_rules! year {
+	($krate:ident) => {
+		Year {
+			year: $krate::YEAR,
+			days: $krate::DAYS,
+		}
+	};
+}
+
+pub const YEARS: &[Year] = &[
+	year!(advent_of_code_2015),
+	year!(advent_of_code_2016),
+	year!(advent_of_code_2017),
+	year!(advent_of_code_2018),
+	year!(advent_of_code_2019),
+	year!(advent_of_code_2020),
+	year!(advent_of_code_2023),
+	year!(advent_of_code_2024),
+	year!(advent_of_code_2025),
+];
+
+#[cfg(test)]
+mod tests {
+	use utils::generate;
+
+	use super::*;
+
+	#[test]
+	fn generated_inputs() {
+		for year in YEARS {
+			for day in year.numbers() {
+				let Some(generator) = year.generator(day) else {
+					continue;
+				};
+				let input = generator(&mut generate::seeded(0), 20);
+				let outcome = year.run_on(day, &Args::default(), &input);
+				assert!(
+					outcome.failures.is_empty(),
+					"{} day_{:0>2}: {:?}",
+					year.year,
+					day,
+					outcome.failures
+				);
+			}
+		}
+	}
+}
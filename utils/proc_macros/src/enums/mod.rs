@@ -24,13 +24,35 @@ pub(crate) fn from_char_internal(
             #(#idents),*
         }
 
-        impl From<char> for #ident{
-            fn from(value: char) -> Self {
-                match value{
-                    #(#chars => Self::#idents),*,
-                    _ => unreachable!(),
+        impl TryFrom<char> for #ident {
+            type Error = ::utils::errors::InvalidChar;
+
+            fn try_from(value: char) -> Result<Self, Self::Error> {
+                match value {
+                    #(#chars => Ok(Self::#idents),)*
+                    found => Err(::utils::errors::InvalidChar {
+                        found,
+                        expected: &[#(#chars),*],
+                    }),
+                }
+            }
+        }
+
+        impl From<#ident> for char {
+            fn from(value: #ident) -> Self {
+                match value {
+                    #(#ident::#idents => #chars),*
                 }
             }
         }
+
+        impl ::std::fmt::Display for #ident {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                let char = match self {
+                    #(Self::#idents => #chars),*
+                };
+                ::std::fmt::Write::write_char(f, char)
+            }
+        }
     ))
 }
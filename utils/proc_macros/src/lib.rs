@@ -1,9 +1,13 @@
 use proc_macro::TokenStream;
 
 mod enums;
+mod solution;
 mod structs;
 
-#[proc_macro_derive(FromStr, attributes(separator, into))]
+#[proc_macro_derive(
+    FromStr,
+    attributes(separator, pattern, keyword, into, inner_separator, rest, skip)
+)]
 pub fn from_str_derive_macro(item: TokenStream) -> TokenStream {
     match structs::from_str_derive_internal(item.into()) {
         Ok(result) => result,
@@ -20,3 +24,21 @@ pub fn from_char(attr: TokenStream, item: TokenStream) -> TokenStream {
     }
     .into()
 }
+
+#[proc_macro_derive(Solution, attributes(solution, example))]
+pub fn solution_derive_macro(item: TokenStream) -> TokenStream {
+    match solution::solution_derive_internal(item.into()) {
+        Ok(result) => result,
+        Err(error) => error.to_compile_error(),
+    }
+    .into()
+}
+
+#[proc_macro]
+pub fn days(item: TokenStream) -> TokenStream {
+    match solution::days_internal(item.into()) {
+        Ok(result) => result,
+        Err(error) => error.to_compile_error(),
+    }
+    .into()
+}
@@ -0,0 +1,45 @@
+use std::{env, fs::read_dir};
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+
+/// Cargo does not track new files, so `lib.rs` must be touched after adding a day.
+pub(crate) fn days_internal(item: TokenStream) -> syn::Result<TokenStream> {
+    if !item.is_empty() {
+        return Err(syn::Error::new_spanned(item, "`days!` takes no arguments"));
+    }
+    let error = |message: String| syn::Error::new(Span::call_site(), message);
+    let package = env::var("CARGO_PKG_NAME").map_err(|e| error(e.to_string()))?;
+    let year: u16 = package
+        .rsplit('_')
+        .next()
+        .and_then(|year| year.parse().ok())
+        .ok_or_else(|| {
+            error(format!(
+                "Cannot find the year in the package name `{}`",
+                package
+            ))
+        })?;
+    let dir = env::var("CARGO_MANIFEST_DIR").map_err(|e| error(e.to_string()))?;
+
+    let mut days: Vec<u8> = read_dir(dir)
+        .map_err(|e| error(e.to_string()))?
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            name.strip_prefix("day_")?.strip_suffix(".rs")?.parse().ok()
+        })
+        .collect();
+    days.sort();
+    let modules: Vec<_> = days
+        .iter()
+        .map(|day| format_ident!("day_{:0>2}", day))
+        .collect();
+
+    Ok(quote!(
+        #(pub mod #modules;)*
+
+        pub const YEAR: u16 = #year;
+
+        pub const DAYS: &[::utils::solution::Day] = &[#(#modules::DAY),*];
+    ))
+}
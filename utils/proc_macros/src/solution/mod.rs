@@ -0,0 +1,223 @@
+use deluxe::{extract_attributes, ExtractAttributes, Flag, ParseMetaItem};
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{parse_quote, DeriveInput, Expr, ExprLit, Lit, Type};
+
+mod days;
+
+pub(crate) use days::days_internal;
+
+#[derive(ExtractAttributes, Default)]
+#[deluxe(attributes(solution), default)]
+struct Overrides {
+    parsed: Option<Type>,
+    parse: Option<Expr>,
+    part1: Option<Expr>,
+    part2: Option<Expr>,
+    single_part: Flag,
+    /// The parts take the whole input as `&str` and parse it themselves.
+    unparsed: Flag,
+    /// A `fn(&mut Seeded, usize) -> String` writing random inputs.
+    generator: Option<Expr>,
+    /// Puzzle parameters for the real input, passed to `parse` and the parts as a second argument.
+    params: Option<Expr>,
+}
+
+#[derive(ParseMetaItem)]
+struct Example {
+    input: Expr,
+    #[deluxe(default)]
+    part1: Option<Expr>,
+    #[deluxe(default)]
+    part2: Option<Expr>,
+    /// Replaces the `params` of the solution for this example.
+    #[deluxe(default)]
+    params: Option<Expr>,
+}
+
+pub(crate) fn solution_derive_internal(item: TokenStream) -> syn::Result<TokenStream> {
+    let mut ast: DeriveInput = syn::parse2(item)?;
+    let Overrides {
+        parsed,
+        parse,
+        part1,
+        part2,
+        single_part,
+        unparsed,
+        generator,
+        params,
+    } = extract_attributes(&mut ast)?;
+    let ident = &ast.ident;
+    let number: u8 = ident
+        .to_string()
+        .strip_prefix("Day")
+        .and_then(|number| number.parse().ok())
+        .ok_or_else(|| {
+            syn::Error::new(
+                ident.span(),
+                "Solutions must be named after their day, like `Day05`",
+            )
+        })?;
+
+    let mut examples = Vec::new();
+    for attr in ast.attrs.iter().filter(|a| a.path().is_ident("example")) {
+        examples.push(deluxe::parse2::<Example>(
+            attr.meta.require_list()?.tokens.clone(),
+        )?);
+    }
+    let example = examples
+        .first()
+        .map(|Example { input, .. }| quote!(const EXAMPLE: &'static str = #input;));
+    let generator = generator.map(|generator| {
+        quote!(
+            const GENERATOR: ::std::option::Option<::utils::generate::Generator> =
+                ::std::option::Option::Some(#generator);
+        )
+    });
+    let expected = |part: &Option<Expr>| match part {
+        // integer answers often overflow `i32`, the default type of a literal
+        Some(Expr::Lit(ExprLit {
+            lit: Lit::Int(int), ..
+        })) => {
+            let digits = int.base10_digits();
+            quote!(Some(::std::string::ToString::to_string(#digits)))
+        }
+        Some(part) => quote!(Some(::std::string::ToString::to_string(&#part))),
+        None => quote!(None),
+    };
+    let tests = examples
+        .iter()
+        .enumerate()
+        .filter(|(_, example)| example.part1.is_some() || example.part2.is_some())
+        .map(|(i, example)| {
+            let name = format_ident!("example_{}", i + 1);
+            let input = &example.input;
+            let part1 = expected(&example.part1);
+            let part2 = expected(&example.part2);
+            quote!(
+                #[cfg(test)]
+                #[test]
+                fn #name() {
+                    ::utils::solution::check_example::<#ident>(#i, #input, #part1, #part2);
+                }
+            )
+        });
+
+    // the parameters of the `source` the day runs on, from the `#[example]` if it has some
+    let (signature, arguments) = match &params {
+        Some(params) => {
+            let arms = examples.iter().enumerate().filter_map(|(i, example)| {
+                let params = example.params.as_ref()?;
+                Some(quote!(::utils::solution::Source::Example(#i) => #params,))
+            });
+            (
+                quote!(, _),
+                quote!(, match source {
+                    #(#arms)*
+                    _ => #params,
+                }),
+            )
+        }
+        None => {
+            if let Some(params) = examples.iter().find_map(|example| example.params.as_ref()) {
+                return Err(syn::Error::new_spanned(
+                    params,
+                    "Examples can only replace the `params` of the solution",
+                ));
+            }
+            (quote!(), quote!())
+        }
+    };
+
+    let (parsed, parse, input, argument) = if unparsed.is_set() {
+        if parsed.is_some() || parse.is_some() {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "`unparsed` solutions cannot override `parsed` or `parse`",
+            ));
+        }
+        (
+            parse_quote!(::std::string::String),
+            quote!(
+                let _ = source;
+                ::std::result::Result::Ok(input.to_owned())
+            ),
+            quote!(&str),
+            quote!(&parsed),
+        )
+    } else {
+        let parse = parse.unwrap_or_else(|| parse_quote!(parse));
+        (
+            parsed.unwrap_or_else(|| parse_quote!(Parsed)),
+            quote!(
+                let parse: fn(&str #signature) -> _ = #parse;
+                ::utils::solution::IntoParsed::<Self::Parsed>::into_parsed(
+                    parse(input #arguments),
+                )
+            ),
+            quote!(Self::Parsed),
+            quote!(parsed),
+        )
+    };
+    let part1 = part1.unwrap_or_else(|| parse_quote!(part1::solve));
+    let part2 = if single_part.is_set() {
+        if part2.is_some() {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "`single_part` solutions cannot override `part2`",
+            ));
+        }
+        quote!(
+            const PART2: bool = false;
+
+            fn part2(
+                _parsed: Self::Parsed,
+                _source: ::utils::solution::Source,
+            ) -> ::std::result::Result<::utils::Answer, ::utils::errors::ParseError> {
+                ::std::result::Result::Ok(::utils::Answer::from(""))
+            }
+        )
+    } else {
+        let part2 = part2.unwrap_or_else(|| parse_quote!(part2::solve));
+        quote!(
+            fn part2(
+                parsed: Self::Parsed,
+                source: ::utils::solution::Source,
+            ) -> ::std::result::Result<::utils::Answer, ::utils::errors::ParseError> {
+                let part2: fn(#input #signature) -> _ = #part2;
+                ::utils::solution::IntoAnswer::into_answer(part2(#argument #arguments))
+            }
+        )
+    };
+
+    Ok(quote!(
+        impl ::utils::solution::Solution for #ident {
+            type Parsed = #parsed;
+
+            #example
+
+            #generator
+
+            fn parse(
+                input: &str,
+                source: ::utils::solution::Source,
+            ) -> ::std::result::Result<Self::Parsed, ::utils::errors::ParseError> {
+                #parse
+            }
+
+            fn part1(
+                parsed: Self::Parsed,
+                source: ::utils::solution::Source,
+            ) -> ::std::result::Result<::utils::Answer, ::utils::errors::ParseError> {
+                let part1: fn(#input #signature) -> _ = #part1;
+                ::utils::solution::IntoAnswer::into_answer(part1(#argument #arguments))
+            }
+
+            #part2
+        }
+
+        pub const DAY: ::utils::solution::Day = ::utils::solution::Day::new::<#ident>(#number);
+
+        #(#tests)*
+    ))
+}
@@ -1,79 +1,460 @@
-use deluxe::{extract_attributes, ExtractAttributes};
-use proc_macro2::{Span, TokenStream};
-use quote::quote;
+use deluxe::{extract_attributes, ExtractAttributes, HasAttributes};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
 use syn::{
-    parse_quote, punctuated::Punctuated, token::Comma, Data::Struct, DeriveInput, Expr, Field,
-    Fields::Named, FieldsNamed, GenericParam, Generics, Meta, TypeParam, WhereClause,
+    parse_quote, Data, DeriveInput, Expr, Field, Fields, GenericArgument, GenericParam, Generics,
+    Ident, LitInt, LitStr, Meta, PathArguments, Type, TypeParam, WhereClause,
 };
 
+mod variants;
+
 #[derive(ExtractAttributes)]
 #[deluxe(attributes(separator))]
 struct Separator(Expr);
 
+/// `#[pattern("#{id} @ {x},{y}: {w}x{h}")]`: literal text with a `{field}` for every field,
+/// where `{{` and `}}` stand for the braces themselves.
+/// Fields of tuple structs and variants are named by their index, as in `{0}`.
+#[derive(ExtractAttributes)]
+#[deluxe(attributes(pattern))]
+struct Pattern(LitStr);
+
+/// What the attributes of a field say about how to parse it.
+#[derive(Default)]
+struct Options {
+    /// `#[into]`: converts the part with `From<&str>` instead of parsing it.
+    into: bool,
+    /// `#[inner_separator(",")]`: parses every item of the part into a collection or an array,
+    /// splitting it on whitespace if no separator is given.
+    inner_separator: Option<Option<Expr>>,
+    /// `#[rest]`: the last field takes the rest of the input, separators included.
+    rest: bool,
+    /// `#[skip(n)]`: ignores `n` parts before the field, 1 if not given.
+    skip: usize,
+}
+
+/// A field, along with its name in patterns and the variable it is parsed into.
+struct Binding<'a> {
+    field: &'a Field,
+    name: String,
+    variable: Ident,
+    options: Options,
+}
+
+enum Segment {
+    Literal(String),
+    /// The index of the field among the bindings.
+    Field(usize),
+}
+
 pub(crate) fn from_str_derive_internal(item: TokenStream) -> syn::Result<TokenStream> {
     let mut ast: DeriveInput = syn::parse2(item)?;
-    let separator: Result<Separator, _> = extract_attributes(&mut ast);
+    let separator: Option<Separator> = attribute(&mut ast, "separator")?;
+    let pattern: Option<Pattern> = attribute(&mut ast, "pattern")?;
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
     let where_clause = add_where_clause(where_clause, &ast.generics);
-    if let Struct(data_struct) = &ast.data {
-        if let Named(FieldsNamed { named: fields, .. }) = &data_struct.fields {
-            let ident = &ast.ident;
-            let split = if let Ok(Separator(sep)) = separator {
-                quote!(value.split(#sep))
+    let ident = &ast.ident;
+    let body = match &ast.data {
+        Data::Struct(data_struct) => {
+            let bindings = bindings(&data_struct.fields)?;
+            let parse = if let Some(Pattern(pattern)) = pattern {
+                if separator.is_some() {
+                    return Err(syn::Error::new(
+                        pattern.span(),
+                        "`pattern` and `separator` cannot be used together",
+                    ));
+                }
+                match_pattern(ident, &segments(&pattern, &bindings)?, &bindings)
+            } else if data_struct.fields.is_empty() {
+                // without a pattern, there is nothing to match but the empty input
+                quote!(
+                    let rest = value.trim();
+                    if !rest.is_empty() {
+                        return Err(::utils::errors::ParseError::Invalid(::std::format!(
+                            "Unexpected `{}`",
+                            rest
+                        ))
+                        .at(value, rest));
+                    }
+                )
             } else {
-                quote!(value.split_whitespace())
+                let split = if let Some(Separator(sep)) = separator {
+                    quote!(value.split(#sep))
+                } else {
+                    quote!(value.split_whitespace())
+                };
+                let parse_fields = parse_fields(ident, &bindings)?;
+                quote!(
+                    let mut parts = #split;
+                    #parse_fields
+                )
             };
-            let parse_fields = parse_fields(fields);
-            let fields = fields.iter().map(|f| &f.ident);
-            return Ok(quote!(
-                impl #impl_generics ::std::str::FromStr for #ident #ty_generics #where_clause {
-                    type Err = ::utils::errors::ParseError;
-
-                    fn from_str(value: &str) -> Result<Self, Self::Err> {
-                        let mut parts = #split;
-                        #(#parse_fields)*
-                        Ok(Self {
-                            #(#fields),*
-                        })
-                    }
-                }
-            ));
+            let construct = construct(quote!(Self), &data_struct.fields, &bindings);
+            quote!(
+                #parse
+                Ok(#construct)
+            )
+        }
+        Data::Enum(data_enum) => variants::parse_variants(ident, data_enum)?,
+        Data::Union(data_union) => {
+            return Err(syn::Error::new(
+                data_union.union_token.span,
+                "`FromStr` can only be derived for structs and enums",
+            ))
         }
+    };
+    Ok(quote!(
+        impl #impl_generics ::std::str::FromStr for #ident #ty_generics #where_clause {
+            type Err = ::utils::errors::ParseError;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                #body
+            }
+        }
+    ))
+}
+
+/// The attribute called `name`, `None` if the item does not have it, or the error if it is malformed.
+fn attribute<T, I>(item: &mut I, name: &str) -> syn::Result<Option<T>>
+where
+    T: ExtractAttributes<I>,
+    I: HasAttributes,
+{
+    if !item.attrs().iter().any(|attr| attr.path().is_ident(name)) {
+        return Ok(None);
     }
-    Err(syn::Error::new(Span::call_site(), ""))
+    extract_attributes(item).map(Some)
 }
 
-fn parse_fields(fields: &Punctuated<Field, Comma>) -> Vec<TokenStream> {
+fn bindings(fields: &Fields) -> syn::Result<Vec<Binding<'_>>> {
     fields
         .iter()
-        .map(|f| {
-            let ident = &f.ident;
-            let parse = if f.attrs.iter().any(|a| match &a.meta {
-                Meta::Path(path) if path.get_ident().is_some() => {
-                    path.get_ident().unwrap() == "into"
+        .enumerate()
+        .map(|(i, field)| {
+            let (name, variable) = match &field.ident {
+                Some(ident) => (ident.to_string(), ident.clone()),
+                None => (i.to_string(), format_ident!("field_{}", i)),
+            };
+            Ok(Binding {
+                field,
+                name,
+                variable,
+                options: options(field)?,
+            })
+        })
+        .collect()
+}
+
+fn options(field: &Field) -> syn::Result<Options> {
+    let mut options = Options::default();
+    for attr in &field.attrs {
+        let path = attr.path();
+        if path.is_ident("into") {
+            attr.meta.require_path_only()?;
+            options.into = true;
+        } else if path.is_ident("inner_separator") {
+            options.inner_separator = Some(match &attr.meta {
+                Meta::Path(_) => None,
+                _ => Some(attr.parse_args()?),
+            });
+        } else if path.is_ident("rest") {
+            attr.meta.require_path_only()?;
+            options.rest = true;
+        } else if path.is_ident("skip") {
+            options.skip = match &attr.meta {
+                Meta::Path(_) => 1,
+                _ => attr.parse_args::<LitInt>()?.base10_parse()?,
+            };
+        }
+    }
+    if options.into && options.inner_separator.is_some() {
+        return Err(syn::Error::new_spanned(
+            field,
+            "`into` and `inner_separator` cannot be used together",
+        ));
+    }
+    Ok(options)
+}
+
+/// The `T` of an `Option<T>`.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    match arguments.args.first()? {
+        GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
+
+/// Builds `path` out of the parsed fields.
+fn construct(path: TokenStream, fields: &Fields, bindings: &[Binding]) -> TokenStream {
+    let variables = bindings.iter().map(|b| &b.variable);
+    match fields {
+        Fields::Named(_) => quote!(#path { #(#variables),* }),
+        Fields::Unnamed(_) => quote!(#path(#(#variables),*)),
+        Fields::Unit => path,
+    }
+}
+
+/// The name of the field in errors, where fields without a name are called after their owner, as in `Cpy.0`.
+fn label(owner: &Ident, binding: &Binding) -> String {
+    if binding.field.ident.is_some() {
+        binding.name.clone()
+    } else {
+        format!("{}.{}", owner, binding.name)
+    }
+}
+
+/// Parses `part` into the field, or returns the error.
+fn parse_field(owner: &Ident, binding: &Binding) -> TokenStream {
+    parse_value(&label(owner, binding), &binding.field.ty, &binding.options)
+}
+
+/// Parses `part` into a value of type `ty`, where an empty part is `None` for an `Option`.
+fn parse_value(name: &str, ty: &Type, options: &Options) -> TokenStream {
+    if let Some(inner) = option_inner(ty) {
+        let parse = parse_value(name, inner, options);
+        return quote!(if part.trim().is_empty() { None } else { Some(#parse) });
+    }
+    if options.into {
+        return quote!(part.into());
+    }
+    let Some(separator) = &options.inner_separator else {
+        return quote!({
+            let part = part.trim();
+            match part.parse() {
+                Ok(parsed) => parsed,
+                Err(error) => {
+                    return Err(::utils::errors::ParseError::new(#name, error).at(value, part))
                 }
-                _ => false,
-            }) {
-                quote!(part.into())
-            } else {
-                quote!(match part.trim().parse() {
-                    Ok(parsed) => parsed,
-                    Err(error) =>
-                        return Err(::utils::errors::ParseError::ParseError(
-                            ::std::stringify!(#ident),
-                            ::std::format!("{}", error),
-                        )),
-                })
+            }
+        });
+    };
+    let split = match separator {
+        Some(separator) => quote!(part.split(#separator)),
+        None => quote!(part.split_whitespace()),
+    };
+    let items = quote!(#split.map(|item| {
+        let item = item.trim();
+        item.parse()
+            .map_err(|error| ::utils::errors::ParseError::new(#name, error).at(value, item))
+    }));
+    if let Type::Array(array) = ty {
+        let len = &array.len;
+        quote!({
+            let items: Vec<_> = match #items.collect() {
+                Ok(items) => items,
+                Err(error) => return Err(error),
             };
+            let found = items.len();
+            match items.try_into() {
+                Ok(items) => items,
+                Err(_) => {
+                    return Err(::utils::errors::ParseError::Invalid(::std::format!(
+                        "Expected {} items in `{}`, found {}",
+                        #len,
+                        #name,
+                        found
+                    ))
+                    .at(value, part.trim()))
+                }
+            }
+        })
+    } else {
+        quote!(match #items.collect() {
+            Ok(items) => items,
+            Err(error) => return Err(error),
+        })
+    }
+}
+
+/// Parses every field from the next item of `parts`, or from the rest of the input if it is `#[rest]`.
+/// A missing part is an error, unless the field is an `Option`.
+fn parse_fields(owner: &Ident, bindings: &[Binding]) -> syn::Result<TokenStream> {
+    let mut parse_fields = Vec::new();
+    for (i, binding) in bindings.iter().enumerate() {
+        if binding.options.rest && i + 1 < bindings.len() {
+            return Err(syn::Error::new_spanned(
+                binding.field,
+                "Only the last field can be `rest`",
+            ));
+        }
+        let variable = &binding.variable;
+        let name = label(owner, binding);
+        let parse = parse_field(owner, binding);
+        let skip = binding.options.skip;
+        let skip = (skip > 0).then(|| {
+            quote!(
+                for _ in 0..#skip {
+                    parts.next();
+                }
+            )
+        });
+        let next = if binding.options.rest {
+            quote!(parts
+                .next()
+                .map(|part| &value[part.as_ptr() as usize - value.as_ptr() as usize..]))
+        } else {
+            quote!(parts.next())
+        };
+        let missing = if option_inner(&binding.field.ty).is_some() {
+            quote!(None)
+        } else {
             quote!(
-                let #ident = if let Some(part) = parts.next() {
-                    #parse
+                return Err(::utils::errors::ParseError::EndOfInput(#name)
+                    .at(value, &value[value.len()..]))
+            )
+        };
+        parse_fields.push(quote!(
+            #skip
+            let #variable = if let Some(part) = #next {
+                #parse
+            } else {
+                #missing
+            };
+        ));
+    }
+    Ok(quote!(#(#parse_fields)*))
+}
+
+/// Splits the pattern into its literals and fields, checking that every field appears exactly once
+/// and that two fields are always separated by some literal text.
+fn segments(pattern: &LitStr, bindings: &[Binding]) -> syn::Result<Vec<Segment>> {
+    let error = |message: String| syn::Error::new(pattern.span(), message);
+    if let Some(binding) = bindings
+        .iter()
+        .find(|b| b.options.rest || b.options.skip > 0)
+    {
+        return Err(syn::Error::new_spanned(
+            binding.field,
+            "`rest` and `skip` cannot be used with a pattern",
+        ));
+    }
+    let text = pattern.value();
+    let mut segments = Vec::new();
+    let mut literal = String::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '{' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => name.push(c),
+                        None => return Err(error("Unclosed `{`".to_owned())),
+                    }
+                }
+                let Some(field) = bindings.iter().position(|b| b.name == name.trim()) else {
+                    return Err(error(format!("No field named `{}`", name)));
+                };
+                let name = &bindings[field].name;
+                if !literal.is_empty() {
+                    segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                } else if matches!(segments.last(), Some(Segment::Field(_))) {
+                    return Err(error(format!(
+                        "`{}` must be separated from the previous field by some text",
+                        name
+                    )));
+                }
+                if segments
+                    .iter()
+                    .any(|segment| matches!(segment, Segment::Field(f) if *f == field))
+                {
+                    return Err(error(format!("`{}` appears more than once", name)));
+                }
+                segments.push(Segment::Field(field));
+            }
+            '}' => return Err(error("Unmatched `}`, write `}}` for a brace".to_owned())),
+            c => literal.push(c),
+        }
+    }
+    if !literal.is_empty() {
+        segments.push(Segment::Literal(literal));
+    }
+    for (i, binding) in bindings.iter().enumerate() {
+        if !segments
+            .iter()
+            .any(|segment| matches!(segment, Segment::Field(f) if *f == i))
+        {
+            return Err(error(format!(
+                "`{}` is missing from the pattern",
+                binding.name
+            )));
+        }
+    }
+    Ok(segments)
+}
+
+/// Consumes `value` one segment at a time: a field ends where the following literal starts,
+/// or at the end of the input if it is the last segment.
+fn match_pattern(owner: &Ident, segments: &[Segment], bindings: &[Binding]) -> TokenStream {
+    let mut result = vec![quote!(let rest = value;)];
+    let mut i = 0;
+    while i < segments.len() {
+        match (&segments[i], segments.get(i + 1)) {
+            (Segment::Literal(literal), _) => {
+                result.push(quote!(
+                    let Some(rest) = rest.strip_prefix(#literal) else {
+                        return Err(::utils::errors::ParseError::Invalid(
+                            ::std::format!("Expected `{}`", #literal),
+                        )
+                        .at(value, rest));
+                    };
+                ));
+                i += 1;
+            }
+            (&Segment::Field(field), next) => {
+                let binding = &bindings[field];
+                let variable = &binding.variable;
+                let name = label(owner, binding);
+                let parse = parse_field(owner, binding);
+                let split = if let Some(Segment::Literal(literal)) = next {
+                    quote!(
+                        let Some((part, rest)) = rest.split_once(#literal) else {
+                            return Err(::utils::errors::ParseError::Invalid(::std::format!(
+                                "Expected `{}` after `{}`",
+                                #literal,
+                                #name,
+                            ))
+                            .at(value, rest));
+                        };
+                    )
                 } else {
-                    return Err(::utils::errors::ParseError::EndOfInput(::std::stringify!(#ident)));
+                    quote!(let (part, rest) = rest.split_at(rest.len());)
                 };
-            )
-        })
-        .collect()
+                result.push(quote!(
+                    #split
+                    let #variable = #parse;
+                ));
+                i += 2;
+            }
+        }
+    }
+    result.push(quote!(if !rest.is_empty() {
+        return Err(::utils::errors::ParseError::Invalid(::std::format!(
+            "Unexpected `{}` after the pattern",
+            rest
+        ))
+        .at(value, rest));
+    }));
+    quote!(#(#result)*)
 }
 
 fn add_where_clause(original: Option<&WhereClause>, generics: &Generics) -> WhereClause {
@@ -85,8 +466,9 @@ fn add_where_clause(original: Option<&WhereClause>, generics: &Generics) -> Wher
         if let GenericParam::Type(TypeParam { ident, .. }) = param {
             new.predicates
                 .push(parse_quote!(#ident: ::std::str::FromStr));
-            new.predicates
-                .push(parse_quote!(<#ident as ::std::str::FromStr>::Err: ::std::fmt::Display));
+            new.predicates.push(
+                parse_quote!(<#ident as ::std::str::FromStr>::Err: ::std::fmt::Display + 'static),
+            );
         };
     }
     new
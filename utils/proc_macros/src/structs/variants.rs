@@ -0,0 +1,112 @@
+use deluxe::ExtractAttributes;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DataEnum, Ident, LitStr};
+
+use super::{
+    attribute, bindings, construct, match_pattern, parse_fields, segments, Pattern, Segment,
+};
+
+/// `#[keyword("cpy")]`: the variant is chosen by the first word of the input,
+/// and its fields are parsed from the following words, which must all be used
+/// unless the last field is `rest`.
+#[derive(ExtractAttributes)]
+#[deluxe(attributes(keyword))]
+struct Keyword(LitStr);
+
+/// Tries in order every variant whose keyword, or the text its pattern starts with, matches the input.
+/// If none of them parses it, returns the error of the first one, or the list of the expected keywords.
+pub(super) fn parse_variants(ident: &Ident, data_enum: &DataEnum) -> syn::Result<TokenStream> {
+    let mut attempts = Vec::new();
+    let mut expected = Vec::new();
+    for variant in &data_enum.variants {
+        let mut variant = variant.clone();
+        let keyword: Option<Keyword> = attribute(&mut variant, "keyword")?;
+        let pattern: Option<Pattern> = attribute(&mut variant, "pattern")?;
+        let name = &variant.ident;
+        let bindings = bindings(&variant.fields)?;
+        let (matches, parse) = match (keyword, pattern) {
+            (Some(Keyword(keyword)), None) => {
+                expected.push(keyword.value());
+                let parse_fields = parse_fields(name, &bindings)?;
+                // a `rest` field takes whatever follows
+                let trailing = (!bindings.iter().any(|b| b.options.rest)).then(|| {
+                    quote!(if let Some(part) = parts.next() {
+                        let part = &value[part.as_ptr() as usize - value.as_ptr() as usize..];
+                        return Err(::utils::errors::ParseError::Invalid(::std::format!(
+                            "Unexpected `{}` after `{}`",
+                            part.trim_end(),
+                            #keyword,
+                        ))
+                        .at(value, part));
+                    })
+                });
+                (
+                    quote!(value.split_whitespace().next() == Some(#keyword)),
+                    quote!(
+                        let mut parts = value.split_whitespace();
+                        parts.next();
+                        #parse_fields
+                        #trailing
+                    ),
+                )
+            }
+            (None, Some(Pattern(pattern))) => {
+                let segments = segments(&pattern, &bindings)?;
+                let Some(Segment::Literal(prefix)) = segments.first() else {
+                    return Err(syn::Error::new(
+                        pattern.span(),
+                        "The pattern of a variant must start with some text",
+                    ));
+                };
+                expected.push(prefix.trim().to_owned());
+                (
+                    quote!(value.starts_with(#prefix)),
+                    match_pattern(name, &segments, &bindings),
+                )
+            }
+            (Some(Keyword(keyword)), Some(_)) => {
+                return Err(syn::Error::new(
+                    keyword.span(),
+                    "`keyword` and `pattern` cannot be used together",
+                ))
+            }
+            (None, None) => {
+                return Err(syn::Error::new(
+                    name.span(),
+                    format!("`{}::{}` needs a `keyword` or a `pattern`", ident, name),
+                ))
+            }
+        };
+        let construct = construct(quote!(Self::#name), &variant.fields, &bindings);
+        attempts.push(quote!(
+            if #matches {
+                let attempt = (|| -> Result<Self, ::utils::errors::ParseError> {
+                    #parse
+                    Ok(#construct)
+                })();
+                match attempt {
+                    Ok(parsed) => return Ok(parsed),
+                    Err(error) => {
+                        first.get_or_insert(error);
+                    }
+                }
+            }
+        ));
+    }
+    let expected = format!(
+        "Expected one of {}",
+        expected
+            .iter()
+            .map(|keyword| format!("`{}`", keyword))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    Ok(quote!(
+        let mut first = None;
+        #(#attempts)*
+        Err(first.unwrap_or_else(|| {
+            ::utils::errors::ParseError::Invalid(#expected.to_owned()).at(value, value.trim_start())
+        }))
+    ))
+}
@@ -0,0 +1,132 @@
+use std::{
+	convert::Infallible,
+	fmt::{Display, Formatter},
+	str::FromStr,
+};
+
+/// The result of a part, whatever type the solution returns.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Answer {
+	Number(i128),
+	Text(String),
+}
+
+impl Display for Answer {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Answer::Number(number) => write!(f, "{}", number),
+			Answer::Text(text) => write!(f, "{}", text),
+		}
+	}
+}
+
+macro_rules! from_numbers {
+	($($number:ty),*) => {
+		$(
+			impl From<$number> for Answer {
+				fn from(value: $number) -> Self {
+					Answer::Number(value as i128)
+				}
+			}
+		)*
+	};
+}
+
+from_numbers!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+impl From<u128> for Answer {
+	fn from(value: u128) -> Self {
+		match i128::try_from(value) {
+			Ok(number) => Answer::Number(number),
+			Err(_) => Answer::Text(value.to_string()),
+		}
+	}
+}
+
+impl From<String> for Answer {
+	fn from(value: String) -> Self {
+		Answer::Text(value)
+	}
+}
+
+impl From<&str> for Answer {
+	fn from(value: &str) -> Self {
+		Answer::Text(value.to_owned())
+	}
+}
+
+impl From<char> for Answer {
+	fn from(value: char) -> Self {
+		Answer::Text(value.to_string())
+	}
+}
+
+/// Reads an answer typed by hand, which is a number if it looks like one.
+impl FromStr for Answer {
+	type Err = Infallible;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(s.parse().map_or_else(|_| Answer::from(s), Answer::Number))
+	}
+}
+
+/// Compares with an expected answer written as text, like the ones in the manifests.
+impl PartialEq<str> for Answer {
+	fn eq(&self, other: &str) -> bool {
+		match self {
+			Answer::Number(number) => other.parse() == Ok(*number),
+			Answer::Text(text) => text == other,
+		}
+	}
+}
+
+impl PartialEq<&str> for Answer {
+	fn eq(&self, other: &&str) -> bool {
+		self == *other
+	}
+}
+
+impl PartialEq<String> for Answer {
+	fn eq(&self, other: &String) -> bool {
+		self == other.as_str()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn from_numbers() {
+		assert_eq!(Answer::from(42u8), Answer::Number(42));
+		assert_eq!(Answer::from(-7i64), Answer::Number(-7));
+		assert_eq!(Answer::from(i128::MIN), Answer::Number(i128::MIN));
+		assert_eq!(Answer::from(u128::MAX >> 1), Answer::Number(i128::MAX));
+		// too big for an i128
+		assert_eq!(
+			Answer::from(u128::MAX),
+			Answer::Text("340282366920938463463374607431768211455".to_owned())
+		);
+	}
+
+	#[test]
+	fn display() {
+		assert_eq!(Answer::from(-7).to_string(), "-7");
+		assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+		assert_eq!(Answer::from('x').to_string(), "x");
+		assert_eq!("-12".parse::<Answer>(), Ok(Answer::Number(-12)));
+		assert_eq!("ABC".parse::<Answer>(), Ok(Answer::from("ABC")));
+	}
+
+	#[test]
+	fn eq_text() {
+		assert_eq!(Answer::from(-7), *"-7");
+		assert_eq!(Answer::from(42), "42");
+		assert_eq!(Answer::from(42), "42".to_owned());
+		assert_ne!(Answer::from(42), "042 ");
+		assert_ne!(Answer::from(42), "abc");
+		assert_eq!(Answer::from("abc"), "abc");
+		assert_ne!(Answer::from("042"), "42");
+		assert_eq!(Answer::from(u128::MAX), u128::MAX.to_string());
+	}
+}
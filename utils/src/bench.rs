@@ -0,0 +1,79 @@
+use std::{
+	fmt::{Display, Formatter},
+	time::Duration,
+};
+
+use crate::{memory::Usage, runner::Part};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Phase {
+	Parse,
+	Part(Part),
+}
+
+impl Display for Phase {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Phase::Parse => write!(f, "parse"),
+			Phase::Part(Part::First) => write!(f, "part1"),
+			Phase::Part(Part::Second) => write!(f, "part2"),
+		}
+	}
+}
+
+impl Phase {
+	pub fn from_name(name: &str) -> Option<Self> {
+		match name {
+			"parse" => Some(Phase::Parse),
+			"part1" => Some(Phase::Part(Part::First)),
+			"part2" => Some(Phase::Part(Part::Second)),
+			_ => None,
+		}
+	}
+}
+
+/// How many times `--bench` repeats every phase.
+#[derive(Copy, Clone, Debug)]
+pub struct Repetitions {
+	/// Runs discarded before measuring.
+	pub warmup: usize,
+	pub runs: usize,
+}
+
+/// The measured times of a phase, one for each run.
+#[derive(Clone, Debug)]
+pub struct Samples {
+	pub phase: Phase,
+	pub times: Vec<Duration>,
+	/// The heap usage of the last run, if it is being counted.
+	pub usage: Option<Usage>,
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct Stats {
+	pub min: Duration,
+	pub median: Duration,
+	pub p95: Duration,
+}
+
+impl Samples {
+	pub fn new(phase: Phase, runs: usize) -> Self {
+		Self {
+			phase,
+			times: Vec::with_capacity(runs),
+			usage: None,
+		}
+	}
+
+	pub fn stats(&self) -> Stats {
+		let mut times = self.times.clone();
+		times.sort();
+		// nearest-rank percentile
+		let percentile = |p: usize| times[(times.len() * p).div_ceil(100).max(1) - 1];
+		Stats {
+			min: times[0],
+			median: percentile(50),
+			p95: percentile(95),
+		}
+	}
+}
@@ -1,9 +1,86 @@
-use std::fmt::{Display, Formatter};
+use std::{
+    any::Any,
+    fmt::{Display, Formatter},
+};
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ParseError {
     EndOfInput(&'static str),
     ParseError(&'static str, String),
+    /// Input that does not match what the solution expects.
+    Invalid(String),
+    /// Another error, along with its position in the input, both starting from 1.
+    At {
+        line: usize,
+        column: usize,
+        error: Box<ParseError>,
+    },
+}
+
+impl ParseError {
+    /// Wraps the error returned while parsing `var`.
+    /// If it is itself a `ParseError`, its position is kept outside,
+    /// so that the fields are listed from the outermost one.
+    pub fn new<E: Display + 'static>(var: &'static str, error: E) -> Self {
+        match (&error as &dyn Any).downcast_ref::<ParseError>() {
+            Some(ParseError::At {
+                line,
+                column,
+                error,
+            }) => ParseError::At {
+                line: *line,
+                column: *column,
+                error: Box::new(ParseError::ParseError(var, error.to_string())),
+            },
+            _ => ParseError::ParseError(var, error.to_string()),
+        }
+    }
+
+    /// Like [`ParseError::new`] for an item of the input, like one of its lines,
+    /// where a `ParseError` already names what failed and is kept as it is.
+    pub fn item<E: Display + 'static>(var: &'static str, error: E) -> Self {
+        match (&error as &dyn Any).downcast_ref::<ParseError>() {
+            Some(error) => error.clone(),
+            None => ParseError::new(var, error),
+        }
+    }
+
+    /// Records that the error happened at `fragment`, which must be a slice of `input`.
+    /// A position already recorded is considered relative to `fragment`.
+    pub fn at(self, input: &str, fragment: &str) -> Self {
+        let Some(offset) = (fragment.as_ptr() as usize).checked_sub(input.as_ptr() as usize) else {
+            return self;
+        };
+        if offset > input.len() {
+            return self;
+        }
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let column = before[before.rfind('\n').map_or(0, |i| i + 1)..]
+            .chars()
+            .count()
+            + 1;
+        match self {
+            ParseError::At {
+                line: inner_line,
+                column: inner_column,
+                error,
+            } => ParseError::At {
+                line: line + inner_line - 1,
+                column: if inner_line == 1 {
+                    column + inner_column - 1
+                } else {
+                    inner_column
+                },
+                error,
+            },
+            error => ParseError::At {
+                line,
+                column,
+                error: Box::new(error),
+            },
+        }
+    }
 }
 
 impl Display for ParseError {
@@ -15,6 +92,33 @@ impl Display for ParseError {
             ParseError::ParseError(var, message) => {
                 write!(f, "Error while parsing `{}`: {}", var, message)
             }
+            ParseError::Invalid(message) => write!(f, "{}", message),
+            ParseError::At {
+                line,
+                column,
+                error,
+            } => write!(f, "line {}, column {}: {}", line, column, error),
+        }
+    }
+}
+
+/// A character that stands for no variant of an enum with `#[from_char]`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct InvalidChar {
+    pub found: char,
+    /// The characters of the variants, in order.
+    pub expected: &'static [char],
+}
+
+impl Display for InvalidChar {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid character `{}`, expected one of ", self.found)?;
+        for (i, char) in self.expected.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "`{}`", char)?;
         }
+        Ok(())
     }
 }
@@ -0,0 +1,19 @@
+use rand::{rngs::StdRng, SeedableRng};
+pub use rand::{seq::SliceRandom, Rng};
+
+/// The random number generator of the generators, always giving the same numbers for the same seed.
+pub type Seeded = StdRng;
+
+/// Writes a valid input of a day with about `size` items, like lines or moves.
+/// Declared with `#[solution(generator = generate)]`.
+pub type Generator = fn(&mut Seeded, usize) -> String;
+
+pub fn seeded(seed: u64) -> Seeded {
+	Seeded::seed_from_u64(seed)
+}
+
+/// Two different numbers in `0..n`, which must be at least 2.
+pub fn distinct(rng: &mut Seeded, n: usize) -> (usize, usize) {
+	let first = rng.gen_range(0..n);
+	(first, (first + rng.gen_range(1..n)) % n)
+}
@@ -0,0 +1,106 @@
+use std::{
+	env,
+	fs::read_to_string,
+	io::{self, Read},
+	path::{Path, PathBuf},
+};
+
+/// Directory holding the `YYYY/day_XX_input.txt` files, used instead of `../inputs` when set.
+pub const INPUTS_VAR: &str = "AOC_INPUTS";
+
+/// Directory of the inputs, `$AOC_INPUTS` or `../inputs`.
+pub fn root() -> PathBuf {
+	env::var_os(INPUTS_VAR).map_or_else(|| PathBuf::from("../inputs"), PathBuf::from)
+}
+
+/// Default path of the input of a day, inside [`root`].
+pub fn path(year: u16, day: u8) -> PathBuf {
+	path_in(&root(), year, day)
+}
+
+/// Path of the input of a day inside a directory laid out like `../inputs`.
+pub fn path_in(root: &Path, year: u16, day: u8) -> PathBuf {
+	root.join(year.to_string())
+		.join(format!("day_{:0>2}_input.txt", day))
+}
+
+/// Reads the input of a day from `path`, from stdin if `path` is `-`,
+/// or from its file inside `root` if it is `None`.
+pub fn read(root: &Path, year: u16, day: u8, path: Option<&Path>) -> io::Result<String> {
+	read_from(root, year, day, path, io::stdin())
+}
+
+fn read_from(
+	root: &Path,
+	year: u16,
+	day: u8,
+	path: Option<&Path>,
+	mut stdin: impl Read,
+) -> io::Result<String> {
+	let input = match path {
+		Some(path) if path == Path::new("-") => {
+			let mut input = String::new();
+			stdin.read_to_string(&mut input)?;
+			input
+		}
+		Some(path) => read_file(path)?,
+		None => read_file(&path_in(root, year, day))?,
+	};
+	Ok(normalize(&input))
+}
+
+fn read_file(path: &Path) -> io::Result<String> {
+	read_to_string(path)
+		.map_err(|error| io::Error::new(error.kind(), format!("{}: {}", path.display(), error)))
+}
+
+/// Converts CRLF line endings to LF, so parsers only need to handle `\n`.
+pub fn normalize(input: &str) -> String {
+	input.replace("\r\n", "\n")
+}
+
+#[cfg(test)]
+mod tests {
+	use std::fs::{create_dir_all, remove_dir_all, write};
+
+	use super::*;
+
+	#[test]
+	fn read_inputs() {
+		let root = env::temp_dir().join(format!("aoc_input_{}", std::process::id()));
+		create_dir_all(root.join("2023")).unwrap();
+		write(path_in(&root, 2023, 5), "1\r\n2\r\n").unwrap();
+		let other = root.join("other.txt");
+		write(&other, "3\n").unwrap();
+
+		assert_eq!(read(&root, 2023, 5, None).unwrap(), "1\n2\n");
+		assert_eq!(read(&root, 2023, 5, Some(&other)).unwrap(), "3\n");
+		assert_eq!(
+			read_from(&root, 2023, 5, Some(Path::new("-")), "4\r\n5".as_bytes()).unwrap(),
+			"4\n5"
+		);
+		let error = read(&root, 2023, 6, None).unwrap_err();
+		assert_eq!(error.kind(), io::ErrorKind::NotFound);
+		assert!(error.to_string().contains("day_06_input.txt"));
+
+		remove_dir_all(root).unwrap();
+	}
+
+	#[test]
+	fn normalize_line_endings() {
+		assert_eq!(normalize("a\r\nb\r\n\r\nc"), "a\nb\n\nc");
+		// only the line endings change, the trailing newline is kept
+		assert_eq!(normalize("a\nb\n"), "a\nb\n");
+		assert_eq!(normalize("a\r\n"), "a\n");
+		assert_eq!(normalize("a"), "a");
+	}
+
+	#[test]
+	fn root_override() {
+		env::set_var(INPUTS_VAR, "/tmp/aoc");
+		assert_eq!(root(), PathBuf::from("/tmp/aoc"));
+		assert_eq!(path(2024, 1), Path::new("/tmp/aoc/2024/day_01_input.txt"));
+		env::remove_var(INPUTS_VAR);
+		assert_eq!(root(), PathBuf::from("../inputs"));
+	}
+}
@@ -1,28 +1,41 @@
+pub use answer::Answer;
 pub use derive_new::new;
-pub use proc_macros::{from_char, FromStr};
+pub use proc_macros::{days, from_char, FromStr, Solution};
+pub use solution::Solution;
 pub use strum::IntoEnumIterator;
 pub use strum_macros::EnumIter;
 
+mod answer;
+pub mod bench;
 pub mod coords;
 pub mod errors;
+pub mod generate;
+pub mod input;
 pub mod math;
 pub mod matrix;
+pub mod memory;
 #[macro_use]
 pub mod parsing;
+pub mod random;
+pub mod runner;
+pub mod solution;
+pub mod trace;
 
 #[macro_export]
 macro_rules! run_days {
-    ($year:ident, $($day:ident),* $(,)*) => {
-        let args: Vec<_> = env::args().collect();
-        let test = args.iter().any(|arg| arg == "--test");
-        let verbose = args.iter().any(|arg| arg == "--verbose");
-        let mut total = Duration::default();
+    ($year:ident) => {
+        let args = $crate::runner::Args::from_env();
+        let mut total = ::std::time::Duration::default();
 
-        $(
-            println!("Running {}", stringify!($day));
-            total += $year::$day::main(test, verbose);
-            println!();
-        )*
+        $crate::runner::silence_panics(|| {
+            for day in $year::DAYS {
+                println!("Running {}", day.name());
+                let outcome = day.run($year::YEAR, &args);
+                print!("{}", outcome.output);
+                total += outcome.elapsed;
+                println!();
+            }
+        });
 
         println!("Total: {:?}", total);
     };
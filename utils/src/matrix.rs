@@ -1,3 +1,5 @@
+use std::fmt::Display;
+
 pub fn transpose_square<T: Clone>(matrix: &mut Vec<Vec<T>>) {
     for i in 0..matrix.len() {
         for j in i + 1..matrix.len() {
@@ -43,3 +45,12 @@ pub fn rotate_right<T: Clone>(matrix: &Vec<Vec<T>>) -> Vec<Vec<T>> {
     }
     result
 }
+
+/// Writes the matrix back as text, one row per line, like the input it was parsed from.
+pub fn render<T: Display>(matrix: &[Vec<T>]) -> String {
+    matrix
+        .iter()
+        .map(|row| row.iter().map(T::to_string).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,117 @@
+use std::{
+	alloc::{GlobalAlloc, Layout, System},
+	fmt::{Display, Formatter},
+	sync::atomic::{AtomicUsize, Ordering::Relaxed},
+};
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, counting what goes through it.
+/// Binaries that want a [`Usage`] for every phase install it with
+/// `#[global_allocator] static ALLOCATOR: CountingAllocator = CountingAllocator;`.
+pub struct CountingAllocator;
+
+fn allocated(size: usize) {
+	ALLOCATIONS.fetch_add(1, Relaxed);
+	ALLOCATED.fetch_add(size, Relaxed);
+	let live = LIVE.fetch_add(size, Relaxed) + size;
+	PEAK.fetch_max(live, Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+	unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+		let ptr = System.alloc(layout);
+		if !ptr.is_null() {
+			allocated(layout.size());
+		}
+		ptr
+	}
+
+	unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+		System.dealloc(ptr, layout);
+		LIVE.fetch_sub(layout.size(), Relaxed);
+	}
+
+	unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+		let ptr = System.alloc_zeroed(layout);
+		if !ptr.is_null() {
+			allocated(layout.size());
+		}
+		ptr
+	}
+
+	/// Counted as a new allocation of `new_size` bytes, replacing the old one.
+	unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+		let new = System.realloc(ptr, layout, new_size);
+		if !new.is_null() {
+			LIVE.fetch_sub(layout.size(), Relaxed);
+			allocated(new_size);
+		}
+		new
+	}
+}
+
+/// Whether [`CountingAllocator`] is the global allocator,
+/// which it is if it counted anything, since every program allocates before `main`.
+pub fn is_counting() -> bool {
+	ALLOCATIONS.load(Relaxed) > 0
+}
+
+/// The heap usage of a phase.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Usage {
+	pub allocations: usize,
+	pub bytes: usize,
+	/// The most bytes live at the same time, including the ones allocated before the phase.
+	pub peak: usize,
+}
+
+impl Usage {
+	/// Runs `f`, measuring its usage if [`is_counting`].
+	/// The counters are shared by all threads, so phases should not run concurrently.
+	pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Usage>) {
+		if !is_counting() {
+			return (f(), None);
+		}
+		let allocations = ALLOCATIONS.load(Relaxed);
+		let bytes = ALLOCATED.load(Relaxed);
+		PEAK.store(LIVE.load(Relaxed), Relaxed);
+		let result = f();
+		let usage = Usage {
+			allocations: ALLOCATIONS.load(Relaxed) - allocations,
+			bytes: ALLOCATED.load(Relaxed) - bytes,
+			peak: PEAK.load(Relaxed),
+		};
+		(result, Some(usage))
+	}
+}
+
+/// Formats bytes with a binary unit, like `1.5 MiB`.
+pub fn format_bytes(bytes: usize) -> String {
+	const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+	if bytes < 1024 {
+		return format!("{} B", bytes);
+	}
+	let mut value = bytes as f64 / 1024.0;
+	let mut unit = 0;
+	while value >= 1024.0 && unit < UNITS.len() - 1 {
+		value /= 1024.0;
+		unit += 1;
+	}
+	format!("{:.1} {}", value, UNITS[unit])
+}
+
+impl Display for Usage {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(
+			f,
+			"{} allocations, {} allocated, {} peak",
+			self.allocations,
+			format_bytes(self.bytes),
+			format_bytes(self.peak)
+		)
+	}
+}
@@ -1,4 +1,9 @@
-use std::{fmt::Debug, str::FromStr};
+use std::{
+    fmt::{Debug, Display},
+    str::FromStr,
+};
+
+use crate::errors::ParseError;
 
 pub fn parse_alpha<const S: char>(chars: &str) -> usize {
     let mut result = 0;
@@ -25,10 +30,30 @@ macro_rules! parse_lines {
     };
 }
 
+/// Only for conversions that cannot fail: the enums of `#[from_char]` go through [`try_parse_matrix`].
 pub fn parse_matrix<T: From<char>>(input: &str) -> Vec<Vec<T>> {
     parse_matrix!(input, T)
 }
 
+/// Parses every character with `T::try_from`, reporting where the first invalid one is.
+pub fn try_parse_matrix<T>(input: &str) -> Result<Vec<Vec<T>>, ParseError>
+where
+    T: TryFrom<char>,
+    <T as TryFrom<char>>::Error: Display + 'static,
+{
+    input
+        .lines()
+        .map(|line| {
+            line.char_indices()
+                .map(|(i, char)| {
+                    T::try_from(char)
+                        .map_err(|error| ParseError::item("char", error).at(input, &line[i..]))
+                })
+                .collect()
+        })
+        .collect()
+}
+
 pub fn parse_lines<T>(input: &str) -> Vec<T>
 where
     T: FromStr,
@@ -36,3 +61,18 @@ where
 {
     parse_lines!(input, T)
 }
+
+/// Like [`parse_lines`], but reports where the first invalid line is instead of panicking.
+pub fn try_parse_lines<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Display + 'static,
+{
+    input
+        .lines()
+        .map(|line| {
+            line.parse()
+                .map_err(|error| ParseError::item("line", error).at(input, line))
+        })
+        .collect()
+}
@@ -0,0 +1,59 @@
+use std::cell::{Cell, RefCell};
+
+use crate::generate::seeded;
+pub use crate::generate::{Rng, Seeded, SliceRandom};
+
+thread_local! {
+	static SEED: Cell<u64> = const { Cell::new(0) };
+	/// Seeds the generators returned by [`rng`], reseeded by [`reset`].
+	static SEEDS: RefCell<Seeded> = RefCell::new(seeded(0));
+	/// Whether the running day asked for randomness.
+	static DRAWN: Cell<bool> = const { Cell::new(false) };
+}
+
+/// A generator for the randomized searches of a day, seeded by the runner:
+/// every call returns a different generator, but the same sequence of them
+/// is returned after every [`reset`] with the same seed.
+pub fn rng() -> Seeded {
+	DRAWN.set(true);
+	seeded(SEEDS.with_borrow_mut(|seeds| seeds.gen()))
+}
+
+/// Sets the seed of the days running on the current thread.
+pub(crate) fn reset(seed: u64) {
+	SEED.set(seed);
+	SEEDS.set(seeded(seed));
+	DRAWN.set(false);
+}
+
+/// Returns the seed if the running day used it since the last [`reset`].
+pub(crate) fn drawn() -> Option<u64> {
+	DRAWN.get().then(|| SEED.get())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn draw() -> Vec<u64> {
+		let mut first = rng();
+		let mut second = rng();
+		(0..4).flat_map(|_| [first.gen(), second.gen()]).collect()
+	}
+
+	#[test]
+	fn reproducible() {
+		reset(42);
+		assert_eq!(drawn(), None);
+		let values = draw();
+		assert_eq!(drawn(), Some(42));
+		// the generators differ, even within the same day
+		assert_ne!(values[0], values[1]);
+		assert_ne!(draw(), values);
+
+		reset(42);
+		assert_eq!(draw(), values);
+		reset(7);
+		assert_ne!(draw(), values);
+	}
+}
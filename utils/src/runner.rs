@@ -0,0 +1,258 @@
+use std::{
+	any::Any,
+	env,
+	fmt::{Display, Formatter, Write},
+	io,
+	panic::{self, catch_unwind, AssertUnwindSafe},
+	path::PathBuf,
+	str::FromStr,
+	time::{Duration, Instant},
+};
+
+use crate::{
+	answer::Answer,
+	bench::Phase,
+	errors::ParseError,
+	input,
+	memory::Usage,
+	random,
+	trace::{self, Level},
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Part {
+	First,
+	Second,
+}
+
+impl Display for Part {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Part::First => write!(f, "First part"),
+			Part::Second => write!(f, "Second part"),
+		}
+	}
+}
+
+impl FromStr for Part {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"1" => Ok(Part::First),
+			"2" => Ok(Part::Second),
+			_ => Err(format!("Invalid part `{}`, expected 1 or 2", s)),
+		}
+	}
+}
+
+/// Options shared by every day, filled either by `run_days!` or by the `aoc` runner.
+#[derive(Clone, Debug, Default)]
+pub struct Args {
+	pub test: bool,
+	pub verbose: bool,
+	/// Runs only the given part, or both if `None`.
+	pub part: Option<Part>,
+	/// Reads the puzzle input from this path instead of the default one, or from stdin if `-`.
+	pub input: Option<PathBuf>,
+	/// Writes the events of the day up to this level, which is `Info` with `--verbose`.
+	pub trace: Option<Level>,
+	/// Seeds the randomized searches of the day, so that the same seed always gives the same run.
+	pub seed: u64,
+}
+
+impl Args {
+	pub fn from_env() -> Self {
+		let args: Vec<_> = env::args().collect();
+		let verbose = args.iter().any(|arg| arg == "--verbose");
+		let trace = args
+			.iter()
+			.find_map(|arg| arg.strip_prefix("--trace=")?.parse().ok());
+		let seed = args
+			.iter()
+			.find_map(|arg| arg.strip_prefix("--seed=")?.parse().ok());
+		Self {
+			test: args.iter().any(|arg| arg == "--test"),
+			verbose,
+			trace: trace.or(verbose.then_some(Level::Info)),
+			seed: seed.unwrap_or_default(),
+			..Default::default()
+		}
+	}
+
+	pub fn runs(&self, part: Part) -> bool {
+		self.part.is_none_or(|p| p == part)
+	}
+
+	pub fn read_input(&self, year: u16, day: u8) -> io::Result<String> {
+		input::read(&input::root(), year, day, self.input.as_deref())
+	}
+}
+
+/// Why a day could not give all its answers.
+#[derive(Clone, Debug)]
+pub enum Failure {
+	Input(String),
+	/// `parse` or a part returned an error.
+	Error(Phase, ParseError),
+	/// The day panicked, during the given phase if it is known.
+	Panic(Option<Phase>, String),
+}
+
+impl Display for Failure {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Failure::Input(error) => write!(f, "Cannot read the input: {}", error),
+			Failure::Error(phase, error) => write!(f, "{} failed: {}", phase, error),
+			Failure::Panic(Some(phase), message) => write!(f, "{} panicked: {}", phase, message),
+			Failure::Panic(None, message) => write!(f, "Panicked: {}", message),
+		}
+	}
+}
+
+/// Runs `f`, turning a panic into a [`Failure`] so that the following days can still run.
+pub fn catch_panic<T>(phase: Option<Phase>, f: impl FnOnce() -> T) -> Result<T, Failure> {
+	catch_unwind(AssertUnwindSafe(f))
+		.map_err(|payload| Failure::Panic(phase, panic_message(payload)))
+}
+
+/// Runs `f` with a panic hook that prints nothing, restoring the previous hook afterwards.
+/// The message of a panic caught by [`catch_panic`] is already part of its [`Failure`],
+/// and the default hook would print it to stderr as soon as it happens,
+/// out of order with the outcomes of the days running concurrently.
+/// The hook is global, so this is meant to wrap the whole run rather than each day.
+pub fn silence_panics<T>(f: impl FnOnce() -> T) -> T {
+	let previous = panic::take_hook();
+	panic::set_hook(Box::new(|_| {}));
+	let result = catch_unwind(AssertUnwindSafe(f));
+	panic::set_hook(previous);
+	// a panic that was not caught by the day is reported again, this time by the previous hook
+	result.unwrap_or_else(|payload| panic!("{}", panic_message(payload)))
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+	match payload.downcast::<String>() {
+		Ok(message) => *message,
+		Err(payload) => payload
+			.downcast_ref::<&str>()
+			.map_or_else(|| "unknown error".to_owned(), |message| message.to_string()),
+	}
+}
+
+/// Runs a phase of a day, turning both its error and its panic into a [`Failure`].
+pub(crate) fn attempt<T>(
+	phase: Phase,
+	f: impl FnOnce() -> Result<T, ParseError>,
+) -> Result<T, Failure> {
+	catch_panic(Some(phase), f)?.map_err(|error| Failure::Error(phase, error))
+}
+
+/// What a day computed, along with the time spent.
+#[derive(Clone, Debug, Default)]
+pub struct Outcome {
+	pub elapsed: Duration,
+	/// The time of every phase that succeeded, parsing included even if not verbose.
+	pub times: Vec<(Phase, Duration)>,
+	pub answers: Vec<(Part, Answer)>,
+	pub failures: Vec<Failure>,
+	/// What the day would have printed, kept aside so that days running concurrently
+	/// can still be printed in order.
+	pub output: String,
+}
+
+impl Outcome {
+	/// Records the failure along with its diagnostic.
+	pub fn fail(&mut self, failure: Failure) {
+		writeln!(self.output, "{}", failure).unwrap();
+		self.failures.push(failure);
+	}
+}
+
+fn describe(usage: Option<Usage>) -> String {
+	usage.map_or_else(String::new, |usage| format!(" ({})", usage))
+}
+
+/// Times the phases of a day, writing the results like every `main` used to print them.
+/// The heap usage of every phase is written too, if it is being counted, and so are its trace events.
+/// A failure is followed by the seed, if the day drew random numbers, so that it can be reproduced.
+pub struct Timer<'a> {
+	args: &'a Args,
+	outcome: Outcome,
+}
+
+impl<'a> Timer<'a> {
+	pub fn new(args: &'a Args) -> Self {
+		random::reset(args.seed);
+		Self {
+			args,
+			outcome: Outcome::default(),
+		}
+	}
+
+	/// Returns `None` if parsing failed, in which case no part should run.
+	pub fn parse<P>(&mut self, parse: impl FnOnce() -> Result<P, ParseError>) -> Option<P> {
+		let start = Instant::now();
+		let ((parsed, usage), events) = trace::collect(self.args.trace, || {
+			Usage::measure(|| attempt(Phase::Parse, parse))
+		});
+		let elapsed = start.elapsed();
+		self.outcome.output.push_str(&events);
+		if parsed.is_ok() {
+			self.outcome.times.push((Phase::Parse, elapsed));
+		}
+		if self.args.verbose {
+			writeln!(
+				self.outcome.output,
+				"Parsed in {:?}{}",
+				elapsed,
+				describe(usage)
+			)
+			.unwrap();
+			self.outcome.elapsed += elapsed;
+		}
+		parsed.map_err(|failure| self.fail(failure)).ok()
+	}
+
+	pub fn part(&mut self, part: Part, solve: impl FnOnce() -> Result<Answer, ParseError>) {
+		if !self.args.runs(part) {
+			return;
+		}
+		let start = Instant::now();
+		let ((result, usage), events) = trace::collect(self.args.trace, || {
+			Usage::measure(|| attempt(Phase::Part(part), solve))
+		});
+		let elapsed = start.elapsed();
+		self.outcome.output.push_str(&events);
+		match result {
+			Ok(result) => {
+				writeln!(self.outcome.output, "{}", result).unwrap();
+				writeln!(
+					self.outcome.output,
+					"{} in {:?}{}",
+					part,
+					elapsed,
+					describe(usage)
+				)
+				.unwrap();
+				self.outcome.elapsed += elapsed;
+				self.outcome.times.push((Phase::Part(part), elapsed));
+				self.outcome.answers.push((part, result));
+			}
+			Err(failure) => self.fail(failure),
+		}
+	}
+
+	fn fail(&mut self, failure: Failure) {
+		self.outcome.fail(failure);
+		if let Some(seed) = random::drawn() {
+			writeln!(self.outcome.output, "Seed {}", seed).unwrap();
+		}
+	}
+
+	pub fn finish(mut self) -> Outcome {
+		if self.args.verbose {
+			writeln!(self.outcome.output, "Total {:?}", self.outcome.elapsed).unwrap();
+		}
+		self.outcome
+	}
+}
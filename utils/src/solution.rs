@@ -0,0 +1,228 @@
+use std::{hint::black_box, time::Instant};
+
+use crate::{
+	answer::Answer,
+	bench::{Phase, Repetitions, Samples},
+	errors::ParseError,
+	generate::Generator,
+	input,
+	memory::Usage,
+	random,
+	runner::{attempt, Args, Failure, Outcome, Part, Timer},
+};
+
+/// The input a day runs on: some puzzles use other parameters for their examples
+/// than for the real input, like a smaller grid or fewer steps.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Source {
+	/// The real input, or any input given with `--input` or generated.
+	Puzzle,
+	/// The `#[example]` at this index, the first one being used with `--test`.
+	Example(usize),
+}
+
+/// A single day, usually implemented with `#[derive(Solution)]`
+/// on top of the `parse`, `part1::solve` and `part2::solve` functions of the module,
+/// which can either return their result or a `Result` of it.
+pub trait Solution {
+	type Parsed: Clone;
+
+	/// Input used with `--test`.
+	const EXAMPLE: &'static str = "";
+	/// Day 25 has no second part.
+	const PART2: bool = true;
+	/// Writes random inputs, for the days with a simple enough grammar.
+	const GENERATOR: Option<Generator> = None;
+
+	fn parse(input: &str, source: Source) -> Result<Self::Parsed, ParseError>;
+
+	fn part1(parsed: Self::Parsed, source: Source) -> Result<Answer, ParseError>;
+
+	fn part2(parsed: Self::Parsed, source: Source) -> Result<Answer, ParseError>;
+}
+
+/// What `parse` can return: the parsed input, or a `Result` of it.
+pub trait IntoParsed<T> {
+	fn into_parsed(self) -> Result<T, ParseError>;
+}
+
+impl<T> IntoParsed<T> for T {
+	fn into_parsed(self) -> Result<T, ParseError> {
+		Ok(self)
+	}
+}
+
+impl<T> IntoParsed<T> for Result<T, ParseError> {
+	fn into_parsed(self) -> Result<T, ParseError> {
+		self
+	}
+}
+
+/// What a part can return: anything convertible into an [`Answer`], or a `Result` of it.
+pub trait IntoAnswer {
+	fn into_answer(self) -> Result<Answer, ParseError>;
+}
+
+impl<T: Into<Answer>> IntoAnswer for T {
+	fn into_answer(self) -> Result<Answer, ParseError> {
+		Ok(self.into())
+	}
+}
+
+impl<T: Into<Answer>> IntoAnswer for Result<T, ParseError> {
+	fn into_answer(self) -> Result<Answer, ParseError> {
+		self.map(Into::into)
+	}
+}
+
+/// Type-erased [`Solution`], collected by `days!` into the `DAYS` of each year.
+#[derive(Copy, Clone)]
+pub struct Day {
+	pub number: u8,
+	pub example: &'static str,
+	pub generator: Option<Generator>,
+	run: fn(&Args, &str, Source) -> Outcome,
+	bench: fn(&Args, &str, Source, Repetitions) -> Result<Vec<Samples>, Failure>,
+}
+
+impl Day {
+	pub const fn new<S: Solution>(number: u8) -> Self {
+		Self {
+			number,
+			example: S::EXAMPLE,
+			generator: S::GENERATOR,
+			run: run::<S>,
+			bench: bench::<S>,
+		}
+	}
+
+	pub fn name(&self) -> String {
+		format!("day_{:0>2}", self.number)
+	}
+
+	fn input(&self, year: u16, args: &Args) -> Result<(String, Source), Failure> {
+		if args.test {
+			Ok((input::normalize(self.example), Source::Example(0)))
+		} else {
+			args.read_input(year, self.number)
+				.map(|input| (input, Source::Puzzle))
+				.map_err(|error| Failure::Input(error.to_string()))
+		}
+	}
+
+	/// Runs the day on the input selected by `args`,
+	/// reporting a diagnostic instead of panicking if it fails.
+	pub fn run(&self, year: u16, args: &Args) -> Outcome {
+		match self.input(year, args) {
+			Ok((input, source)) => (self.run)(args, &input, source),
+			Err(failure) => {
+				let mut outcome = Outcome::default();
+				outcome.fail(failure);
+				outcome
+			}
+		}
+	}
+
+	/// Runs the day on the given input instead of the one selected by `args`.
+	pub fn run_on(&self, args: &Args, input: &str) -> Outcome {
+		(self.run)(args, &input::normalize(input), Source::Puzzle)
+	}
+
+	/// Times every phase of the day separately, without printing anything.
+	pub fn bench(
+		&self,
+		year: u16,
+		args: &Args,
+		repetitions: Repetitions,
+	) -> Result<Vec<Samples>, Failure> {
+		let (input, source) = self.input(year, args)?;
+		(self.bench)(args, &input, source, repetitions)
+	}
+}
+
+fn run<S: Solution>(args: &Args, input: &str, source: Source) -> Outcome {
+	let mut timer = Timer::new(args);
+	if let Some(parsed) = timer.parse(|| S::parse(input, source)) {
+		if S::PART2 {
+			timer.part(Part::First, || S::part1(parsed.clone(), source));
+			timer.part(Part::Second, || S::part2(parsed, source));
+		} else {
+			timer.part(Part::First, || S::part1(parsed, source));
+		}
+	}
+	timer.finish()
+}
+
+fn bench<S: Solution>(
+	args: &Args,
+	input: &str,
+	source: Source,
+	repetitions: Repetitions,
+) -> Result<Vec<Samples>, Failure> {
+	let mut parts = vec![Part::First];
+	if S::PART2 {
+		parts.push(Part::Second);
+	}
+	parts.retain(|&part| args.runs(part));
+	let mut samples: Vec<_> = [Phase::Parse]
+		.into_iter()
+		.chain(parts.iter().map(|&part| Phase::Part(part)))
+		.map(|phase| Samples::new(phase, repetitions.runs))
+		.collect();
+
+	random::reset(args.seed);
+	for run in 0..repetitions.warmup + repetitions.runs {
+		let measured = run >= repetitions.warmup;
+		let start = Instant::now();
+		let (parsed, usage) = Usage::measure(|| attempt(Phase::Parse, || S::parse(input, source)));
+		let parsed = black_box(parsed?);
+		let elapsed = start.elapsed();
+		if measured {
+			samples[0].times.push(elapsed);
+			samples[0].usage = usage;
+		}
+		for (i, &part) in parts.iter().enumerate() {
+			let parsed = parsed.clone();
+			let start = Instant::now();
+			let (answer, usage) = Usage::measure(|| {
+				attempt(Phase::Part(part), || match part {
+					Part::First => S::part1(parsed, source),
+					Part::Second => S::part2(parsed, source),
+				})
+			});
+			drop(black_box(answer?));
+			let elapsed = start.elapsed();
+			if measured {
+				samples[i + 1].times.push(elapsed);
+				samples[i + 1].usage = usage;
+			}
+		}
+	}
+	Ok(samples)
+}
+
+/// Called by the tests generated from `#[example(input = ..., part1 = ..., part2 = ...)]`,
+/// with the index of the example among those of the day.
+pub fn check_example<S: Solution>(
+	index: usize,
+	input: &str,
+	part1: Option<String>,
+	part2: Option<String>,
+) {
+	fn unwrap<T>(result: Result<T, ParseError>) -> T {
+		result.unwrap_or_else(|error| panic!("{}", error))
+	}
+
+	let source = Source::Example(index);
+	let parsed = unwrap(S::parse(&input::normalize(input), source));
+	if let Some(expected) = part1 {
+		assert_eq!(
+			unwrap(S::part1(parsed.clone(), source)),
+			expected,
+			"First part"
+		);
+	}
+	if let Some(expected) = part2 {
+		assert_eq!(unwrap(S::part2(parsed, source)), expected, "Second part");
+	}
+}
@@ -0,0 +1,179 @@
+use std::{
+	cell::RefCell,
+	fmt::{Arguments, Display, Formatter, Write},
+	str::FromStr,
+	sync::atomic::{AtomicU8, Ordering::Relaxed},
+	time::Instant,
+};
+
+/// How detailed an event is, from the least to the most.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Level {
+	Info = 1,
+	Debug = 2,
+	Trace = 3,
+}
+
+impl Display for Level {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Level::Info => write!(f, "info"),
+			Level::Debug => write!(f, "debug"),
+			Level::Trace => write!(f, "trace"),
+		}
+	}
+}
+
+impl FromStr for Level {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"info" => Ok(Level::Info),
+			"debug" => Ok(Level::Debug),
+			"trace" => Ok(Level::Trace),
+			_ => Err(format!(
+				"Invalid level `{}`, expected info, debug or trace",
+				s
+			)),
+		}
+	}
+}
+
+/// The most detailed level collected by any thread so far,
+/// so that events are a single load when nothing is collected.
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(0);
+
+struct Sink {
+	level: Level,
+	depth: usize,
+	output: String,
+}
+
+thread_local! {
+	static SINK: RefCell<Option<Sink>> = const { RefCell::new(None) };
+}
+
+/// Whether an event of this level would be collected, checked by the macros before formatting it.
+#[inline]
+pub fn enabled(level: Level) -> bool {
+	level as u8 <= MAX_LEVEL.load(Relaxed)
+		&& SINK.with_borrow(|sink| sink.as_ref().is_some_and(|sink| level <= sink.level))
+}
+
+/// Called by [`event!`](crate::event) once the event is known to be enabled.
+#[doc(hidden)]
+pub fn event(level: Level, args: Arguments) {
+	SINK.with_borrow_mut(|sink| {
+		if let Some(sink) = sink {
+			let indent = 2 * sink.depth;
+			writeln!(sink.output, "{:indent$}[{}] {}", "", level, args).unwrap();
+		}
+	});
+}
+
+/// Runs `f`, collecting the events up to `level` that it emits on the current thread.
+pub fn collect<T>(level: Option<Level>, f: impl FnOnce() -> T) -> (T, String) {
+	let Some(level) = level else {
+		return (f(), String::new());
+	};
+	MAX_LEVEL.fetch_max(level as u8, Relaxed);
+	let previous = SINK.replace(Some(Sink {
+		level,
+		depth: 0,
+		output: String::new(),
+	}));
+	let result = f();
+	let sink = SINK.replace(previous);
+	(result, sink.map_or_else(String::new, |sink| sink.output))
+}
+
+/// Indents the events emitted while it is alive, and reports how long it lived when dropped.
+/// Created by [`span!`](crate::span).
+pub struct Span {
+	/// `None` if the span is not collected.
+	entered: Option<(Level, String, Instant)>,
+}
+
+impl Span {
+	#[doc(hidden)]
+	pub fn enter(level: Level, name: impl FnOnce() -> String) -> Self {
+		if !enabled(level) {
+			return Self { entered: None };
+		}
+		let name = name();
+		event(level, format_args!("{}", name));
+		SINK.with_borrow_mut(|sink| {
+			if let Some(sink) = sink {
+				sink.depth += 1;
+			}
+		});
+		Self {
+			entered: Some((level, name, Instant::now())),
+		}
+	}
+}
+
+impl Drop for Span {
+	fn drop(&mut self) {
+		let Some((level, name, start)) = self.entered.take() else {
+			return;
+		};
+		let elapsed = start.elapsed();
+		SINK.with_borrow_mut(|sink| {
+			if let Some(sink) = sink {
+				sink.depth = sink.depth.saturating_sub(1);
+			}
+		});
+		event(level, format_args!("{} took {:?}", name, elapsed));
+	}
+}
+
+/// Emits an event of the given [`Level`] with a `format!`-like message,
+/// formatted only if the runner collects that level for the current day:
+/// ```ignore
+/// utils::event!(Debug, "round {}: {} units left", round, units.len());
+/// ```
+#[macro_export]
+macro_rules! event {
+	($level:ident, $($arg:tt)+) => {
+		if $crate::trace::enabled($crate::trace::Level::$level) {
+			$crate::trace::event($crate::trace::Level::$level, ::std::format_args!($($arg)+));
+		}
+	};
+}
+
+/// An [`event!`] of level `Info`, shown by `--verbose`.
+#[macro_export]
+macro_rules! info {
+	($($arg:tt)+) => {
+		$crate::event!(Info, $($arg)+)
+	};
+}
+
+/// An [`event!`] of level `Debug`.
+#[macro_export]
+macro_rules! debug {
+	($($arg:tt)+) => {
+		$crate::event!(Debug, $($arg)+)
+	};
+}
+
+/// An [`event!`] of level `Trace`.
+#[macro_export]
+macro_rules! trace {
+	($($arg:tt)+) => {
+		$crate::event!(Trace, $($arg)+)
+	};
+}
+
+/// Opens a [`Span`](crate::trace::Span) lasting until the end of the scope:
+/// ```ignore
+/// let _span = utils::span!(Debug, "round {}", round);
+/// ```
+#[macro_export]
+macro_rules! span {
+	($level:ident, $($arg:tt)+) => {
+		$crate::trace::Span::enter($crate::trace::Level::$level, || ::std::format!($($arg)+))
+	};
+}
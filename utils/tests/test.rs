@@ -1,6 +1,16 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, time::Duration};
 
 use proc_macros::{from_char, FromStr};
+use utils::{
+    bench::{Phase, Samples},
+    errors::ParseError,
+    matrix::render,
+    parsing::{try_parse_lines, try_parse_matrix},
+    runner::{catch_panic, silence_panics, Args, Failure, Part},
+    solution::Day,
+    trace::{collect, Level},
+    Answer, Solution,
+};
 
 #[test]
 fn enums() {
@@ -15,7 +25,22 @@ fn enums() {
         Left = '<',
     }
 
-    assert_eq!(Cell::Forest, '#'.into());
+    assert_eq!(Ok(Cell::Forest), '#'.try_into());
+    assert_eq!(Ok(Cell::Up), Cell::try_from('^'));
+    assert_eq!('>', char::from(Cell::Right));
+    assert_eq!(
+        Cell::try_from('x').unwrap_err().to_string(),
+        "Invalid character `x`, expected one of `.`, `#`, `^`, `>`, `v`, `<`"
+    );
+
+    let grid = "#.^\n<v>";
+    let cells: Vec<Vec<Cell>> = try_parse_matrix(grid).unwrap();
+    assert_eq!(cells[1][2], Cell::Right);
+    assert_eq!(render(&cells), grid);
+    assert_eq!(
+        try_parse_matrix::<Cell>("#.\n.x").unwrap_err().to_string(),
+        "line 2, column 2: Error while parsing `char`: Invalid character `x`, expected one of `.`, `#`, `^`, `>`, `v`, `<`"
+    );
 }
 
 #[test]
@@ -46,6 +71,33 @@ fn structs() {
 
     assert_eq!(Ok(Test3 { x: 3, y: 4 }), "3   4".parse());
     assert_eq!(Ok(Test3 { x: 3, y: 4 }), "3 4".parse());
+
+    #[derive(Copy, Clone, Debug, Eq, PartialEq, FromStr)]
+    struct Newtype(isize);
+
+    #[derive(Copy, Clone, Debug, Eq, PartialEq, FromStr)]
+    #[separator(',')]
+    struct Tuple(Newtype, char);
+
+    assert_eq!(Ok(Tuple(Newtype(-3), 'a')), "-3, a".parse());
+    assert_eq!(
+        "3, ab".parse::<Tuple>().unwrap_err().to_string(),
+        "line 1, column 4: Error while parsing `Tuple.1`: too many characters in string"
+    );
+
+    #[derive(Copy, Clone, Debug, Eq, PartialEq, FromStr)]
+    struct Empty;
+
+    #[derive(Copy, Clone, Debug, Eq, PartialEq, FromStr)]
+    #[pattern("nothing")]
+    struct Nothing;
+
+    assert_eq!(Ok(Empty), " ".parse());
+    assert_eq!(Ok(Nothing), "nothing".parse());
+    assert_eq!(
+        "x".parse::<Empty>().unwrap_err().to_string(),
+        "line 1, column 1: Unexpected `x`"
+    );
 }
 
 #[test]
@@ -97,11 +149,330 @@ fn error() {
 
     assert_eq!(
         "19, 13, 30".parse::<Hail>().unwrap_err().to_string(),
-        "Unexpected end of input while parsing `velocity`"
+        "line 1, column 11: Unexpected end of input while parsing `velocity`"
     );
 
     assert_eq!(
         "19, 13, 30 @".parse::<Hail>().unwrap_err().to_string(),
-        "Error while parsing `velocity`: Error while parsing `x`: cannot parse integer from empty string"
+        "line 1, column 13: Error while parsing `velocity`: Error while parsing `x`: cannot parse integer from empty string"
+    );
+
+    assert_eq!(
+        try_parse_lines::<Hail>("19, 13, 30 @ 2, 1, 2\n18, 19, 22 @ 1, 1, x")
+            .unwrap_err()
+            .to_string(),
+        "line 2, column 20: Error while parsing `velocity`: Error while parsing `z`: invalid digit found in string"
+    );
+}
+
+#[test]
+fn pattern() {
+    #[derive(Copy, Clone, Debug, Eq, PartialEq, FromStr)]
+    #[pattern("#{id} @ {left},{top}: {width}x{height}")]
+    struct Claim {
+        id: usize,
+        left: usize,
+        top: usize,
+        width: usize,
+        height: usize,
+    }
+
+    assert_eq!(
+        Ok(Claim {
+            id: 1,
+            left: 3,
+            top: 2,
+            width: 5,
+            height: 4
+        }),
+        "#1 @ 3,2: 5x4".parse()
+    );
+
+    #[derive(Copy, Clone, Debug, Eq, PartialEq, FromStr)]
+    #[separator(',')]
+    struct Triple {
+        x: isize,
+        y: isize,
+        z: isize,
+    }
+
+    #[derive(Copy, Clone, Debug, Eq, PartialEq, FromStr)]
+    #[pattern("{{{position}}} <{velocity}>")]
+    struct Particle {
+        position: Triple,
+        velocity: Triple,
+    }
+
+    assert_eq!(
+        Ok(Particle {
+            position: Triple { x: 1, y: -2, z: 3 },
+            velocity: Triple { x: 0, y: 0, z: -1 },
+        }),
+        "{1,-2, 3} < 0,0,-1>".parse()
+    );
+
+    assert_eq!(
+        "1 @ 3,2: 5x4".parse::<Claim>().unwrap_err().to_string(),
+        "line 1, column 1: Expected `#`"
+    );
+    assert_eq!(
+        "#1 @ 3;2: 5x4".parse::<Claim>().unwrap_err().to_string(),
+        "line 1, column 6: Expected `,` after `left`"
+    );
+    assert_eq!(
+        "#1 @ 3,2: 5xx".parse::<Claim>().unwrap_err().to_string(),
+        "line 1, column 13: Error while parsing `height`: invalid digit found in string"
+    );
+    assert_eq!(
+        "{1,2,3} <4,5,6>!"
+            .parse::<Particle>()
+            .unwrap_err()
+            .to_string(),
+        "line 1, column 16: Unexpected `!` after the pattern"
+    );
+    assert_eq!(
+        "{1,2,x} <4,5,6>"
+            .parse::<Particle>()
+            .unwrap_err()
+            .to_string(),
+        "line 1, column 6: Error while parsing `position`: Error while parsing `z`: invalid digit found in string"
+    );
+}
+
+#[test]
+fn variants() {
+    #[derive(Copy, Clone, Debug, Eq, PartialEq, FromStr)]
+    enum Instruction {
+        #[keyword("cpy")]
+        Cpy(isize, char),
+        #[keyword("inc")]
+        Inc { register: char },
+        #[keyword("nop")]
+        Nop,
+        #[pattern("swap position {0} with position {1}")]
+        SwapPosition(usize, usize),
+        #[pattern("swap letter {x} with letter {y}")]
+        SwapLetter { x: char, y: char },
+    }
+
+    assert_eq!(
+        Ok(vec![
+            Instruction::Cpy(-41, 'a'),
+            Instruction::Inc { register: 'b' },
+            Instruction::Nop,
+            Instruction::SwapPosition(4, 0),
+            Instruction::SwapLetter { x: 'd', y: 'b' },
+        ]),
+        try_parse_lines(
+            "cpy -41 a\ninc b\nnop\nswap position 4 with position 0\nswap letter d with letter b"
+        )
+    );
+
+    assert_eq!(
+        "dec a".parse::<Instruction>().unwrap_err().to_string(),
+        "line 1, column 1: Expected one of `cpy`, `inc`, `nop`, `swap position`, `swap letter`"
+    );
+    assert_eq!(
+        "cpy a b".parse::<Instruction>().unwrap_err().to_string(),
+        "line 1, column 5: Error while parsing `Cpy.0`: invalid digit found in string"
+    );
+    assert_eq!(
+        "inc".parse::<Instruction>().unwrap_err().to_string(),
+        "line 1, column 4: Unexpected end of input while parsing `register`"
+    );
+    assert_eq!(
+        "noop garbage"
+            .parse::<Instruction>()
+            .unwrap_err()
+            .to_string(),
+        "line 1, column 1: Expected one of `cpy`, `inc`, `nop`, `swap position`, `swap letter`"
+    );
+    assert_eq!(
+        "nop garbage"
+            .parse::<Instruction>()
+            .unwrap_err()
+            .to_string(),
+        "line 1, column 5: Unexpected `garbage` after `nop`"
+    );
+    assert_eq!(
+        "inc b c d".parse::<Instruction>().unwrap_err().to_string(),
+        "line 1, column 7: Unexpected `c d` after `inc`"
+    );
+    assert_eq!(
+        "swap letter d with b"
+            .parse::<Instruction>()
+            .unwrap_err()
+            .to_string(),
+        "line 1, column 13: Expected ` with letter ` after `x`"
     );
 }
+
+#[test]
+fn fields() {
+    #[derive(Clone, Debug, Eq, PartialEq, FromStr)]
+    #[separator(':')]
+    struct Equation {
+        target: u64,
+        #[inner_separator]
+        factors: Vec<u64>,
+        bonus: Option<u64>,
+    }
+
+    assert_eq!(
+        Ok(Equation {
+            target: 190,
+            factors: vec![10, 19],
+            bonus: None
+        }),
+        "190: 10 19".parse()
+    );
+    assert_eq!(
+        Ok(Equation {
+            target: 190,
+            factors: vec![10, 19],
+            bonus: Some(3)
+        }),
+        "190: 10 19: 3".parse()
+    );
+
+    #[derive(Clone, Debug, Eq, PartialEq, FromStr)]
+    struct Card {
+        #[skip]
+        id: usize,
+        #[inner_separator(',')]
+        left: [usize; 3],
+        #[skip(2)]
+        #[rest]
+        name: String,
+    }
+
+    assert_eq!(
+        Ok(Card {
+            id: 1,
+            left: [41, 48, 83],
+            name: "a  b".to_owned()
+        }),
+        "Card 1 41,48,83 | x a  b".parse()
+    );
+
+    assert_eq!(
+        "Card 1 41,48 | x a"
+            .parse::<Card>()
+            .unwrap_err()
+            .to_string(),
+        "line 1, column 8: Expected 3 items in `left`, found 2"
+    );
+    assert_eq!(
+        "Card 1 41,4x,83".parse::<Card>().unwrap_err().to_string(),
+        "line 1, column 11: Error while parsing `left`: invalid digit found in string"
+    );
+    assert_eq!(
+        "Card 1 41,48,83 |".parse::<Card>().unwrap_err().to_string(),
+        "line 1, column 18: Unexpected end of input while parsing `name`"
+    );
+}
+
+#[test]
+fn trace() {
+    fn rounds() -> usize {
+        for round in 1..=2 {
+            let _span = utils::span!(Debug, "round {}", round);
+            utils::info!("fighting");
+            utils::trace!("never collected");
+        }
+        2
+    }
+
+    assert_eq!(rounds(), 2);
+    assert_eq!(
+        collect(Some(Level::Info), rounds),
+        (2, "[info] fighting\n[info] fighting\n".to_owned())
+    );
+    let (_, events) = collect(Some(Level::Debug), rounds);
+    let lines: Vec<_> = events.lines().collect();
+    assert_eq!(lines.len(), 6);
+    assert_eq!(lines[0], "[debug] round 1");
+    assert_eq!(lines[1], "  [info] fighting");
+    assert!(lines[2].starts_with("[debug] round 1 took "));
+}
+
+#[test]
+fn bench() {
+    let mut samples = Samples::new(Phase::Part(Part::First), 20);
+    // nearest-rank percentiles do not depend on the order of the runs
+    samples.times = (1..=20).rev().map(Duration::from_millis).collect();
+    let stats = samples.stats();
+    assert_eq!(stats.min, Duration::from_millis(1));
+    assert_eq!(stats.median, Duration::from_millis(10));
+    assert_eq!(stats.p95, Duration::from_millis(19));
+
+    samples.times = vec![Duration::from_millis(7)];
+    let stats = samples.stats();
+    assert_eq!(
+        (stats.min, stats.median, stats.p95),
+        (
+            Duration::from_millis(7),
+            Duration::from_millis(7),
+            Duration::from_millis(7)
+        )
+    );
+
+    samples.times = vec![Duration::from_millis(3), Duration::from_millis(1)];
+    assert_eq!(samples.stats().median, Duration::from_millis(1));
+    assert_eq!(samples.stats().p95, Duration::from_millis(3));
+
+    for phase in [
+        Phase::Parse,
+        Phase::Part(Part::First),
+        Phase::Part(Part::Second),
+    ] {
+        assert_eq!(Phase::from_name(&phase.to_string()), Some(phase));
+    }
+    assert_eq!(Phase::from_name("part3"), None);
+}
+
+#[test]
+fn panics() {
+    let caught = silence_panics(|| catch_panic(Some(Phase::Parse), || panic!("no input")));
+    assert!(matches!(
+        caught,
+        Err(Failure::Panic(Some(Phase::Parse), message)) if message == "no input"
+    ));
+
+    // a panic escaping the run is raised again
+    let escaped = std::panic::catch_unwind(|| silence_panics(|| panic!("{} escaped", 1)));
+    let payload = escaped.unwrap_err();
+    assert_eq!(payload.downcast_ref::<String>().unwrap(), "1 escaped");
+}
+
+#[test]
+fn part_error() {
+    type Parsed = Vec<u32>;
+
+    fn parse(input: &str) -> Parsed {
+        input.lines().map(|line| line.parse().unwrap()).collect()
+    }
+
+    fn first(numbers: Parsed) -> Result<u32, ParseError> {
+        numbers
+            .into_iter()
+            .find(|number| number % 2 == 0)
+            .ok_or(ParseError::Invalid("no even number".to_owned()))
+    }
+
+    fn second(numbers: Parsed) -> u32 {
+        numbers.into_iter().sum()
+    }
+
+    #[derive(Solution)]
+    #[solution(part1 = first, part2 = second)]
+    struct Day01;
+
+    let outcome = Day::new::<Day01>(1).run_on(&Args::default(), "1\n3\n5\n");
+    assert!(matches!(
+        outcome.failures.as_slice(),
+        [Failure::Error(Phase::Part(Part::First), ParseError::Invalid(_))]
+    ));
+    // the other part still runs
+    assert_eq!(outcome.answers, vec![(Part::Second, Answer::from(9))]);
+}